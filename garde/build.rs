@@ -0,0 +1,105 @@
+//! Generates the lookup tables of rules which embed a data file:
+//! - the ISO 3166-1 tables used by the `country_code` rule from `data/iso3166-1.txt`,
+//!   if the `country-code` feature is enabled
+//! - the list used by the `not_common_password` rule from `src/rules/password/common-passwords.txt`,
+//!   if the `common-passwords` feature is enabled
+
+use std::fmt::Write as _;
+use std::path::PathBuf;
+use std::{env, fs};
+
+const DATA: &str = "data/iso3166-1.txt";
+const PASSWORDS: &str = "src/rules/password/common-passwords.txt";
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+
+    if env::var_os("CARGO_FEATURE_COUNTRY_CODE").is_some() {
+        country_codes();
+    }
+    if env::var_os("CARGO_FEATURE_COMMON_PASSWORDS").is_some() {
+        common_passwords();
+    }
+}
+
+fn country_codes() {
+    println!("cargo:rerun-if-changed={DATA}");
+
+    let data = fs::read_to_string(DATA).expect("failed to read ISO 3166-1 data file");
+
+    let mut alpha2 = Vec::new();
+    let mut alpha3 = Vec::new();
+    for (i, line) in data.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let mut parts = line.split_whitespace();
+        let (Some(a2), Some(a3)) = (parts.next(), parts.next()) else {
+            panic!("{DATA}:{}: expected alpha-2 and alpha-3 codes", i + 1);
+        };
+        assert!(
+            a2.len() == 2 && a2.bytes().all(|b| b.is_ascii_uppercase()),
+            "{DATA}:{}: invalid alpha-2 code `{a2}`",
+            i + 1
+        );
+        assert!(
+            a3.len() == 3 && a3.bytes().all(|b| b.is_ascii_uppercase()),
+            "{DATA}:{}: invalid alpha-3 code `{a3}`",
+            i + 1
+        );
+        alpha2.push(a2);
+        alpha3.push(a3);
+    }
+    // the tables are searched using `binary_search`
+    alpha2.sort_unstable();
+    alpha2.dedup();
+    alpha3.sort_unstable();
+    alpha3.dedup();
+
+    let mut out = String::new();
+    write_table(&mut out, "ALPHA2", 2, &alpha2);
+    write_table(&mut out, "ALPHA3", 3, &alpha3);
+
+    let path = PathBuf::from(env::var_os("OUT_DIR").unwrap()).join("country_codes.rs");
+    fs::write(path, out).expect("failed to write country code tables");
+}
+
+fn write_table(out: &mut String, name: &str, len: usize, codes: &[&str]) {
+    writeln!(out, "static {name}: [[u8; {len}]; {}] = [", codes.len()).unwrap();
+    for code in codes {
+        writeln!(out, "    *b\"{code}\",").unwrap();
+    }
+    writeln!(out, "];").unwrap();
+}
+
+fn common_passwords() {
+    println!("cargo:rerun-if-changed={PASSWORDS}");
+
+    let data = fs::read_to_string(PASSWORDS).expect("failed to read common password list");
+
+    // lowercase, so that `case_insensitive` only has to lowercase the value
+    let mut passwords = data
+        .lines()
+        .map(|line| line.trim().to_lowercase())
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>();
+    // the table is searched using `binary_search`
+    passwords.sort_unstable();
+    passwords.dedup();
+
+    let mut out = String::new();
+    writeln!(
+        out,
+        "static COMMON_PASSWORDS: [&str; {}] = [",
+        passwords.len()
+    )
+    .unwrap();
+    for password in &passwords {
+        writeln!(out, "    {password:?},").unwrap();
+    }
+    writeln!(out, "];").unwrap();
+
+    let path = PathBuf::from(env::var_os("OUT_DIR").unwrap()).join("common_passwords.rs");
+    fs::write(path, out).expect("failed to write common password table");
+}
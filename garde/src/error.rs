@@ -3,12 +3,21 @@
 //! The entrypoint of this module is the [`Error`] type.
 #![allow(dead_code)]
 
+mod nested;
+mod pretty;
+#[cfg(feature = "problem-details")]
+mod problem_details;
 mod rc_list;
 use std::borrow::Cow;
+use std::collections::HashMap;
 
 use compact_str::{CompactString, ToCompactString};
 use smallvec::SmallVec;
 
+pub use self::nested::{Nested, NestedEntry, ERRORS_KEY};
+pub use self::pretty::Pretty;
+#[cfg(feature = "problem-details")]
+pub use self::problem_details::{InvalidParam, ProblemDetails};
 use self::rc_list::List;
 
 /// A validation error report.
@@ -18,6 +27,9 @@ use self::rc_list::List;
 /// A single field or list item may have any number of errors attached to it.
 ///
 /// It is possible to extract all errors for specific field using the [`select`][`crate::select`] macro.
+///
+/// `Report` implements [`std::error::Error`], so it may be returned using `?` from functions
+/// returning e.g. `Result<T, Box<dyn std::error::Error + Send + Sync>>`.
 #[derive(Clone, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Report {
@@ -36,12 +48,107 @@ impl Report {
         self.errors.push((path, error));
     }
 
+    /// Append all errors from `other` into this report.
+    ///
+    /// Errors at the same path are accumulated.
+    pub fn merge(&mut self, other: Report) {
+        self.errors.extend(other.errors);
+    }
+
+    /// Append all errors from `other` into this report, with their paths nested under `prefix`.
+    pub fn merge_prefixed(&mut self, prefix: &Path, other: Report) {
+        self.errors.extend(
+            other
+                .errors
+                .into_iter()
+                .map(|(path, error)| (prefix.concat(&path), error)),
+        );
+    }
+
+    /// Returns a new report containing only the errors whose path starts with `prefix`.
+    ///
+    /// The prefix is compared against the dot notation of the path, e.g. `billing_address`
+    /// matches `billing_address.zip` and `billing_address[0]`, but not `billing_address_2`.
+    pub fn filter_by_path_prefix(&self, prefix: &str) -> Report {
+        let errors = self
+            .errors
+            .iter()
+            .filter(|(path, _)| path.strip_str_prefix(prefix).is_some())
+            .cloned()
+            .collect();
+        Report { errors }
+    }
+
+    /// Like [`Report::filter_by_path_prefix`], but also removes `prefix` from the paths
+    /// of the returned errors, e.g. `billing_address.zip` becomes `zip`.
+    pub fn remove_path_prefix(&self, prefix: &str) -> Report {
+        let errors = self
+            .errors
+            .iter()
+            .filter_map(|(path, error)| {
+                let path = path.strip_str_prefix(prefix)?;
+                Some((path, error.clone()))
+            })
+            .collect();
+        Report { errors }
+    }
+
+    /// Splits the report into one report per top-level field, keyed by the name of the field.
+    ///
+    /// The first component is removed from the paths of the grouped errors, e.g. `address.zip`
+    /// is grouped under `address` with the path `zip`. Errors without a path are grouped under `""`.
+    ///
+    /// This is useful to display errors next to the form input they belong to.
+    pub fn group_by_top_level_field(&self) -> HashMap<String, Report> {
+        let mut groups = HashMap::<String, Report>::new();
+        for (path, error) in self.errors.iter() {
+            let (field, rest) = path.split_first();
+            groups
+                .entry(field)
+                .or_insert_with(Report::new)
+                .append(rest, error.clone());
+        }
+        groups
+    }
+
     /// Iterate over all `(Path, Error)` pairs.
     pub fn iter(&self) -> impl Iterator<Item = &(Path, Error)> {
         self.errors.iter()
     }
 
-    /// Returns `true` if the report contains no validation errors.
+    /// Iterate over all `(Path, Error)` pairs with [`Level::Error`].
+    pub fn errors(&self) -> impl Iterator<Item = &(Path, Error)> {
+        self.errors.iter().filter(|(_, error)| !error.is_warning())
+    }
+
+    /// Iterate over all `(Path, Error)` pairs with [`Level::Warning`].
+    pub fn warnings(&self) -> impl Iterator<Item = &(Path, Error)> {
+        self.errors.iter().filter(|(_, error)| error.is_warning())
+    }
+
+    /// Returns `true` if the report contains at least one error with [`Level::Error`].
+    ///
+    /// Validation only fails if this is `true`, so a report which only contains warnings
+    /// is not returned from [`Validate::validate`][`crate::Validate::validate`].
+    /// To collect warnings, use [`Validate::validate_into`][`crate::Validate::validate_into`].
+    #[inline]
+    pub fn has_errors(&self) -> bool {
+        self.errors().next().is_some()
+    }
+
+    /// Returns the number of entries (errors and warnings) in the report.
+    ///
+    /// Use [`Report::errors`] or [`Report::warnings`] to count only one of them.
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.errors.len()
+    }
+
+    /// Returns `true` if the report contains no entries (errors or warnings).
+    ///
+    /// A report which only contains warnings is not empty, use [`Report::has_errors`]
+    /// to check whether validation failed.
+    #[inline]
     pub fn is_empty(&self) -> bool {
         self.errors.is_empty()
     }
@@ -50,15 +157,80 @@ impl Report {
     pub fn into_inner(self) -> Vec<(Path, Error)> {
         self.errors
     }
+
+    /// Iterate over all errors, with their path converted to a string.
+    ///
+    /// Fields are separated by `.`, and list items use brackets, e.g. `items[2].name`.
+    pub fn flatten(&self) -> impl Iterator<Item = (String, Error)> + '_ {
+        self.flatten_with_separator(".")
+    }
+
+    /// Like [`Report::flatten`], but fields are separated by `separator`.
+    pub fn flatten_with_separator<'a>(
+        &'a self,
+        separator: &'a str,
+    ) -> impl Iterator<Item = (String, Error)> + 'a {
+        struct WithSeparator<'a> {
+            path: &'a Path,
+            separator: &'a str,
+        }
+
+        impl std::fmt::Display for WithSeparator<'_> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                self.path.fmt_with_separator(f, self.separator)
+            }
+        }
+
+        self.errors.iter().map(move |(path, error)| {
+            let path = WithSeparator { path, separator }.to_string();
+            (path, error.clone())
+        })
+    }
+
+    /// Group the errors by their path, mirroring the structure of the validated value.
+    ///
+    /// See [`Nested`] for more information.
+    pub fn nested(&self) -> Nested<'_> {
+        Nested::new(self)
+    }
+
+    /// Displays the errors as an indented tree, which mirrors the structure of the validated value.
+    ///
+    /// Unlike the [`Display`][std::fmt::Display] implementation of `Report`, which writes one error per line
+    /// in a format that is easy to parse, this is intended to be read by humans.
+    ///
+    /// See [`Pretty`] for more information.
+    pub fn display_pretty(&self) -> Pretty<'_> {
+        Pretty::new(self)
+    }
+
+    /// Converts into a nested [`serde_json::Value`].
+    ///
+    /// See [`Nested`] for more information.
+    #[cfg(feature = "serde_json")]
+    pub fn to_json_value(&self) -> serde_json::Value {
+        self.nested().to_json_value()
+    }
+
+    /// Converts into an [RFC 7807](https://datatracker.ietf.org/doc/html/rfc7807) problem details object.
+    ///
+    /// See [`ProblemDetails`] for more information.
+    #[cfg(feature = "problem-details")]
+    pub fn to_problem_details(&self, type_uri: &str, title: &str) -> ProblemDetails {
+        ProblemDetails::new(self, type_uri, title)
+    }
 }
 
 impl std::fmt::Display for Report {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         for (path, error) in self.iter() {
-            if path.is_empty() {
-                writeln!(f, "{error}")?;
-            } else {
-                writeln!(f, "{path}: {error}")?;
+            if error.is_warning() {
+                write!(f, "warning: ")?;
+            }
+            match (path.is_empty(), error.label()) {
+                (true, _) => writeln!(f, "{error}")?,
+                (false, Some(label)) => writeln!(f, "{path} ({label}): {}", error.message)?,
+                (false, None) => writeln!(f, "{path}: {error}")?,
             }
         }
         Ok(())
@@ -67,27 +239,256 @@ impl std::fmt::Display for Report {
 
 impl std::error::Error for Report {}
 
+impl IntoIterator for Report {
+    type Item = (Path, Error);
+    type IntoIter = std::vec::IntoIter<(Path, Error)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a Report {
+    type Item = &'a (Path, Error);
+    type IntoIter = std::slice::Iter<'a, (Path, Error)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.iter()
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(from = "ErrorRepr", into = "ErrorRepr"))]
 pub struct Error {
     message: CompactString,
+    code: Option<CompactString>,
+    // Most errors have none of these, so they are boxed to keep `Error` small.
+    extra: Option<Box<Extra>>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+struct Extra {
+    label: Option<CompactString>,
+    level: Level,
+    metadata: Metadata,
+}
+
+static NO_METADATA: Metadata = Metadata {
+    entries: Vec::new(),
+};
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct ErrorRepr {
+    message: CompactString,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    code: Option<CompactString>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    label: Option<CompactString>,
+    #[serde(default, skip_serializing_if = "Level::is_error")]
+    level: Level,
+    #[serde(default, skip_serializing_if = "Metadata::is_empty")]
+    metadata: Metadata,
+}
+
+#[cfg(feature = "serde")]
+impl From<ErrorRepr> for Error {
+    fn from(repr: ErrorRepr) -> Self {
+        Error {
+            message: repr.message,
+            code: repr.code,
+            extra: None,
+        }
+        .with_extra(|extra| {
+            *extra = Extra {
+                label: repr.label,
+                level: repr.level,
+                metadata: repr.metadata,
+            }
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<Error> for ErrorRepr {
+    fn from(error: Error) -> Self {
+        let extra = error.extra.map(|extra| *extra).unwrap_or_default();
+        ErrorRepr {
+            message: error.message,
+            code: error.code,
+            label: extra.label,
+            level: extra.level,
+            metadata: extra.metadata,
+        }
+    }
+}
+
+/// The severity of an [`Error`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Level {
+    /// Causes validation to fail.
+    #[default]
+    Error,
+    /// Is reported, but does not cause validation to fail.
+    Warning,
+}
+
+impl Level {
+    #[cfg_attr(not(feature = "serde"), allow(dead_code))]
+    fn is_error(&self) -> bool {
+        *self == Level::Error
+    }
+}
+
+/// Structured key-value metadata attached to an [`Error`].
+///
+/// Keys are unique, and are iterated in sorted order.
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Metadata {
+    entries: Vec<(CompactString, CompactString)>,
+}
+
+impl Metadata {
+    /// Returns the value of `key`, if present.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.entries
+            .binary_search_by(|(k, _)| k.as_str().cmp(key))
+            .ok()
+            .map(|i| self.entries[i].1.as_str())
+    }
+
+    /// Iterates over all `(key, value)` pairs, sorted by key.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.entries.iter().map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    fn insert(&mut self, key: CompactString, value: CompactString) {
+        match self.entries.binary_search_by(|(k, _)| k.cmp(&key)) {
+            Ok(i) => self.entries[i].1 = value,
+            Err(i) => self.entries.insert(i, (key, value)),
+        }
+    }
 }
 
 impl Error {
     pub fn new(message: impl ToCompactString) -> Self {
         Self {
             message: message.to_compact_string(),
+            code: None,
+            extra: None,
         }
     }
 
+    fn with_extra(mut self, f: impl FnOnce(&mut Extra)) -> Self {
+        let mut extra = self.extra.take().unwrap_or_default();
+        f(&mut extra);
+        if *extra != Extra::default() {
+            self.extra = Some(extra);
+        }
+        self
+    }
+
+    #[doc(hidden)]
+    pub fn __with_message(mut self, message: impl ToCompactString) -> Self {
+        self.message = message.to_compact_string();
+        self
+    }
+
+    /// Set the severity of this error.
+    pub fn with_level(self, level: Level) -> Self {
+        self.with_extra(|extra| extra.level = level)
+    }
+
+    /// Attach a machine-readable error code to this error.
+    pub fn with_code(mut self, code: impl ToCompactString) -> Self {
+        self.code = Some(code.to_compact_string());
+        self
+    }
+
+    /// Attach a human-readable label of the field to this error.
+    pub fn with_label(self, label: impl ToCompactString) -> Self {
+        self.with_extra(|extra| extra.label = Some(label.to_compact_string()))
+    }
+
+    /// Attach a metadata entry to this error, replacing any previous value of `key`.
+    ///
+    /// ```rust
+    /// let error = garde::Error::new("length is lower than 3")
+    ///     .with_metadata("min", 3)
+    ///     .with_metadata("actual_length", 1);
+    /// assert_eq!(error.metadata().get("min"), Some("3"));
+    /// ```
+    pub fn with_metadata(self, key: &str, value: impl ToCompactString) -> Self {
+        self.with_extra(|extra| {
+            extra
+                .metadata
+                .insert(key.to_compact_string(), value.to_compact_string())
+        })
+    }
+
     pub fn message(&self) -> &str {
         self.message.as_ref()
     }
+
+    /// The machine-readable error code of this error.
+    ///
+    /// Errors produced by built-in rules have a default code, such as `GARDE_LENGTH`,
+    /// which may be overridden using `#[garde(code(...))]`.
+    pub fn code(&self) -> Option<&str> {
+        self.code.as_deref()
+    }
+
+    /// The human-readable label of the field this error belongs to.
+    ///
+    /// Set using `#[garde(label(...))]`. When present, it is displayed next to the path.
+    pub fn label(&self) -> Option<&str> {
+        self.extra.as_ref()?.label.as_deref()
+    }
+
+    /// The severity of this error.
+    ///
+    /// Errors produced by rules wrapped in `#[garde(warn(...))]` have [`Level::Warning`].
+    pub fn level(&self) -> Level {
+        self.extra
+            .as_ref()
+            .map_or(Level::Error, |extra| extra.level)
+    }
+
+    /// Returns `true` if this error has [`Level::Warning`].
+    pub fn is_warning(&self) -> bool {
+        self.level() == Level::Warning
+    }
+
+    /// The metadata attached to this error.
+    ///
+    /// Errors produced by some built-in rules have metadata describing the failure,
+    /// e.g. `length` sets the violated bound (`min` or `max`) and `actual_length`.
+    /// Most built-in rules set no metadata, see the [crate documentation](crate) for the full list.
+    /// Custom messages set using `#[garde(message(...))]` keep the metadata of the original error.
+    pub fn metadata(&self) -> &Metadata {
+        self.extra
+            .as_ref()
+            .map_or(&NO_METADATA, |extra| &extra.metadata)
+    }
 }
 
 impl std::fmt::Display for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{}", self.message)
+        match self.label() {
+            Some(label) => write!(f, "{}: {}", label, self.message),
+            None => write!(f, "{}", self.message),
+        }
     }
 }
 
@@ -121,6 +522,44 @@ impl std::fmt::Display for NoKey {
     }
 }
 
+/// Represents the path component of a map key, displayed as `[key:"name"]`.
+///
+/// Used by the `key` modifier of the [`inner`][`crate::rules::inner`] rule.
+pub struct MapKey<'a, K>(pub &'a K);
+
+/// Represents the path component of a map value, displayed as `[value:"name"]`.
+///
+/// Used by the `value` modifier of the [`inner`][`crate::rules::inner`] rule.
+pub struct MapValue<'a, K>(pub &'a K);
+
+impl<'a, K> Clone for MapKey<'a, K> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, K> Copy for MapKey<'a, K> {}
+
+impl<'a, K> Clone for MapValue<'a, K> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, K> Copy for MapValue<'a, K> {}
+
+impl<'a, K: std::fmt::Display> std::fmt::Display for MapKey<'a, K> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "key:{:?}", self.0.to_string())
+    }
+}
+
+impl<'a, K: std::fmt::Display> std::fmt::Display for MapValue<'a, K> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "value:{:?}", self.0.to_string())
+    }
+}
+
 pub trait PathComponentKind: std::fmt::Display + ToCompactString {
     fn component_kind() -> Kind;
 }
@@ -142,6 +581,18 @@ impl_path_component_kind!(String => Key);
 impl_path_component_kind!(CompactString => Key);
 impl_path_component_kind!(NoKey => None);
 
+impl<'a, K: std::fmt::Display> PathComponentKind for MapKey<'a, K> {
+    fn component_kind() -> Kind {
+        Kind::Index
+    }
+}
+
+impl<'a, K: std::fmt::Display> PathComponentKind for MapValue<'a, K> {
+    fn component_kind() -> Kind {
+        Kind::Index
+    }
+}
+
 impl<'a, T: PathComponentKind> PathComponentKind for &'a T {
     fn component_kind() -> Kind {
         T::component_kind()
@@ -177,6 +628,73 @@ impl Path {
         }
     }
 
+    /// Converts the path into an [RFC 6901](https://datatracker.ietf.org/doc/html/rfc6901) JSON Pointer,
+    /// e.g. `/user/address/0/zip`.
+    pub fn as_json_pointer(&self) -> String {
+        let mut pointer = String::new();
+        for (kind, component) in self.__iter().rev() {
+            if kind == Kind::None {
+                continue;
+            }
+            pointer.push('/');
+            for c in component.chars() {
+                match c {
+                    '~' => pointer.push_str("~0"),
+                    '/' => pointer.push_str("~1"),
+                    c => pointer.push(c),
+                }
+            }
+        }
+        pointer
+    }
+
+    /// Converts the path into dot notation, e.g. `user.address[0].zip`.
+    ///
+    /// This is the same as the [`Display`][std::fmt::Display] implementation.
+    pub fn as_dot_notation(&self) -> String {
+        self.to_string()
+    }
+
+    fn concat(&self, other: &Path) -> Self {
+        let mut components = self.components.clone();
+        for (kind, component) in other.__iter().rev() {
+            components = components.append((kind, component.clone()));
+        }
+        Self { components }
+    }
+
+    /// Splits the path into its first component and the remainder.
+    fn split_first(&self) -> (String, Path) {
+        let mut components = self.__iter().rev();
+        let first = components
+            .next()
+            .map(|(_, component)| component.to_string())
+            .unwrap_or_default();
+        let mut rest = List::new();
+        for (kind, component) in components {
+            rest = rest.append((kind, component.clone()));
+        }
+        (first, Path { components: rest })
+    }
+
+    /// Returns the remainder of the path if its dot notation starts with `prefix`,
+    /// where the prefix must end at a component boundary.
+    fn strip_str_prefix(&self, prefix: &str) -> Option<Path> {
+        let components = self.__iter().rev().collect::<TempComponents>();
+        let mut head = Path::empty();
+        for (index, (kind, component)) in components.iter().enumerate() {
+            if head.to_string() == prefix {
+                let mut rest = List::new();
+                for (kind, component) in &components[index..] {
+                    rest = rest.append((*kind, (*component).clone()));
+                }
+                return Some(Path { components: rest });
+            }
+            head.components = head.components.append((*kind, (*component).clone()));
+        }
+        (head.to_string() == prefix).then(Path::empty)
+    }
+
     #[doc(hidden)]
     pub fn __iter(
         &self,
@@ -213,6 +731,12 @@ impl std::fmt::Debug for Path {
 
 impl std::fmt::Display for Path {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.fmt_with_separator(f, ".")
+    }
+}
+
+impl Path {
+    fn fmt_with_separator(&self, f: &mut std::fmt::Formatter, separator: &str) -> std::fmt::Result {
         let mut components = self.__iter().rev().peekable();
         let mut first = true;
         while let Some((kind, component)) = components.next() {
@@ -227,7 +751,7 @@ impl std::fmt::Display for Path {
             if let Some((kind, _)) = components.peek() {
                 match kind {
                     Kind::None => {}
-                    Kind::Key => f.write_str(".")?,
+                    Kind::Key => f.write_str(separator)?,
                     Kind::Index => f.write_str("[")?,
                 }
             }
@@ -268,6 +792,30 @@ impl<'de> serde::Deserialize<'de> for Path {
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for Metadata {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_map(self.iter())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Metadata {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let entries =
+            std::collections::BTreeMap::<CompactString, CompactString>::deserialize(deserializer)?;
+        Ok(Metadata {
+            entries: entries.into_iter().collect(),
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -277,12 +825,343 @@ mod tests {
         let _ = assert::<Report>;
     };
 
+    #[test]
+    fn report_into_boxed_error() {
+        fn validate() -> std::result::Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            let mut report = Report::new();
+            report.append(Path::new("name"), Error::new("length is lower than 1"));
+            Err(report)?;
+            Ok(())
+        }
+
+        let error = validate().unwrap_err();
+        assert!(error.source().is_none());
+        assert_eq!(error.to_string(), "name: length is lower than 1\n");
+        assert!(error.downcast_ref::<Report>().is_some());
+    }
+
+    #[test]
+    fn error_metadata() {
+        let error = Error::new("invalid")
+            .with_metadata("max", 10)
+            .with_metadata("actual", "a")
+            .with_metadata("max", 20);
+        assert_eq!(error.metadata().get("max"), Some("20"));
+        assert_eq!(error.metadata().get("min"), None);
+        assert_eq!(
+            error.metadata().iter().collect::<Vec<_>>(),
+            [("actual", "a"), ("max", "20")]
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn error_metadata_serde() {
+        let error = Error::new("invalid").with_metadata("max", 10);
+        let json = serde_json::to_string(&error).unwrap();
+        assert_eq!(json, r#"{"message":"invalid","metadata":{"max":"10"}}"#);
+        assert_eq!(serde_json::from_str::<Error>(&json).unwrap(), error);
+
+        let json = serde_json::to_string(&Error::new("invalid")).unwrap();
+        assert_eq!(json, r#"{"message":"invalid"}"#);
+    }
+
     #[test]
     fn path_join() {
         let path = Path::new("a").join("b").join("c");
         assert_eq!(path.to_string(), "a.b.c");
     }
 
+    #[test]
+    fn error_size() {
+        // The label, level, and metadata are boxed, as most errors have none of them.
+        assert_eq!(
+            std::mem::size_of::<Error>(),
+            2 * std::mem::size_of::<CompactString>() + std::mem::size_of::<usize>()
+        );
+    }
+
+    #[test]
+    fn report_display_label() {
+        let mut report = Report::new();
+        report.append(
+            Path::new("password_confirmation"),
+            Error::new("length is lower than 8").with_label("Password Confirmation"),
+        );
+        report.append(Path::new("name"), Error::new("length is lower than 1"));
+        assert_eq!(
+            report.to_string(),
+            "password_confirmation (Password Confirmation): length is lower than 8\nname: length is lower than 1\n"
+        );
+    }
+
+    #[test]
+    fn report_display_pretty() {
+        let mut report = Report::new();
+        report.append(Path::new("username"), Error::new("lorem"));
+        report.append(Path::new("address").join("zip"), Error::new("ipsum"));
+        report.append(
+            Path::new("items").join(0),
+            Error::new("dolor").with_level(Level::Warning),
+        );
+        report.append(Path::empty(), Error::new("sit"));
+        assert_eq!(
+            report.display_pretty().to_string(),
+            "- sit\nusername:\n  - lorem\naddress:\n  zip:\n    - ipsum\nitems:\n  [0]:\n    - warning: dolor\n"
+        );
+    }
+
+    #[test]
+    fn report_nested_iter() {
+        fn walk(nested: &Nested<'_>, out: &mut Vec<String>, prefix: &str) {
+            for entry in nested.iter() {
+                match entry {
+                    NestedEntry::Message(error) => out.push(format!("{prefix}: {error}")),
+                    NestedEntry::Field(key, child) => walk(child, out, &format!("{prefix}/{key}")),
+                    NestedEntry::Index(index, child) => {
+                        walk(child, out, &format!("{prefix}/#{index}"))
+                    }
+                }
+            }
+        }
+
+        let mut report = Report::new();
+        report.append(Path::new("address").join("zip"), Error::new("lorem"));
+        report.append(Path::new("items").join(1), Error::new("ipsum"));
+        report.append(Path::new("address"), Error::new("dolor"));
+        report.append(Path::empty(), Error::new("sit"));
+
+        let mut out = Vec::new();
+        walk(&report.nested(), &mut out, "");
+        assert_eq!(
+            out,
+            [
+                ": sit",
+                "/address: dolor",
+                "/address/zip: lorem",
+                "/items/#1: ipsum"
+            ]
+        );
+    }
+
+    #[cfg(feature = "color")]
+    #[test]
+    fn report_display_pretty_colored() {
+        let mut report = Report::new();
+        report.append(Path::new("username"), Error::new("lorem"));
+        let colored = report.display_pretty().colored().to_string();
+        assert!(colored.contains("\u{1b}["));
+        assert_ne!(colored, report.display_pretty().to_string());
+    }
+
+    #[test]
+    fn report_warnings() {
+        let mut report = Report::new();
+        report.append(
+            Path::new("password"),
+            Error::new("length is lower than 12").with_level(Level::Warning),
+        );
+        assert!(!report.is_empty());
+        assert_eq!(report.count(), 1);
+        assert!(!report.has_errors());
+        assert_eq!(report.warnings().count(), 1);
+
+        report.append(Path::new("name"), Error::new("length is lower than 1"));
+        assert!(report.has_errors());
+        assert_eq!(report.errors().count(), 1);
+        assert_eq!(
+            report.to_string(),
+            "warning: password: length is lower than 12\nname: length is lower than 1\n"
+        );
+    }
+
+    #[test]
+    fn report_count() {
+        let mut report = Report::new();
+        assert_eq!(report.count(), 0);
+        assert!(report.is_empty());
+
+        report.append(Path::new("a"), Error::new("lorem"));
+        report.append(Path::new("a"), Error::new("ipsum"));
+        report.append(Path::new("b").join(0), Error::new("dolor"));
+        assert_eq!(report.count(), 3);
+        assert!(!report.is_empty());
+    }
+
+    #[test]
+    fn report_flatten() {
+        let mut report = Report::new();
+        report.append(
+            Path::new("user").join("address").join("zip"),
+            Error::new("lorem"),
+        );
+        report.append(Path::new("items").join(2).join("name"), Error::new("ipsum"));
+        report.append(Path::empty(), Error::new("dolor"));
+
+        assert_eq!(
+            report.flatten().collect::<Vec<_>>(),
+            [
+                ("user.address.zip".to_string(), Error::new("lorem")),
+                ("items[2].name".to_string(), Error::new("ipsum")),
+                ("".to_string(), Error::new("dolor")),
+            ]
+        );
+        assert_eq!(
+            report
+                .flatten_with_separator("/")
+                .map(|(path, _)| path)
+                .collect::<Vec<_>>(),
+            ["user/address/zip", "items[2]/name", ""]
+        );
+    }
+
+    #[test]
+    fn report_merge() {
+        let mut report = Report::new();
+        report.append(Path::new("a"), Error::new("lorem"));
+
+        let mut other = Report::new();
+        other.append(Path::new("a"), Error::new("ipsum"));
+        other.append(Path::new("b").join(0), Error::new("dolor"));
+        report.merge(other.clone());
+        assert_eq!(
+            report.flatten().collect::<Vec<_>>(),
+            [
+                ("a".to_string(), Error::new("lorem")),
+                ("a".to_string(), Error::new("ipsum")),
+                ("b[0]".to_string(), Error::new("dolor")),
+            ]
+        );
+
+        let mut report = Report::new();
+        report.merge_prefixed(&Path::new("step").join(1), other);
+        assert_eq!(
+            report.flatten().map(|(path, _)| path).collect::<Vec<_>>(),
+            ["step[1].a", "step[1].b[0]"]
+        );
+    }
+
+    #[test]
+    fn report_filter_by_path_prefix() {
+        let mut report = Report::new();
+        report.append(
+            Path::new("billing_address").join("zip"),
+            Error::new("lorem"),
+        );
+        report.append(
+            Path::new("billing_address").join(0).join("street"),
+            Error::new("ipsum"),
+        );
+        report.append(Path::new("billing_address_2"), Error::new("dolor"));
+        report.append(Path::new("name"), Error::new("sit"));
+
+        assert_eq!(
+            report
+                .filter_by_path_prefix("billing_address")
+                .flatten()
+                .collect::<Vec<_>>(),
+            [
+                ("billing_address.zip".to_string(), Error::new("lorem")),
+                ("billing_address[0].street".to_string(), Error::new("ipsum")),
+            ]
+        );
+        assert_eq!(
+            report
+                .remove_path_prefix("billing_address")
+                .flatten()
+                .collect::<Vec<_>>(),
+            [
+                ("zip".to_string(), Error::new("lorem")),
+                ("[0].street".to_string(), Error::new("ipsum")),
+            ]
+        );
+        assert_eq!(
+            report
+                .remove_path_prefix("billing_address[0]")
+                .flatten()
+                .collect::<Vec<_>>(),
+            [("street".to_string(), Error::new("ipsum"))]
+        );
+        assert_eq!(report.filter_by_path_prefix("").count(), 4);
+        assert!(report.filter_by_path_prefix("missing").is_empty());
+    }
+
+    #[test]
+    fn report_group_by_top_level_field() {
+        let mut report = Report::new();
+        report.append(Path::new("address").join("zip"), Error::new("lorem"));
+        report.append(
+            Path::new("address").join("lines").join(0),
+            Error::new("ipsum"),
+        );
+        report.append(Path::new("name"), Error::new("dolor"));
+        report.append(Path::empty(), Error::new("sit"));
+
+        let groups = report.group_by_top_level_field();
+        let mut keys = groups.keys().map(String::as_str).collect::<Vec<_>>();
+        keys.sort();
+        assert_eq!(keys, ["", "address", "name"]);
+        assert_eq!(
+            groups["address"].flatten().collect::<Vec<_>>(),
+            [
+                ("zip".to_string(), Error::new("lorem")),
+                ("lines[0]".to_string(), Error::new("ipsum")),
+            ]
+        );
+        assert_eq!(
+            groups["name"].flatten().collect::<Vec<_>>(),
+            [("".to_string(), Error::new("dolor"))]
+        );
+        assert_eq!(
+            groups[""].flatten().collect::<Vec<_>>(),
+            [("".to_string(), Error::new("sit"))]
+        );
+    }
+
+    #[test]
+    fn path_json_pointer() {
+        assert_eq!(Path::empty().as_json_pointer(), "");
+        assert_eq!(
+            Path::new("user")
+                .join("address")
+                .join(0)
+                .join("zip")
+                .as_json_pointer(),
+            "/user/address/0/zip"
+        );
+        assert_eq!(Path::new("a/b").join("m~n").as_json_pointer(), "/a~1b/m~0n");
+    }
+
+    #[test]
+    fn path_dot_notation() {
+        assert_eq!(
+            Path::new("user")
+                .join("address")
+                .join(0)
+                .join("zip")
+                .as_dot_notation(),
+            "user.address[0].zip"
+        );
+    }
+
+    #[test]
+    fn report_into_iter() {
+        let mut report = Report::new();
+        report.append(Path::new("a"), Error::new("lorem"));
+        report.append(Path::new("b").join(0), Error::new("ipsum"));
+
+        let borrowed = (&report).into_iter().cloned().collect::<Vec<_>>();
+        let owned = report.into_iter().collect::<Vec<_>>();
+        assert_eq!(borrowed, owned);
+        assert_eq!(
+            owned,
+            [
+                (Path::new("a"), Error::new("lorem")),
+                (Path::new("b").join(0), Error::new("ipsum")),
+            ]
+        );
+    }
+
     #[test]
     fn report_select() {
         let mut report = Report::new();
@@ -322,5 +1201,48 @@ mod tests {
 
             assert_eq!(report.errors, de.errors);
         }
+
+        #[test]
+        fn serialize_nested() {
+            let mut report = Report::new();
+            report.append(Path::new("username"), Error::new("too short"));
+            report.append(Path::new("address").join("zip"), Error::new("invalid"));
+            report.append(Path::new("address"), Error::new("incomplete"));
+            report.append(Path::new("items").join(2).join("name"), Error::new("empty"));
+            report.append(Path::new("username"), Error::new("not ascii"));
+
+            let expected = serde_json::json!({
+                "username": ["too short", "not ascii"],
+                "address": { "_errors": ["incomplete"], "zip": ["invalid"] },
+                "items": { "2": { "name": ["empty"] } },
+            });
+            assert_eq!(serde_json::to_value(report.nested()).unwrap(), expected);
+            assert_eq!(report.to_json_value(), expected);
+        }
+
+        #[cfg(feature = "problem-details")]
+        #[test]
+        fn serialize_problem_details() {
+            let mut report = Report::new();
+            report.append(Path::empty(), Error::new("invalid"));
+            report.append(Path::new("username"), Error::new("too short"));
+            report.append(Path::new("items").join(2).join("a/b"), Error::new("empty"));
+
+            let problem = report
+                .to_problem_details("https://example.com/probs/validation", "Invalid request")
+                .with_status(422);
+
+            let expected = serde_json::json!({
+                "type": "https://example.com/probs/validation",
+                "title": "Invalid request",
+                "status": 422,
+                "invalid-params": [
+                    { "name": "", "reason": "invalid" },
+                    { "name": "/username", "reason": "too short" },
+                    { "name": "/items/2/a~1b", "reason": "empty" },
+                ],
+            });
+            assert_eq!(serde_json::to_value(&problem).unwrap(), expected);
+        }
     }
 }
@@ -0,0 +1,179 @@
+//! A nested representation of a [`Report`], which mirrors the structure of the validated value.
+
+use super::{Error, Kind, Report};
+
+/// A view of a [`Report`] in which errors are grouped by their path.
+///
+/// When serialized, fields and list items become object keys, and the errors
+/// at a given path become a list of messages:
+///
+/// ```json
+/// { "username": ["length is lower than 3"], "address": { "zip": ["not a valid zip code"] } }
+/// ```
+///
+/// If a path has both its own errors and nested errors, its own errors
+/// are stored under the `"_errors"` key.
+///
+/// The tree can also be traversed using [`Nested::iter`], which is useful for
+/// building custom formats of a report.
+///
+/// Created using [`Report::nested`].
+pub struct Nested<'a> {
+    pub(super) errors: Vec<&'a Error>,
+    pub(super) children: Vec<(Kind, &'a str, Nested<'a>)>,
+}
+
+/// An entry of a [`Nested`] report, as returned by [`Nested::iter`].
+pub enum NestedEntry<'n, 'a> {
+    /// An error at the current path.
+    Message(&'a Error),
+    /// A field or map key, and the errors nested under it.
+    Field(&'a str, &'n Nested<'a>),
+    /// A list index, and the errors nested under it.
+    Index(usize, &'n Nested<'a>),
+}
+
+impl<'a> Nested<'a> {
+    fn child(&mut self, kind: Kind, key: &'a str) -> &mut Nested<'a> {
+        let index = match self.children.iter().position(|(_, k, _)| *k == key) {
+            Some(index) => index,
+            None => {
+                self.children.push((
+                    kind,
+                    key,
+                    Nested {
+                        errors: Vec::new(),
+                        children: Vec::new(),
+                    },
+                ));
+                self.children.len() - 1
+            }
+        };
+        &mut self.children[index].2
+    }
+}
+
+/// The key under which the errors of a path are stored when it also has nested errors.
+pub const ERRORS_KEY: &str = "_errors";
+
+impl<'a> Nested<'a> {
+    pub(super) fn new(report: &'a Report) -> Self {
+        let mut root = Nested {
+            errors: Vec::new(),
+            children: Vec::new(),
+        };
+        for (path, error) in report.iter() {
+            let mut node = &mut root;
+            for (kind, component) in path.__iter().rev() {
+                if kind == Kind::None {
+                    continue;
+                }
+                node = node.child(kind, component.as_str());
+            }
+            node.errors.push(error);
+        }
+        root
+    }
+
+    /// Iterates over the errors at the current path, followed by the fields and indices
+    /// which have nested errors, in the order in which they were first reported.
+    ///
+    /// ```rust
+    /// use garde::error::NestedEntry;
+    /// use garde::{Error, Path, Report};
+    ///
+    /// let mut report = Report::new();
+    /// report.append(Path::new("tags").join(0), Error::new("too long"));
+    ///
+    /// let nested = report.nested();
+    /// let Some(NestedEntry::Field("tags", tags)) = nested.iter().next() else {
+    ///     unreachable!()
+    /// };
+    /// let Some(NestedEntry::Index(0, item)) = tags.iter().next() else {
+    ///     unreachable!()
+    /// };
+    /// let Some(NestedEntry::Message(error)) = item.iter().next() else {
+    ///     unreachable!()
+    /// };
+    /// assert_eq!(error.message(), "too long");
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = NestedEntry<'_, 'a>> + '_ {
+        let errors = self.errors.iter().map(|error| NestedEntry::Message(error));
+        let children = self
+            .children
+            .iter()
+            .map(|(kind, key, child)| match (kind, key.parse()) {
+                (Kind::Index, Ok(index)) => NestedEntry::Index(index, child),
+                _ => NestedEntry::Field(key, child),
+            });
+        errors.chain(children)
+    }
+
+    /// Converts this into a [`serde_json::Value`].
+    #[cfg(feature = "serde_json")]
+    pub fn to_json_value(&self) -> serde_json::Value {
+        fn to_value(node: &Nested<'_>) -> serde_json::Value {
+            let errors = || {
+                serde_json::Value::Array(
+                    node.errors
+                        .iter()
+                        .map(|error| serde_json::Value::String(error.message().into()))
+                        .collect(),
+                )
+            };
+            if node.children.is_empty() {
+                return errors();
+            }
+
+            let mut map = serde_json::Map::new();
+            if !node.errors.is_empty() {
+                map.insert(ERRORS_KEY.into(), errors());
+            }
+            for (_, key, child) in node.children.iter() {
+                map.insert((*key).into(), to_value(child));
+            }
+            serde_json::Value::Object(map)
+        }
+
+        to_value(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'a> serde::Serialize for Nested<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::{SerializeMap as _, SerializeSeq as _};
+
+        struct Errors<'a, 'b>(&'b [&'a Error]);
+
+        impl<'a, 'b> serde::Serialize for Errors<'a, 'b> {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                let mut seq = serializer.serialize_seq(Some(self.0.len()))?;
+                for error in self.0 {
+                    seq.serialize_element(error.message())?;
+                }
+                seq.end()
+            }
+        }
+
+        if self.children.is_empty() {
+            return Errors(&self.errors).serialize(serializer);
+        }
+
+        let len = self.children.len() + usize::from(!self.errors.is_empty());
+        let mut map = serializer.serialize_map(Some(len))?;
+        if !self.errors.is_empty() {
+            map.serialize_entry(ERRORS_KEY, &Errors(&self.errors))?;
+        }
+        for (_, key, child) in self.children.iter() {
+            map.serialize_entry(key, child)?;
+        }
+        map.end()
+    }
+}
@@ -0,0 +1,110 @@
+//! A human-readable, multi-line representation of a [`Report`].
+
+use std::fmt::{self, Display};
+
+use super::{Error, Nested, NestedEntry, Report};
+
+/// Displays a [`Report`] as an indented tree, which mirrors the structure of the validated value:
+///
+/// ```text
+/// username:
+///   - length is lower than 3
+/// address:
+///   zip:
+///     - not a valid zip code
+/// items:
+///   [0]:
+///     - warning: length is greater than 10
+/// ```
+///
+/// Errors which are not attached to any field are displayed first, without indentation.
+///
+/// With the `color` feature enabled, [`Pretty::colored`] highlights the output using ANSI escape codes.
+///
+/// Created using [`Report::display_pretty`].
+pub struct Pretty<'a> {
+    nested: Nested<'a>,
+    colored: bool,
+}
+
+impl<'a> Pretty<'a> {
+    pub(super) fn new(report: &'a Report) -> Self {
+        Self {
+            nested: Nested::new(report),
+            colored: false,
+        }
+    }
+
+    /// Highlights field names, errors, and warnings using ANSI escape codes.
+    #[cfg(feature = "color")]
+    pub fn colored(mut self) -> Self {
+        self.colored = true;
+        self
+    }
+
+    fn fmt_node(&self, f: &mut fmt::Formatter<'_>, node: &Nested<'_>, depth: usize) -> fmt::Result {
+        let indent = depth * 2;
+        for entry in node.iter() {
+            write!(f, "{:indent$}", "")?;
+            let child = match entry {
+                NestedEntry::Message(error) => {
+                    self.fmt_error(f, error)?;
+                    writeln!(f)?;
+                    continue;
+                }
+                NestedEntry::Field(key, child) => {
+                    self.fmt_key(f, key)?;
+                    child
+                }
+                NestedEntry::Index(index, child) => {
+                    self.fmt_key(f, format_args!("[{index}]"))?;
+                    child
+                }
+            };
+            writeln!(f, ":")?;
+            self.fmt_node(f, child, depth + 1)?;
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "color")]
+    fn fmt_key(&self, f: &mut fmt::Formatter<'_>, key: impl Display) -> fmt::Result {
+        use owo_colors::OwoColorize as _;
+
+        match self.colored {
+            true => write!(f, "{}", key.bold()),
+            false => write!(f, "{key}"),
+        }
+    }
+
+    #[cfg(not(feature = "color"))]
+    fn fmt_key(&self, f: &mut fmt::Formatter<'_>, key: impl Display) -> fmt::Result {
+        write!(f, "{key}")
+    }
+
+    #[cfg(feature = "color")]
+    fn fmt_error(&self, f: &mut fmt::Formatter<'_>, error: &Error) -> fmt::Result {
+        use owo_colors::OwoColorize as _;
+
+        match (self.colored, error.is_warning()) {
+            (true, true) => write!(f, "{} {}: {error}", "-".yellow(), "warning".yellow()),
+            (true, false) => write!(f, "{} {error}", "-".red()),
+            (false, true) => write!(f, "- warning: {error}"),
+            (false, false) => write!(f, "- {error}"),
+        }
+    }
+
+    #[cfg(not(feature = "color"))]
+    fn fmt_error(&self, f: &mut fmt::Formatter<'_>, error: &Error) -> fmt::Result {
+        match error.is_warning() {
+            true => write!(f, "- warning: {error}"),
+            false => write!(f, "- {error}"),
+        }
+    }
+}
+
+impl Display for Pretty<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_node(f, &self.nested, 0)
+    }
+}
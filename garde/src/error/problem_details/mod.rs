@@ -0,0 +1,91 @@
+//! An [RFC 7807](https://datatracker.ietf.org/doc/html/rfc7807) representation of a [`Report`].
+
+use super::Report;
+
+/// A "Problem Details for HTTP APIs" object, as described by [RFC 7807](https://datatracker.ietf.org/doc/html/rfc7807).
+///
+/// Every error in the report is stored in the `invalid-params` extension member,
+/// with its path as a [JSON Pointer](https://datatracker.ietf.org/doc/html/rfc6901):
+///
+/// ```json
+/// {
+///   "type": "https://example.com/probs/validation",
+///   "title": "Your request is not valid.",
+///   "invalid-params": [
+///     { "name": "/username", "reason": "length is lower than 3" },
+///     { "name": "/address/zip", "reason": "not a valid zip code" }
+///   ]
+/// }
+/// ```
+///
+/// Errors which are not attached to any field have an empty `name`, which points at the whole document.
+///
+/// Created using [`Report::to_problem_details`].
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
+pub struct ProblemDetails {
+    /// A URI reference which identifies the problem type.
+    #[serde(rename = "type")]
+    pub type_uri: String,
+    /// A short, human-readable summary of the problem type.
+    pub title: String,
+    /// The HTTP status code of the response.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<u16>,
+    /// A human-readable explanation specific to this occurrence of the problem.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+    /// A URI reference which identifies the specific occurrence of the problem.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instance: Option<String>,
+    /// The errors in the report.
+    #[serde(rename = "invalid-params")]
+    pub invalid_params: Vec<InvalidParam>,
+}
+
+/// A single entry in [`ProblemDetails::invalid_params`].
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
+pub struct InvalidParam {
+    /// The path of the invalid value, as a JSON Pointer.
+    pub name: String,
+    /// The error message.
+    pub reason: String,
+}
+
+impl ProblemDetails {
+    pub(super) fn new(report: &Report, type_uri: &str, title: &str) -> Self {
+        let invalid_params = report
+            .iter()
+            .map(|(path, error)| InvalidParam {
+                name: path.as_json_pointer(),
+                reason: error.message().to_string(),
+            })
+            .collect();
+
+        Self {
+            type_uri: type_uri.to_string(),
+            title: title.to_string(),
+            status: None,
+            detail: None,
+            instance: None,
+            invalid_params,
+        }
+    }
+
+    /// Sets the [`status`][`ProblemDetails::status`] member.
+    pub fn with_status(mut self, status: u16) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sets the [`detail`][`ProblemDetails::detail`] member.
+    pub fn with_detail(mut self, detail: impl Into<String>) -> Self {
+        self.detail = Some(detail.into());
+        self
+    }
+
+    /// Sets the [`instance`][`ProblemDetails::instance`] member.
+    pub fn with_instance(mut self, instance: impl Into<String>) -> Self {
+        self.instance = Some(instance.into());
+        self
+    }
+}
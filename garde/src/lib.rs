@@ -2,16 +2,86 @@
 
 pub mod error;
 pub mod rules;
+#[cfg(feature = "json-schema")]
+pub mod schema;
+#[cfg(feature = "testing")]
+pub mod testing;
 pub mod validate;
 
 pub use error::{Error, Path, Report};
+#[cfg(all(feature = "derive", feature = "json-schema"))]
+pub use garde_derive::JsonSchema;
 #[cfg(feature = "derive")]
 pub use garde_derive::{select, Validate};
+#[cfg(feature = "json-schema")]
+pub use schema::JsonSchema;
+#[cfg(feature = "async")]
+pub use validate::AsyncValidate;
 pub use validate::{Unvalidated, Valid, Validate};
 
 pub type Result = ::core::result::Result<(), Error>;
 
+/// Commonly used types and traits, which can be imported all at once using `use garde::prelude::*;`.
+///
+/// Besides the core types, this includes the traits which are implemented to support custom types
+/// in rules that are not string-based, such as [`HasSimpleLength`][`crate::rules::length::HasSimpleLength`]
+/// for `length` or [`Bounds`][`crate::rules::range::Bounds`] for `range`. String-based rules are supported
+/// by implementing [`AsStr`][`crate::rules::AsStr`].
+///
+/// The derive macro is not included, but it is always available as `#[derive(garde::Validate)]`.
+///
+/// ```rust
+/// use garde::prelude::*;
+///
+/// struct Tags(Vec<String>);
+///
+/// impl HasSimpleLength for Tags {
+///     fn length(&self) -> usize {
+///         self.0.len()
+///     }
+/// }
+///
+/// #[derive(garde::Validate)]
+/// struct Post {
+///     #[garde(length(max = 3))]
+///     tags: Tags,
+/// }
+///
+/// fn check(post: Post) -> Result<Valid<Post>, Report> {
+///     Unvalidated::new(post).validate()
+/// }
+/// # assert!(check(Post { tags: Tags(vec![]) }).is_ok());
+/// ```
+pub mod prelude {
+    pub use crate::error::{Error, Path, PathComponentKind, Report};
+    pub use crate::rules::finite::IsFinite;
+    pub use crate::rules::inner::{Inner, InnerMap};
+    #[cfg(feature = "unicode")]
+    pub use crate::rules::length::HasGraphemes;
+    pub use crate::rules::length::{
+        ExactSizeLength, HasBytes, HasChars, HasSimpleLength, HasUtf16CodeUnits,
+    };
+    pub use crate::rules::multiple_of::MultipleOf;
+    pub use crate::rules::precision::HasPrecision;
+    pub use crate::rules::range::sign::Signed;
+    pub use crate::rules::range::Bounds;
+    pub use crate::rules::required::Required;
+    pub use crate::rules::unique::HasUniqueElements;
+    pub use crate::rules::{AsStr, Rule};
+    #[cfg(feature = "json-schema")]
+    pub use crate::schema::JsonSchema;
+    #[cfg(feature = "async")]
+    pub use crate::validate::AsyncValidate;
+    pub use crate::validate::{Unvalidated, Valid, Validate};
+}
+
 pub mod external {
+    #[cfg(feature = "async")]
+    pub use async_trait;
+    #[cfg(all(feature = "email-dns", not(target_arch = "wasm32")))]
+    pub use hickory_resolver;
+    #[cfg(feature = "json-schema")]
+    pub use serde_json;
     pub use {compact_str, smallvec};
 }
 
@@ -41,6 +111,111 @@ pub mod util {
 
     pub use crate::__nested_path as nested_path;
 
+    #[inline]
+    pub fn __dive_context<'a, C: ?Sized, R>(ctx: &'a C, f: impl FnOnce(&'a C) -> R) -> R {
+        f(ctx)
+    }
+
+    #[inline]
+    pub fn __normalize_trim<T: AsRef<str> + ?Sized>(value: &T) -> &str {
+        value.as_ref().trim()
+    }
+
+    #[inline]
+    pub fn __normalize_lowercase<T: AsRef<str> + ?Sized>(value: &T) -> String {
+        value.as_ref().to_lowercase()
+    }
+
+    #[inline]
+    pub fn __normalize_uppercase<T: AsRef<str> + ?Sized>(value: &T) -> String {
+        value.as_ref().to_uppercase()
+    }
+
+    #[inline]
+    pub fn __when<S: ?Sized, C: ?Sized>(
+        this: &S,
+        ctx: &C,
+        condition: impl FnOnce(&S, &C) -> bool,
+    ) -> bool {
+        condition(this, ctx)
+    }
+
+    #[inline]
+    pub fn __in_group(group: Option<&str>, groups: &[&str]) -> bool {
+        match group {
+            Some(group) => groups.contains(&group),
+            None => true,
+        }
+    }
+
+    /// Used to validate a `dive` field asynchronously only if its type implements `AsyncValidate`.
+    ///
+    /// This uses autoref specialization: method lookup finds [`__AsyncDiveValidate`] before
+    /// [`__AsyncDiveFallback`], because the latter is implemented for `&__AsyncDive`.
+    #[cfg(feature = "async")]
+    pub struct __AsyncDive<'a, T>(pub &'a T);
+
+    #[cfg(feature = "async")]
+    pub type __AsyncDiveFuture<'a> =
+        std::pin::Pin<Box<dyn std::future::Future<Output = ()> + Send + 'a>>;
+
+    #[cfg(feature = "async")]
+    pub trait __AsyncDiveValidate {
+        type Context;
+
+        fn __garde_validate_async<'a>(
+            &'a self,
+            ctx: &'a Self::Context,
+            parent: Path,
+            report: &'a mut crate::Report,
+        ) -> Option<__AsyncDiveFuture<'a>>;
+    }
+
+    #[cfg(feature = "async")]
+    impl<T: crate::AsyncValidate> __AsyncDiveValidate for __AsyncDive<'_, T>
+    where
+        T::Context: Sync,
+    {
+        type Context = T::Context;
+
+        #[inline]
+        fn __garde_validate_async<'a>(
+            &'a self,
+            ctx: &'a Self::Context,
+            parent: Path,
+            report: &'a mut crate::Report,
+        ) -> Option<__AsyncDiveFuture<'a>> {
+            Some(self.0.validate_into_async(ctx, parent, report))
+        }
+    }
+
+    #[cfg(feature = "async")]
+    pub trait __AsyncDiveFallback {
+        type Context;
+
+        fn __garde_validate_async<'a>(
+            &'a self,
+            ctx: &'a Self::Context,
+            parent: Path,
+            report: &'a mut crate::Report,
+        ) -> Option<__AsyncDiveFuture<'a>>;
+    }
+
+    #[cfg(feature = "async")]
+    impl<T: crate::Validate> __AsyncDiveFallback for &__AsyncDive<'_, T> {
+        type Context = T::Context;
+
+        #[inline]
+        fn __garde_validate_async<'a>(
+            &'a self,
+            _: &'a Self::Context,
+            _: Path,
+            _: &'a mut crate::Report,
+        ) -> Option<__AsyncDiveFuture<'a>> {
+            None
+        }
+    }
+
     pub trait MaybeJoin {
         fn maybe_join<C, P, CF>(&mut self, parent: P, component: CF) -> Path
         where
@@ -0,0 +1,66 @@
+//! Alphabetic validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(alphabetic)]
+//!     v: String,
+//!     #[garde(alphabetic(ascii))]
+//!     w: String,
+//! }
+//! ```
+//!
+//! By default, any character with the Unicode `Alphabetic` property is accepted,
+//! such as `é` or `日本`. See [`char::is_alphabetic`].
+//! With `ascii`, only ASCII letters (`a-z`, `A-Z`) are accepted.
+//!
+//! The entrypoint is the [`Alphabetic`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(alphabetic)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+
+use super::AsStr;
+use crate::error::Error;
+
+pub fn apply<T: Alphabetic>(v: &T, (ascii,): (bool,)) -> Result<(), Error> {
+    let valid = match ascii {
+        true => v.validate_ascii_alphabetic(),
+        false => v.validate_alphabetic(),
+    };
+    if !valid {
+        return Err(Error::new("not alphabetic"));
+    }
+    Ok(())
+}
+
+pub trait Alphabetic {
+    fn validate_alphabetic(&self) -> bool;
+
+    /// Used by `alphabetic(ascii)`, which only accepts ASCII letters (`a-z`, `A-Z`).
+    fn validate_ascii_alphabetic(&self) -> bool;
+}
+
+impl<T: AsStr> Alphabetic for T {
+    fn validate_alphabetic(&self) -> bool {
+        self.as_str().chars().all(|c| c.is_alphabetic())
+    }
+
+    fn validate_ascii_alphabetic(&self) -> bool {
+        self.as_str().bytes().all(|b| b.is_ascii_alphabetic())
+    }
+}
+
+impl<T: Alphabetic> Alphabetic for Option<T> {
+    fn validate_alphabetic(&self) -> bool {
+        match self {
+            Some(value) => value.validate_alphabetic(),
+            None => true,
+        }
+    }
+
+    fn validate_ascii_alphabetic(&self) -> bool {
+        match self {
+            Some(value) => value.validate_ascii_alphabetic(),
+            None => true,
+        }
+    }
+}
@@ -5,9 +5,15 @@
 //! struct Test {
 //!     #[garde(alphanumeric)]
 //!     v: String,
+//!     #[garde(alphanumeric(ascii))]
+//!     w: String,
 //! }
 //! ```
 //!
+//! By default, any character with the Unicode `Alphabetic` or `Numeric` property is accepted,
+//! such as `é`, `日本`, or `٣`. See [`char::is_alphanumeric`].
+//! With `ascii`, only ASCII letters and digits (`a-z`, `A-Z`, `0-9`) are accepted.
+//!
 //! The entrypoint is the [`Alphanumeric`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(alphanumeric)]` rule.
 //!
 //! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
@@ -15,8 +21,12 @@
 use super::AsStr;
 use crate::error::Error;
 
-pub fn apply<T: Alphanumeric>(v: &T, _: ()) -> Result<(), Error> {
-    if !v.validate_alphanumeric() {
+pub fn apply<T: Alphanumeric>(v: &T, (ascii,): (bool,)) -> Result<(), Error> {
+    let valid = match ascii {
+        true => v.validate_ascii_alphanumeric(),
+        false => v.validate_alphanumeric(),
+    };
+    if !valid {
         return Err(Error::new("not alphanumeric"));
     }
     Ok(())
@@ -24,12 +34,19 @@ pub fn apply<T: Alphanumeric>(v: &T, _: ()) -> Result<(), Error> {
 
 pub trait Alphanumeric {
     fn validate_alphanumeric(&self) -> bool;
+
+    /// Used by `alphanumeric(ascii)`, which only accepts ASCII letters and digits (`a-z`, `A-Z`, `0-9`).
+    fn validate_ascii_alphanumeric(&self) -> bool;
 }
 
 impl<T: AsStr> Alphanumeric for T {
     fn validate_alphanumeric(&self) -> bool {
         self.as_str().chars().all(|c| c.is_alphanumeric())
     }
+
+    fn validate_ascii_alphanumeric(&self) -> bool {
+        self.as_str().bytes().all(|b| b.is_ascii_alphanumeric())
+    }
 }
 
 impl<T: Alphanumeric> Alphanumeric for Option<T> {
@@ -39,4 +56,11 @@ impl<T: Alphanumeric> Alphanumeric for Option<T> {
             None => true,
         }
     }
+
+    fn validate_ascii_alphanumeric(&self) -> bool {
+        match self {
+            Some(value) => value.validate_ascii_alphanumeric(),
+            None => true,
+        }
+    }
 }
@@ -0,0 +1,89 @@
+//! Base64 validation using the [`base64`] crate.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(base64)]
+//!     v: String,
+//!     #[garde(base64(url_safe, padding = "optional"))]
+//!     w: String,
+//! }
+//! ```
+//!
+//! By default, the standard alphabet is used and padding is required.
+//! The `url_safe` argument switches to the URL-safe alphabet, and the `padding`
+//! argument accepts one of `"required"`, `"optional"`, or `"none"`.
+//!
+//! The entrypoint is the [`ValidBase64`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(base64)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+
+use std::fmt::Display;
+
+use base64::engine::{DecodePaddingMode, GeneralPurpose, GeneralPurposeConfig};
+use base64::Engine as _;
+
+use super::AsStr;
+use crate::error::Error;
+
+pub fn apply<T: ValidBase64>(v: &T, (alphabet, padding): (Alphabet, Padding)) -> Result<(), Error> {
+    if let Err(e) = v.validate_base64(alphabet, padding) {
+        return Err(Error::new(format!("not valid base64: {e}")));
+    }
+    Ok(())
+}
+
+pub trait ValidBase64 {
+    type Error: Display;
+
+    fn validate_base64(&self, alphabet: Alphabet, padding: Padding) -> Result<(), Self::Error>;
+}
+
+#[derive(Clone, Copy)]
+pub enum Alphabet {
+    Standard,
+    UrlSafe,
+}
+
+#[derive(Clone, Copy)]
+pub enum Padding {
+    Required,
+    Optional,
+    None,
+}
+
+fn engine(alphabet: Alphabet, padding: Padding) -> GeneralPurpose {
+    let alphabet = match alphabet {
+        Alphabet::Standard => &base64::alphabet::STANDARD,
+        Alphabet::UrlSafe => &base64::alphabet::URL_SAFE,
+    };
+    let padding = match padding {
+        Padding::Required => DecodePaddingMode::RequireCanonical,
+        Padding::Optional => DecodePaddingMode::Indifferent,
+        Padding::None => DecodePaddingMode::RequireNone,
+    };
+    GeneralPurpose::new(
+        alphabet,
+        GeneralPurposeConfig::new().with_decode_padding_mode(padding),
+    )
+}
+
+impl<T: AsStr> ValidBase64 for T {
+    type Error = base64::DecodeError;
+
+    fn validate_base64(&self, alphabet: Alphabet, padding: Padding) -> Result<(), Self::Error> {
+        let _ = engine(alphabet, padding).decode(self.as_str())?;
+        Ok(())
+    }
+}
+
+impl<T: ValidBase64> ValidBase64 for Option<T> {
+    type Error = T::Error;
+
+    fn validate_base64(&self, alphabet: Alphabet, padding: Padding) -> Result<(), Self::Error> {
+        match self {
+            Some(value) => value.validate_base64(alphabet, padding),
+            None => Ok(()),
+        }
+    }
+}
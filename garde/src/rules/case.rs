@@ -0,0 +1,23 @@
+//! Letter case validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(lowercase)]
+//!     slug: String,
+//!     #[garde(uppercase)]
+//!     currency: String,
+//! }
+//! ```
+//!
+//! The entrypoints are the [`IsLowercase`] and [`IsUppercase`] traits. Implementing these traits for a type allows that type to be used with the
+//! `#[garde(lowercase)]` and `#[garde(uppercase)]` rules respectively.
+//!
+//! Both traits have a blanket implementation for all `T: garde::rules::AsStr`.
+//! Characters without case, such as digits and punctuation, are accepted by both rules.
+
+pub mod lowercase;
+pub use lowercase::IsLowercase;
+
+pub mod uppercase;
+pub use uppercase::IsUppercase;
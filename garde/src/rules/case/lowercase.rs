@@ -0,0 +1,30 @@
+//! Implemented by string-like types which contain no uppercase characters.
+
+use crate::error::Error;
+use crate::rules::AsStr;
+
+pub fn apply<T: IsLowercase>(v: &T, _: ()) -> Result<(), Error> {
+    if !v.validate_lowercase() {
+        return Err(Error::new("not lowercase"));
+    }
+    Ok(())
+}
+
+pub trait IsLowercase {
+    fn validate_lowercase(&self) -> bool;
+}
+
+impl<T: AsStr> IsLowercase for T {
+    fn validate_lowercase(&self) -> bool {
+        !self.as_str().chars().any(|c| c.is_uppercase())
+    }
+}
+
+impl<T: IsLowercase> IsLowercase for Option<T> {
+    fn validate_lowercase(&self) -> bool {
+        match self {
+            Some(value) => value.validate_lowercase(),
+            None => true,
+        }
+    }
+}
@@ -0,0 +1,30 @@
+//! Implemented by string-like types which contain no lowercase characters.
+
+use crate::error::Error;
+use crate::rules::AsStr;
+
+pub fn apply<T: IsUppercase>(v: &T, _: ()) -> Result<(), Error> {
+    if !v.validate_uppercase() {
+        return Err(Error::new("not uppercase"));
+    }
+    Ok(())
+}
+
+pub trait IsUppercase {
+    fn validate_uppercase(&self) -> bool;
+}
+
+impl<T: AsStr> IsUppercase for T {
+    fn validate_uppercase(&self) -> bool {
+        !self.as_str().chars().any(|c| c.is_lowercase())
+    }
+}
+
+impl<T: IsUppercase> IsUppercase for Option<T> {
+    fn validate_uppercase(&self) -> bool {
+        match self {
+            Some(value) => value.validate_uppercase(),
+            None => true,
+        }
+    }
+}
@@ -0,0 +1,59 @@
+//! CIDR notation validation using the [`ipnet`] crate.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(cidr)]
+//!     v: String,
+//!     #[garde(cidr(version = "ipv4"))]
+//!     w: String,
+//!     #[garde(inner(cidr))]
+//!     x: Vec<String>,
+//! }
+//! ```
+//!
+//! The value must be an IP address followed by a prefix length, such as `192.168.1.0/24` or `2001:db8::/32`.
+//! The `version` argument may be `"ipv4"` or `"ipv6"` to only accept networks of that version.
+//!
+//! The address does not need to be the first address of the network, so `192.168.1.1/24` is also accepted.
+//!
+//! The entrypoint is the [`ValidCidr`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(cidr)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+
+use ipnet::IpNet;
+
+use super::ip::IpKind;
+use super::AsStr;
+use crate::error::Error;
+
+pub fn apply<T: ValidCidr>(v: &T, (kind,): (IpKind,)) -> Result<(), Error> {
+    if !v.validate_cidr(kind) {
+        return Err(Error::new(format!(
+            "not a valid {kind} network in CIDR notation"
+        )));
+    }
+    Ok(())
+}
+
+pub trait ValidCidr {
+    fn validate_cidr(&self, kind: IpKind) -> bool;
+}
+
+impl<T: AsStr> ValidCidr for T {
+    fn validate_cidr(&self, kind: IpKind) -> bool {
+        matches!(
+            (kind, self.as_str().parse::<IpNet>()),
+            (IpKind::Any, Ok(_)) | (IpKind::V4, Ok(IpNet::V4(_))) | (IpKind::V6, Ok(IpNet::V6(_)))
+        )
+    }
+}
+
+impl<T: ValidCidr> ValidCidr for Option<T> {
+    fn validate_cidr(&self, kind: IpKind) -> bool {
+        match self {
+            Some(value) => value.validate_cidr(kind),
+            None => true,
+        }
+    }
+}
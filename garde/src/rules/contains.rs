@@ -9,9 +9,20 @@
 //!     v: String,
 //!     #[garde(contains(STR))]
 //!     w: String,
+//!     #[garde(not_contains("test"))]
+//!     x: String,
+//!     #[garde(contains_any(["spam", "scam", "phishing"]))]
+//!     y: String,
+//!     #[garde(contains_all(["foo", "bar"]))]
+//!     z: String,
 //! }
 //! ```
 //!
+//! The `not_contains` rule inverts the check, and is implemented in the [`not`] module.
+//!
+//! The `contains_any` and `contains_all` rules accept a list of substrings, and require the value to contain
+//! at least one of them or all of them, respectively. They are implemented in the [`any`] and [`all`] modules.
+//!
 //! The entrypoint is the [`Contains`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(contains)]` rule.
 //!
 //! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
@@ -26,8 +37,54 @@ pub fn apply<T: Contains>(v: &T, (pat,): (&str,)) -> Result<(), Error> {
     Ok(())
 }
 
+pub mod not {
+    use super::Contains;
+    use crate::error::Error;
+
+    pub fn apply<T: Contains>(v: &T, (pat,): (&str,)) -> Result<(), Error> {
+        if !v.validate_not_contains(pat) {
+            return Err(Error::new(format!("must not contain \"{pat}\"")));
+        }
+        Ok(())
+    }
+}
+
+pub mod any {
+    use super::Contains;
+    use crate::error::Error;
+
+    pub fn apply<T: Contains>(v: &T, (pats,): (&[&str],)) -> Result<(), Error> {
+        if !pats.iter().any(|pat| v.validate_contains(pat)) {
+            return Err(Error::new(format!("does not contain any of {pats:?}")));
+        }
+        Ok(())
+    }
+}
+
+pub mod all {
+    use super::Contains;
+    use crate::error::Error;
+
+    pub fn apply<T: Contains>(v: &T, (pats,): (&[&str],)) -> Result<(), Error> {
+        let missing = pats
+            .iter()
+            .filter(|pat| !v.validate_contains(pat))
+            .collect::<Vec<_>>();
+        if !missing.is_empty() {
+            return Err(Error::new(format!(
+                "does not contain all of {pats:?}, missing {missing:?}"
+            )));
+        }
+        Ok(())
+    }
+}
+
 pub trait Contains {
     fn validate_contains(&self, pat: &str) -> bool;
+
+    fn validate_not_contains(&self, pat: &str) -> bool {
+        !self.validate_contains(pat)
+    }
 }
 
 impl<T: AsStr> Contains for T {
@@ -43,4 +100,11 @@ impl<T: Contains> Contains for Option<T> {
             None => true,
         }
     }
+
+    fn validate_not_contains(&self, pat: &str) -> bool {
+        match self {
+            Some(value) => value.validate_not_contains(pat),
+            None => true,
+        }
+    }
 }
@@ -0,0 +1,84 @@
+//! ISO 3166-1 country code validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(country_code)]
+//!     v: String,
+//!     #[garde(country_code(alpha = 3, case_insensitive))]
+//!     w: String,
+//! }
+//! ```
+//!
+//! By default, the value must be an uppercase ISO 3166-1 alpha-2 code, such as `DE`.
+//! `alpha = 3` switches to alpha-3 codes, such as `DEU`, and `case_insensitive` also accepts lowercase letters.
+//!
+//! The set of valid codes is generated at build time from `data/iso3166-1.txt`.
+//!
+//! The entrypoint is the [`ValidCountryCode`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(country_code)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+
+use std::fmt::Display;
+
+use super::AsStr;
+use crate::error::Error;
+
+include!(concat!(env!("OUT_DIR"), "/country_codes.rs"));
+
+pub fn apply<T: ValidCountryCode>(
+    v: &T,
+    (alpha, case_insensitive): (Alpha, bool),
+) -> Result<(), Error> {
+    if !v.validate_country_code(alpha, case_insensitive) {
+        return Err(Error::new(format!("not a valid {alpha}")));
+    }
+    Ok(())
+}
+
+pub trait ValidCountryCode {
+    fn validate_country_code(&self, alpha: Alpha, case_insensitive: bool) -> bool;
+}
+
+#[derive(Clone, Copy)]
+pub enum Alpha {
+    Alpha2,
+    Alpha3,
+}
+
+impl Display for Alpha {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Alpha::Alpha2 => write!(f, "ISO 3166-1 alpha-2 country code"),
+            Alpha::Alpha3 => write!(f, "ISO 3166-1 alpha-3 country code"),
+        }
+    }
+}
+
+fn is_country_code<const N: usize>(table: &[[u8; N]], v: &str, case_insensitive: bool) -> bool {
+    let Ok(mut code) = <[u8; N]>::try_from(v.as_bytes()) else {
+        return false;
+    };
+    if case_insensitive {
+        code.make_ascii_uppercase();
+    }
+    table.binary_search(&code).is_ok()
+}
+
+impl<T: AsStr> ValidCountryCode for T {
+    fn validate_country_code(&self, alpha: Alpha, case_insensitive: bool) -> bool {
+        match alpha {
+            Alpha::Alpha2 => is_country_code(&ALPHA2, self.as_str(), case_insensitive),
+            Alpha::Alpha3 => is_country_code(&ALPHA3, self.as_str(), case_insensitive),
+        }
+    }
+}
+
+impl<T: ValidCountryCode> ValidCountryCode for Option<T> {
+    fn validate_country_code(&self, alpha: Alpha, case_insensitive: bool) -> bool {
+        match self {
+            Some(value) => value.validate_country_code(alpha, case_insensitive),
+            None => true,
+        }
+    }
+}
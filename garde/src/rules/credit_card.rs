@@ -5,51 +5,103 @@
 //! struct Test {
 //!     #[garde(credit_card)]
 //!     v: String,
+//!     #[garde(credit_card(networks = ["visa", "mastercard"]))]
+//!     w: String,
 //! }
 //! ```
 //!
+//! The card number must have a known network, a valid length for that network, and a valid Luhn check digit.
+//! If `networks` is set, the network of the card must also be one of the given networks.
+//! The network is detected from the prefix of the card number, and the names are those of [`Network::name`].
+//!
 //! The entrypoint is the [`CreditCard`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(credit_card)]` rule.
 //!
 //! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
 
+use std::borrow::Cow;
 use std::fmt::Display;
 
+pub use card_validate::Type as Network;
+
 use super::AsStr;
 use crate::error::Error;
 
-pub fn apply<T: CreditCard>(v: &T, _: ()) -> Result<(), Error> {
-    if let Err(e) = v.validate_credit_card() {
-        return Err(Error::new(format!("not a valid credit card number: {e}")));
+pub fn apply<T: CreditCard>(v: &T, (networks,): (&[Network],)) -> Result<(), Error> {
+    match v.validate_credit_card() {
+        Err(e) => Err(Error::new(format!("not a valid credit card number: {e}"))),
+        Ok(Some(network)) if !networks.is_empty() && !networks.contains(&network) => Err(
+            Error::new(format!("card must be {}", DisplayNetworks(networks))),
+        ),
+        Ok(_) => Ok(()),
     }
-    Ok(())
 }
 
 pub trait CreditCard {
     type Error: Display;
 
-    fn validate_credit_card(&self) -> Result<(), Self::Error>;
+    /// Validates the card number, and returns its network.
+    ///
+    /// Returns `Ok(None)` if there is no card number to validate, such as for `Option::None`.
+    fn validate_credit_card(&self) -> Result<Option<Network>, Self::Error>;
 }
 
 impl<T: AsStr> CreditCard for T {
     type Error = InvalidCard;
 
-    fn validate_credit_card(&self) -> Result<(), Self::Error> {
-        let _ = card_validate::Validate::from(self.as_str())?;
-        Ok(())
+    fn validate_credit_card(&self) -> Result<Option<Network>, Self::Error> {
+        let card = card_validate::Validate::from(self.as_str())?;
+        Ok(Some(card.card_type))
     }
 }
 
 impl<T: CreditCard> CreditCard for Option<T> {
     type Error = T::Error;
 
-    fn validate_credit_card(&self) -> Result<(), Self::Error> {
+    fn validate_credit_card(&self) -> Result<Option<Network>, Self::Error> {
         match self {
             Some(value) => value.validate_credit_card(),
-            None => Ok(()),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Displays networks as e.g. `Visa, Mastercard or American Express`.
+struct DisplayNetworks<'a>(&'a [Network]);
+
+impl Display for DisplayNetworks<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let last = self.0.len().saturating_sub(1);
+        for (i, network) in self.0.iter().enumerate() {
+            match i {
+                0 => {}
+                i if i == last => f.write_str(" or ")?,
+                _ => f.write_str(", ")?,
+            }
+            f.write_str(&display_name(network))?;
         }
+        Ok(())
     }
 }
 
+fn display_name(network: &Network) -> Cow<'static, str> {
+    let name = match network {
+        Network::VisaElectron => "Visa Electron",
+        Network::Maestro => "Maestro",
+        Network::Forbrugsforeningen => "Forbrugsforeningen",
+        Network::Dankort => "Dankort",
+        Network::Visa => "Visa",
+        Network::MIR => "Mir",
+        Network::MasterCard => "Mastercard",
+        Network::Amex => "American Express",
+        Network::DinersClub => "Diners Club",
+        Network::Discover => "Discover",
+        Network::UnionPay => "UnionPay",
+        Network::JCB => "JCB",
+        other => return Cow::Owned(other.to_string()),
+    };
+    Cow::Borrowed(name)
+}
+
 pub struct InvalidCard(card_validate::ValidateError);
 impl Display for InvalidCard {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -0,0 +1,98 @@
+//! Date validation using the [`chrono`] crate.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(date)]
+//!     v: String,
+//!     #[garde(date(format = "%d.%m.%Y", after = "01.01.2000"))]
+//!     w: String,
+//! }
+//! ```
+//!
+//! All arguments are optional:
+//! - `format`: a [`chrono` format string](chrono::format::strftime), `%Y-%m-%d` by default.
+//! - `after`: the date must be later than this date.
+//! - `before`: the date must be earlier than this date.
+//!
+//! The bounds are written in the same format as the value, and are checked at compile time.
+//!
+//! The entrypoint is the [`ValidDate`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(date)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+//!
+//! See also the [`time`][super::time] and [`datetime`][super::datetime] rules.
+
+use std::fmt::Display;
+
+use chrono::{NaiveDate, ParseResult};
+
+use super::AsStr;
+use crate::error::Error;
+
+pub const DEFAULT_FORMAT: &str = "%Y-%m-%d";
+
+pub fn apply<T: ValidDate>(
+    v: &T,
+    (format, after, before): (Option<&str>, Option<&str>, Option<&str>),
+) -> Result<(), Error> {
+    let format = format.unwrap_or(DEFAULT_FORMAT);
+    let parse = |v: &str| NaiveDate::parse_from_str(v, format);
+    match v.parse_date(format) {
+        Ok(Some(value)) => check_bounds(value, after, before, parse),
+        Ok(None) => Ok(()),
+        Err(e) => Err(Error::new(format!("not a valid date: {e}"))),
+    }
+}
+
+pub trait ValidDate {
+    /// Parses the value, or returns `None` if there is no value to parse.
+    fn parse_date(&self, format: &str) -> ParseResult<Option<NaiveDate>>;
+}
+
+impl<T: AsStr> ValidDate for T {
+    fn parse_date(&self, format: &str) -> ParseResult<Option<NaiveDate>> {
+        NaiveDate::parse_from_str(self.as_str(), format).map(Some)
+    }
+}
+
+impl<T: ValidDate> ValidDate for Option<T> {
+    fn parse_date(&self, format: &str) -> ParseResult<Option<NaiveDate>> {
+        match self {
+            Some(value) => value.parse_date(format),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Checks that `value` is strictly between the `after` and `before` bounds, which are parsed using `parse`.
+pub(crate) fn check_bounds<V: PartialOrd, E: Display>(
+    value: V,
+    after: Option<&str>,
+    before: Option<&str>,
+    parse: impl Fn(&str) -> Result<V, E>,
+) -> Result<(), Error> {
+    if let Some(after) = after {
+        match parse(after) {
+            Ok(bound) if value > bound => {}
+            Ok(_) => {
+                return Err(
+                    Error::new(format!("must be after {after}")).with_metadata("after", after)
+                )
+            }
+            Err(e) => return Err(Error::new(format!("invalid bound `{after}`: {e}"))),
+        }
+    }
+    if let Some(before) = before {
+        match parse(before) {
+            Ok(bound) if value < bound => {}
+            Ok(_) => {
+                return Err(
+                    Error::new(format!("must be before {before}")).with_metadata("before", before)
+                )
+            }
+            Err(e) => return Err(Error::new(format!("invalid bound `{before}`: {e}"))),
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,78 @@
+//! Date and time validation using the [`chrono`] crate.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(datetime)]
+//!     v: String,
+//!     #[garde(datetime(format = "%Y-%m-%dT%H:%M:%SZ", after = "2000-01-01T00:00:00Z"))]
+//!     w: String,
+//! }
+//! ```
+//!
+//! All arguments are optional:
+//! - `format`: a [`chrono` format string](chrono::format::strftime). By default, the value must be in the
+//!   [RFC 3339](https://datatracker.ietf.org/doc/html/rfc3339) format, e.g. `2000-01-01T12:00:00+01:00`.
+//! - `after`: the date and time must be later than this date and time.
+//! - `before`: the date and time must be earlier than this date and time.
+//!
+//! The bounds are written in the same format as the value, and are checked at compile time.
+//! If the format includes a UTC offset, values are converted to UTC before they are compared.
+//! Otherwise, they are treated as UTC.
+//!
+//! The entrypoint is the [`ValidDateTime`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(datetime)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+
+use chrono::format::{Parsed, StrftimeItems};
+use chrono::{NaiveDateTime, ParseResult};
+
+use super::date::check_bounds;
+use super::AsStr;
+use crate::error::Error;
+
+pub fn apply<T: ValidDateTime>(
+    v: &T,
+    (format, after, before): (Option<&str>, Option<&str>, Option<&str>),
+) -> Result<(), Error> {
+    let parse = |v: &str| parse_datetime(v, format);
+    match v.parse_datetime(format) {
+        Ok(Some(value)) => check_bounds(value, after, before, parse),
+        Ok(None) => Ok(()),
+        Err(e) => Err(Error::new(format!("not a valid date and time: {e}"))),
+    }
+}
+
+pub trait ValidDateTime {
+    /// Parses the value as a date and time in UTC, or returns `None` if there is no value to parse.
+    ///
+    /// If `format` is `None`, the value is parsed as RFC 3339.
+    fn parse_datetime(&self, format: Option<&str>) -> ParseResult<Option<NaiveDateTime>>;
+}
+
+impl<T: AsStr> ValidDateTime for T {
+    fn parse_datetime(&self, format: Option<&str>) -> ParseResult<Option<NaiveDateTime>> {
+        parse_datetime(self.as_str(), format).map(Some)
+    }
+}
+
+impl<T: ValidDateTime> ValidDateTime for Option<T> {
+    fn parse_datetime(&self, format: Option<&str>) -> ParseResult<Option<NaiveDateTime>> {
+        match self {
+            Some(value) => value.parse_datetime(format),
+            None => Ok(None),
+        }
+    }
+}
+
+fn parse_datetime(value: &str, format: Option<&str>) -> ParseResult<NaiveDateTime> {
+    let Some(format) = format else {
+        return chrono::DateTime::parse_from_rfc3339(value).map(|v| v.naive_utc());
+    };
+    let mut parsed = Parsed::new();
+    chrono::format::parse(&mut parsed, value, StrftimeItems::new(format))?;
+    match parsed.offset() {
+        Some(_) => parsed.to_datetime().map(|v| v.naive_utc()),
+        None => parsed.to_naive_datetime_with_offset(0),
+    }
+}
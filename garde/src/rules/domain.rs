@@ -0,0 +1,129 @@
+//! Domain name validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(domain)]
+//!     v: String,
+//!     #[garde(domain(require_tld))]
+//!     w: String,
+//! }
+//! ```
+//!
+//! A domain name consists of labels separated by dots. Each label must be 1 to 63 characters long,
+//! contain only ASCII letters, digits and hyphens, and may not begin or end with a hyphen.
+//! The whole domain may be at most 253 characters long, not counting an optional trailing dot.
+//!
+//! The `require_tld` argument additionally requires the domain to consist of at least two labels, e.g. `example.com`.
+//!
+//! If the `email-idna` feature is enabled, internationalized domain names are converted to their ASCII form before validation.
+//!
+//! The entrypoint is the [`ValidDomain`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(domain)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+
+use std::fmt::Display;
+
+use super::AsStr;
+use crate::error::Error;
+
+pub fn apply<T: ValidDomain>(v: &T, (require_tld,): (bool,)) -> Result<(), Error> {
+    if let Err(e) = v.validate_domain(require_tld) {
+        return Err(Error::new(format!("not a valid domain: {e}")));
+    }
+    Ok(())
+}
+
+pub trait ValidDomain {
+    fn validate_domain(&self, require_tld: bool) -> Result<(), InvalidDomain>;
+}
+
+impl<T: AsStr> ValidDomain for T {
+    fn validate_domain(&self, require_tld: bool) -> Result<(), InvalidDomain> {
+        let value = self.as_str();
+
+        #[cfg(feature = "email-idna")]
+        {
+            if !value.is_ascii() {
+                let Ok(value) =
+                    idna::domain_to_ascii_cow(value.as_bytes(), idna::AsciiDenyList::URL)
+                else {
+                    return Err(InvalidDomain::InvalidCharacter);
+                };
+                return parse_domain(&value, require_tld);
+            }
+        }
+
+        parse_domain(value, require_tld)
+    }
+}
+
+impl<T: ValidDomain> ValidDomain for Option<T> {
+    fn validate_domain(&self, require_tld: bool) -> Result<(), InvalidDomain> {
+        match self {
+            Some(value) => value.validate_domain(require_tld),
+            None => Ok(()),
+        }
+    }
+}
+
+fn parse_domain(value: &str, require_tld: bool) -> Result<(), InvalidDomain> {
+    let value = value.strip_suffix('.').unwrap_or(value);
+    if value.is_empty() {
+        return Err(InvalidDomain::Empty);
+    }
+    if value.len() > 253 {
+        return Err(InvalidDomain::TooLong);
+    }
+
+    let mut labels = 0;
+    for label in value.split('.') {
+        if label.is_empty() {
+            return Err(InvalidDomain::EmptyLabel);
+        }
+        if label.len() > 63 {
+            return Err(InvalidDomain::LabelTooLong);
+        }
+        if !label
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'-')
+        {
+            return Err(InvalidDomain::InvalidCharacter);
+        }
+        if label.starts_with('-') || label.ends_with('-') {
+            return Err(InvalidDomain::HyphenAtLabelEdge);
+        }
+        labels += 1;
+    }
+
+    if require_tld && labels < 2 {
+        return Err(InvalidDomain::MissingTld);
+    }
+
+    Ok(())
+}
+
+#[derive(Debug)]
+pub enum InvalidDomain {
+    Empty,
+    TooLong,
+    EmptyLabel,
+    LabelTooLong,
+    InvalidCharacter,
+    HyphenAtLabelEdge,
+    MissingTld,
+}
+
+impl Display for InvalidDomain {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvalidDomain::Empty => write!(f, "value is empty"),
+            InvalidDomain::TooLong => write!(f, "value is longer than 253 characters"),
+            InvalidDomain::EmptyLabel => write!(f, "label is empty"),
+            InvalidDomain::LabelTooLong => write!(f, "label is longer than 63 characters"),
+            InvalidDomain::InvalidCharacter => write!(f, "label contains invalid characters"),
+            InvalidDomain::HyphenAtLabelEdge => write!(f, "label begins or ends with a hyphen"),
+            InvalidDomain::MissingTld => write!(f, "missing top-level domain"),
+        }
+    }
+}
@@ -11,10 +11,27 @@
 //! The entrypoint is the [`Email`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(email)]` rule.
 //!
 //! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+//!
+//! If the `email-dns` feature is enabled, `check_mx` additionally checks that
+//! the domain of the email address has MX records. The lookup is asynchronous, so it only runs in `AsyncValidate`:
+//!
+//! ```rust
+//! # #[cfg(all(feature = "email-dns", not(target_arch = "wasm32")))]
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(email(check_mx))]
+//!     v: String,
+//! }
+//! ```
+//!
+//! It is implemented in the `mx` module.
 
 use std::fmt::Display;
 use std::str::FromStr;
 
+#[cfg(all(feature = "email-dns", not(target_arch = "wasm32")))]
+pub mod mx;
+
 use super::pattern::Matcher;
 use super::AsStr;
 use crate::error::Error;
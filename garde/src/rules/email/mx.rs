@@ -0,0 +1,105 @@
+//! Implemented by string-like types which contain an email address with a domain that can receive mail.
+//!
+//! This backs the `check_mx` argument of the `email` rule.
+//! The domain of the email address is looked up using the [`hickory_resolver`] crate,
+//! and the address is only valid if the domain has at least one MX record.
+//!
+//! Lookups share a global resolver, which is configured from the system configuration
+//! (e.g. `/etc/resolv.conf`) by default. Call [`configure`] before the first lookup to use a different configuration.
+//! The resolver caches results for as long as the TTL of the records allows, which can be turned off
+//! by setting [`ResolverOpts::cache_size`] to `0`.
+//!
+//! `check_mx` only runs in [`AsyncValidate`][crate::validate::AsyncValidate], and must be awaited within a Tokio runtime.
+//! It is not available on `wasm32` targets.
+
+use hickory_resolver::config::{ResolverConfig, ResolverOpts};
+use hickory_resolver::error::{ResolveError, ResolveErrorKind};
+use hickory_resolver::lookup::MxLookup;
+use hickory_resolver::{system_conf, TokioAsyncResolver};
+use once_cell::sync::OnceCell;
+
+use super::parse_email;
+use crate::error::Error;
+use crate::rules::AsStr;
+
+static CONFIG: OnceCell<(ResolverConfig, ResolverOpts)> = OnceCell::new();
+
+/// Sets the configuration of the resolver used by `check_mx`.
+///
+/// Returns `false` if the resolver was already configured, either by a previous call
+/// or by a lookup which happened before this call.
+pub fn configure(config: ResolverConfig, options: ResolverOpts) -> bool {
+    CONFIG.set((config, options)).is_ok()
+}
+
+fn config() -> &'static (ResolverConfig, ResolverOpts) {
+    CONFIG.get_or_init(|| system_conf::read_system_conf().unwrap_or_default())
+}
+
+pub fn check_mx<T: EmailDomain + ?Sized, C: ?Sized>(
+    v: &T,
+    _: &C,
+) -> impl std::future::Future<Output = Result<(), Error>> + Send + 'static {
+    static RESOLVER: OnceCell<TokioAsyncResolver> = OnceCell::new();
+
+    let name = v.email_domain().map(fully_qualified);
+    async move {
+        let Some(name) = name else {
+            return Ok(());
+        };
+        let resolver = RESOLVER.get_or_init(|| {
+            let (config, options) = config();
+            TokioAsyncResolver::tokio(config.clone(), options.clone())
+        });
+        check_lookup(resolver.mx_lookup(name).await)
+    }
+}
+
+pub trait EmailDomain {
+    /// Returns the domain of the email address, or `None` if there is nothing to look up.
+    fn email_domain(&self) -> Option<&str>;
+}
+
+impl<T: AsStr> EmailDomain for T {
+    fn email_domain(&self) -> Option<&str> {
+        let value = self.as_str();
+        // invalid addresses are already reported by the `email` rule itself
+        if parse_email(value).is_err() {
+            return None;
+        }
+        let (_, domain) = value.rsplit_once('@')?;
+        // address literals such as `[127.0.0.1]` have no MX records
+        if domain.starts_with('[') {
+            return None;
+        }
+        Some(domain)
+    }
+}
+
+impl<T: EmailDomain> EmailDomain for Option<T> {
+    fn email_domain(&self) -> Option<&str> {
+        self.as_ref().and_then(EmailDomain::email_domain)
+    }
+}
+
+/// Appends the root label, so that the domain isn't resolved relative to the search domains of the system.
+fn fully_qualified(domain: &str) -> String {
+    match domain.ends_with('.') {
+        true => domain.to_owned(),
+        false => format!("{domain}."),
+    }
+}
+
+fn check_lookup(lookup: Result<MxLookup, ResolveError>) -> Result<(), Error> {
+    let error = || Error::new("email domain has no MX records").with_code("GARDE_EMAIL_MX");
+    match lookup {
+        // a single MX record pointing to the root is a "null MX", meaning the domain does not accept mail (RFC 7505)
+        Ok(lookup) if lookup.iter().any(|mx| !mx.exchange().is_root()) => Ok(()),
+        Ok(_) => Err(error()),
+        Err(e) if matches!(e.kind(), ResolveErrorKind::NoRecordsFound { .. }) => Err(error()),
+        Err(e) => {
+            Err(Error::new(format!("could not look up MX records: {e}"))
+                .with_code("GARDE_EMAIL_MX"))
+        }
+    }
+}
@@ -0,0 +1,73 @@
+//! Finite number validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(finite)]
+//!     v: f64,
+//!     #[garde(finite, range(min = 0.0, max = 100.0))]
+//!     w: f32,
+//! }
+//! ```
+//!
+//! The entrypoint is the [`IsFinite`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(finite)]` rule.
+//!
+//! This trait is implemented for `f32` and `f64`.
+
+use std::fmt::Display;
+
+use crate::error::Error;
+
+pub fn apply<T: IsFinite>(v: &T, _: ()) -> Result<(), Error> {
+    if let Err(e) = v.validate_finite() {
+        return Err(Error::new(format!("expected a finite number, got {e}")));
+    }
+    Ok(())
+}
+
+pub trait IsFinite {
+    fn validate_finite(&self) -> Result<(), NotFinite>;
+}
+
+pub enum NotFinite {
+    NaN,
+    Infinite,
+}
+
+impl Display for NotFinite {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NotFinite::NaN => write!(f, "NaN"),
+            NotFinite::Infinite => write!(f, "infinity"),
+        }
+    }
+}
+
+macro_rules! impl_for_float {
+    ($($T:ident),*) => {
+        $(
+            impl IsFinite for $T {
+                fn validate_finite(&self) -> Result<(), NotFinite> {
+                    if self.is_nan() {
+                        Err(NotFinite::NaN)
+                    } else if self.is_infinite() {
+                        Err(NotFinite::Infinite)
+                    } else {
+                        Ok(())
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_for_float!(f32, f64);
+
+impl<T: IsFinite> IsFinite for Option<T> {
+    fn validate_finite(&self) -> Result<(), NotFinite> {
+        match self {
+            Some(value) => value.validate_finite(),
+            None => Ok(()),
+        }
+    }
+}
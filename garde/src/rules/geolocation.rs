@@ -0,0 +1,155 @@
+//! Geographic coordinate validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(latitude)]
+//!     lat: f64,
+//!     #[garde(longitude)]
+//!     lon: f64,
+//!     #[garde(coordinate_pair)]
+//!     position: (f64, f64),
+//! }
+//! ```
+//!
+//! A latitude must be within `-90.0..=90.0`, and a longitude must be within `-180.0..=180.0`.
+//! NaN and infinite values are rejected.
+//!
+//! The `coordinate_pair` rule validates a `(latitude, longitude)` tuple.
+//!
+//! The entrypoints are the [`ValidLatitude`], [`ValidLongitude`], and [`ValidCoordinatePair`] traits.
+//! Implementing these traits for a type allows that type to be used with the
+//! `#[garde(latitude)]`, `#[garde(longitude)]`, and `#[garde(coordinate_pair)]` rules, respectively.
+//!
+//! The [`ValidLatitude`] and [`ValidLongitude`] traits are implemented for `f32` and `f64`,
+//! and [`ValidCoordinatePair`] has a blanket implementation for all `(T, U)` where `T: ValidLatitude` and `U: ValidLongitude`.
+
+use std::fmt::Display;
+
+use super::finite::NotFinite;
+use crate::error::Error;
+
+pub mod latitude {
+    use super::ValidLatitude;
+    use crate::error::Error;
+
+    pub fn apply<T: ValidLatitude>(v: &T, _: ()) -> Result<(), Error> {
+        if let Err(e) = v.validate_latitude() {
+            return Err(Error::new(format!("not a valid latitude: {e}")));
+        }
+        Ok(())
+    }
+}
+
+pub mod longitude {
+    use super::ValidLongitude;
+    use crate::error::Error;
+
+    pub fn apply<T: ValidLongitude>(v: &T, _: ()) -> Result<(), Error> {
+        if let Err(e) = v.validate_longitude() {
+            return Err(Error::new(format!("not a valid longitude: {e}")));
+        }
+        Ok(())
+    }
+}
+
+pub mod coordinate_pair {
+    use super::ValidCoordinatePair;
+    use crate::error::Error;
+
+    pub fn apply<T: ValidCoordinatePair>(v: &T, _: ()) -> Result<(), Error> {
+        v.validate_coordinate_pair()
+    }
+}
+
+pub trait ValidLatitude {
+    fn validate_latitude(&self) -> Result<(), InvalidCoordinate>;
+}
+
+pub trait ValidLongitude {
+    fn validate_longitude(&self) -> Result<(), InvalidCoordinate>;
+}
+
+pub trait ValidCoordinatePair {
+    fn validate_coordinate_pair(&self) -> Result<(), Error>;
+}
+
+pub enum InvalidCoordinate {
+    NotFinite(NotFinite),
+    OutOfRange { max: f64 },
+}
+
+impl Display for InvalidCoordinate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvalidCoordinate::NotFinite(e) => write!(f, "expected a finite number, got {e}"),
+            InvalidCoordinate::OutOfRange { max } => write!(f, "must be between -{max} and {max}"),
+        }
+    }
+}
+
+fn check(value: f64, max: f64) -> Result<(), InvalidCoordinate> {
+    if value.is_nan() {
+        Err(InvalidCoordinate::NotFinite(NotFinite::NaN))
+    } else if value.is_infinite() {
+        Err(InvalidCoordinate::NotFinite(NotFinite::Infinite))
+    } else if !(-max..=max).contains(&value) {
+        Err(InvalidCoordinate::OutOfRange { max })
+    } else {
+        Ok(())
+    }
+}
+
+macro_rules! impl_for_float {
+    ($($T:ident),*) => {
+        $(
+            impl ValidLatitude for $T {
+                fn validate_latitude(&self) -> Result<(), InvalidCoordinate> {
+                    check(f64::from(*self), 90.0)
+                }
+            }
+
+            impl ValidLongitude for $T {
+                fn validate_longitude(&self) -> Result<(), InvalidCoordinate> {
+                    check(f64::from(*self), 180.0)
+                }
+            }
+        )*
+    };
+}
+
+impl_for_float!(f32, f64);
+
+impl<T: ValidLatitude> ValidLatitude for Option<T> {
+    fn validate_latitude(&self) -> Result<(), InvalidCoordinate> {
+        match self {
+            Some(value) => value.validate_latitude(),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T: ValidLongitude> ValidLongitude for Option<T> {
+    fn validate_longitude(&self) -> Result<(), InvalidCoordinate> {
+        match self {
+            Some(value) => value.validate_longitude(),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T: ValidLatitude, U: ValidLongitude> ValidCoordinatePair for (T, U) {
+    fn validate_coordinate_pair(&self) -> Result<(), Error> {
+        latitude::apply(&self.0, ())?;
+        longitude::apply(&self.1, ())
+    }
+}
+
+impl<T: ValidCoordinatePair> ValidCoordinatePair for Option<T> {
+    fn validate_coordinate_pair(&self) -> Result<(), Error> {
+        match self {
+            Some(value) => value.validate_coordinate_pair(),
+            None => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,45 @@
+//! Hexadecimal validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(hex, length(equal = 64))]
+//!     sha256: String,
+//! }
+//! ```
+//!
+//! Both lowercase and uppercase digits are accepted.
+//! An empty string contains no invalid digits, so it is accepted as well. Use `length(min = 1)` to reject it.
+//!
+//! The entrypoint is the [`ValidHex`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(hex)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+
+use super::AsStr;
+use crate::error::Error;
+
+pub fn apply<T: ValidHex>(v: &T, _: ()) -> Result<(), Error> {
+    if !v.validate_hex() {
+        return Err(Error::new("not hexadecimal"));
+    }
+    Ok(())
+}
+
+pub trait ValidHex {
+    fn validate_hex(&self) -> bool;
+}
+
+impl<T: AsStr> ValidHex for T {
+    fn validate_hex(&self) -> bool {
+        self.as_str().bytes().all(|b| b.is_ascii_hexdigit())
+    }
+}
+
+impl<T: ValidHex> ValidHex for Option<T> {
+    fn validate_hex(&self) -> bool {
+        match self {
+            Some(value) => value.validate_hex(),
+            None => true,
+        }
+    }
+}
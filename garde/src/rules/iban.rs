@@ -0,0 +1,81 @@
+//! IBAN validation using the [`iban_validate`](https://docs.rs/iban_validate) crate.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(iban)]
+//!     v: String,
+//!     #[garde(iban(country = "DE"))]
+//!     w: String,
+//! }
+//! ```
+//!
+//! Both the format and the checksum of the IBAN are validated.
+//! The `country` argument is optional. If present, the IBAN must begin with that ISO 3166-1 alpha-2 country code.
+//!
+//! The entrypoint is the [`ValidIban`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(iban)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+
+use std::fmt::Display;
+
+use iban::IbanLike as _;
+
+use super::AsStr;
+use crate::error::Error;
+
+pub fn apply<T: ValidIban>(v: &T, (country,): (Option<&str>,)) -> Result<(), Error> {
+    if let Err(e) = v.validate_iban(country) {
+        return Err(match (e, country) {
+            (InvalidIban::Country, Some(country)) => {
+                Error::new(format!("IBAN is not from country \"{country}\""))
+            }
+            (e, _) => Error::new(format!("not a valid IBAN: {e}")),
+        });
+    }
+    Ok(())
+}
+
+pub trait ValidIban {
+    fn validate_iban(&self, country: Option<&str>) -> Result<(), InvalidIban>;
+}
+
+impl<T: AsStr> ValidIban for T {
+    fn validate_iban(&self, country: Option<&str>) -> Result<(), InvalidIban> {
+        let iban = self.as_str().parse::<iban::Iban>()?;
+        match country {
+            Some(country) if iban.country_code() != country => Err(InvalidIban::Country),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<T: ValidIban> ValidIban for Option<T> {
+    fn validate_iban(&self, country: Option<&str>) -> Result<(), InvalidIban> {
+        match self {
+            Some(value) => value.validate_iban(country),
+            None => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum InvalidIban {
+    Parse(iban::ParseIbanError),
+    Country,
+}
+
+impl Display for InvalidIban {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvalidIban::Parse(e) => write!(f, "{e}"),
+            InvalidIban::Country => write!(f, "country code does not match"),
+        }
+    }
+}
+
+impl From<iban::ParseIbanError> for InvalidIban {
+    fn from(value: iban::ParseIbanError) -> Self {
+        Self::Parse(value)
+    }
+}
@@ -8,6 +8,19 @@
 //! }
 //! ```
 //!
+//! Keys and values of maps may be validated separately using `key` and `value`:
+//!
+//! ```rust
+//! # use std::collections::HashMap;
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(inner(key(alphanumeric, length(max = 50)), value(length(min = 1))))]
+//!     v: HashMap<String, String>,
+//! }
+//! ```
+//!
+//! Errors of keys are reported at `v[key:"name"]`, and errors of values at `v[value:"name"]`.
+//!
 //! The entrypoint is the [`Inner`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(inner(..))]` rule.
 
 use crate::error::{NoKey, PathComponentKind};
@@ -20,6 +33,14 @@ where
     field.validate_inner(f)
 }
 
+pub fn apply_map<T, K, V, F>(field: &T, f: F)
+where
+    T: InnerMap<K, V>,
+    F: FnMut(&K, &V),
+{
+    field.validate_inner_map(f)
+}
+
 pub trait Inner<T> {
     type Key: PathComponentKind;
 
@@ -50,6 +71,17 @@ impl<const N: usize, T> Inner<T> for [T; N] {
     }
 }
 
+impl<A: smallvec::Array> Inner<A::Item> for smallvec::SmallVec<A> {
+    type Key = usize;
+
+    fn validate_inner<F>(&self, f: F)
+    where
+        F: FnMut(&A::Item, &Self::Key),
+    {
+        self.as_slice().validate_inner(f)
+    }
+}
+
 impl<'a, T> Inner<T> for &'a [T] {
     type Key = usize;
 
@@ -75,3 +107,41 @@ impl<T> Inner<T> for Option<T> {
         }
     }
 }
+
+/// Implementing this trait for a type allows that type to be used with `#[garde(inner(key(..), value(..)))]`.
+pub trait InnerMap<K, V> {
+    fn validate_inner_map<F>(&self, f: F)
+    where
+        F: FnMut(&K, &V);
+}
+
+impl<K, V, S> InnerMap<K, V> for std::collections::HashMap<K, V, S> {
+    fn validate_inner_map<F>(&self, mut f: F)
+    where
+        F: FnMut(&K, &V),
+    {
+        for (key, value) in self.iter() {
+            f(key, value);
+        }
+    }
+}
+
+impl<K, V> InnerMap<K, V> for std::collections::BTreeMap<K, V> {
+    fn validate_inner_map<F>(&self, mut f: F)
+    where
+        F: FnMut(&K, &V),
+    {
+        for (key, value) in self.iter() {
+            f(key, value);
+        }
+    }
+}
+
+impl<K, V, T: InnerMap<K, V>> InnerMap<K, V> for &T {
+    fn validate_inner_map<F>(&self, f: F)
+    where
+        F: FnMut(&K, &V),
+    {
+        (**self).validate_inner_map(f)
+    }
+}
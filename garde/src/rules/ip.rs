@@ -10,9 +10,40 @@
 //!
 //! The entrypoint is the [`Ip`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(ip)]` rule.
 //!
-//! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`,
+//! and is also implemented for [`IpAddr`], [`Ipv4Addr`], and [`Ipv6Addr`].
+//! An address which is already parsed is always a valid `ip`, but `ipv4` and `ipv6` still check that it has the right version:
+//!
+//! ```rust
+//! use std::net::{IpAddr, Ipv4Addr};
+//!
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(ipv4)]
+//!     v: IpAddr,
+//!     #[garde(ipv4)]
+//!     w: Ipv4Addr,
+//! }
+//! ```
+//!
+//! If the `ipnet` feature is enabled, the `ip_in_subnet` rule additionally checks that the address belongs to a subnet in CIDR notation:
+//!
+//! ```rust
+//! # #[cfg(feature = "ipnet")]
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(ip_in_subnet("192.168.0.0/24"))]
+//!     v: String,
+//! }
+//! ```
+//!
+//! It is implemented in the `subnet` module.
 
 use std::fmt::Display;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+#[cfg(feature = "ipnet")]
+pub mod subnet;
 
 use super::AsStr;
 use crate::error::Error;
@@ -30,7 +61,7 @@ pub trait Ip {
     fn validate_ip(&self, kind: IpKind) -> Result<(), Self::Error>;
 }
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug)]
 pub enum IpKind {
     Any,
     V4,
@@ -54,19 +85,62 @@ impl<T: AsStr> Ip for T {
         let v = self.as_str();
         match kind {
             IpKind::Any => {
-                let _ = v.parse::<std::net::IpAddr>()?;
+                let _ = v.parse::<IpAddr>()?;
             }
             IpKind::V4 => {
-                let _ = v.parse::<std::net::Ipv4Addr>()?;
+                let _ = v.parse::<Ipv4Addr>()?;
             }
             IpKind::V6 => {
-                let _ = v.parse::<std::net::Ipv6Addr>()?;
+                let _ = v.parse::<Ipv6Addr>()?;
             }
         };
         Ok(())
     }
 }
 
+impl Ip for IpAddr {
+    type Error = WrongIpVersion;
+
+    fn validate_ip(&self, kind: IpKind) -> Result<(), Self::Error> {
+        match (kind, self) {
+            (IpKind::Any, _) | (IpKind::V4, IpAddr::V4(_)) | (IpKind::V6, IpAddr::V6(_)) => Ok(()),
+            (IpKind::V4, IpAddr::V6(_)) | (IpKind::V6, IpAddr::V4(_)) => Err(WrongIpVersion(kind)),
+        }
+    }
+}
+
+impl Ip for Ipv4Addr {
+    type Error = WrongIpVersion;
+
+    fn validate_ip(&self, kind: IpKind) -> Result<(), Self::Error> {
+        match kind {
+            IpKind::Any | IpKind::V4 => Ok(()),
+            IpKind::V6 => Err(WrongIpVersion(kind)),
+        }
+    }
+}
+
+impl Ip for Ipv6Addr {
+    type Error = WrongIpVersion;
+
+    fn validate_ip(&self, kind: IpKind) -> Result<(), Self::Error> {
+        match kind {
+            IpKind::Any | IpKind::V6 => Ok(()),
+            IpKind::V4 => Err(WrongIpVersion(kind)),
+        }
+    }
+}
+
+/// Returned when a parsed address is not of the expected [`IpKind`].
+#[derive(Debug)]
+pub struct WrongIpVersion(pub IpKind);
+
+impl Display for WrongIpVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "not an {} address", self.0)
+    }
+}
+
 impl<T: Ip> Ip for Option<T> {
     type Error = T::Error;
 
@@ -0,0 +1,83 @@
+//! Implemented by string-like types which contain an IP address within a subnet,
+//! and by [`IpAddr`], [`Ipv4Addr`], and [`Ipv6Addr`].
+//!
+//! This backs the `ip_in_subnet` rule. The subnet is parsed from CIDR notation at compile time.
+
+use std::fmt::Display;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+pub use ipnet::IpNet;
+
+use crate::error::Error;
+use crate::rules::AsStr;
+
+pub fn apply<T: IpInSubnet>(v: &T, (subnet,): (&IpNet,)) -> Result<(), Error> {
+    if let Err(e) = v.validate_ip_in_subnet(subnet) {
+        return Err(match e {
+            NotInSubnet::Parse(_) => Error::new("not a valid IP address"),
+            NotInSubnet::Outside => Error::new(format!("IP address is not in subnet {subnet}")),
+        });
+    }
+    Ok(())
+}
+
+pub trait IpInSubnet {
+    fn validate_ip_in_subnet(&self, subnet: &IpNet) -> Result<(), NotInSubnet>;
+}
+
+impl<T: AsStr> IpInSubnet for T {
+    fn validate_ip_in_subnet(&self, subnet: &IpNet) -> Result<(), NotInSubnet> {
+        let ip = self.as_str().parse::<IpAddr>()?;
+        if !subnet.contains(&ip) {
+            return Err(NotInSubnet::Outside);
+        }
+        Ok(())
+    }
+}
+
+macro_rules! impl_for_addr {
+    ($($T:ty),*) => {
+        $(
+            impl IpInSubnet for $T {
+                fn validate_ip_in_subnet(&self, subnet: &IpNet) -> Result<(), NotInSubnet> {
+                    if !subnet.contains(&IpAddr::from(*self)) {
+                        return Err(NotInSubnet::Outside);
+                    }
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
+
+impl_for_addr!(IpAddr, Ipv4Addr, Ipv6Addr);
+
+impl<T: IpInSubnet> IpInSubnet for Option<T> {
+    fn validate_ip_in_subnet(&self, subnet: &IpNet) -> Result<(), NotInSubnet> {
+        match self {
+            Some(value) => value.validate_ip_in_subnet(subnet),
+            None => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum NotInSubnet {
+    Parse(std::net::AddrParseError),
+    Outside,
+}
+
+impl Display for NotInSubnet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NotInSubnet::Parse(e) => write!(f, "{e}"),
+            NotInSubnet::Outside => write!(f, "address is not in subnet"),
+        }
+    }
+}
+
+impl From<std::net::AddrParseError> for NotInSubnet {
+    fn from(value: std::net::AddrParseError) -> Self {
+        Self::Parse(value)
+    }
+}
@@ -0,0 +1,136 @@
+//! ISBN validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(isbn)]
+//!     v: String,
+//!     #[garde(isbn(format = "isbn13"))]
+//!     w: String,
+//!     #[garde(isbn(version = 10))]
+//!     x: String,
+//! }
+//! ```
+//!
+//! By default, both ISBN-10 and ISBN-13 are accepted. The `format` argument restricts validation to either `"isbn10"` or `"isbn13"`,
+//! and `version = 10` or `version = 13` may be used instead.
+//!
+//! The check digit is verified using mod-11 for ISBN-10 and mod-10 for ISBN-13, which must also begin with `978` or `979`.
+//! Hyphens between digit groups are ignored, so `978-3-16-148410-0` and `9783161484100` are both valid.
+//! A hyphenated ISBN must have all of its groups: four for ISBN-10, and five for ISBN-13. The check digit is always the last group,
+//! and the `978` or `979` prefix of an ISBN-13 is the first group.
+//!
+//! The entrypoint is the [`ValidIsbn`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(isbn)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+
+use std::fmt::Display;
+
+use super::AsStr;
+use crate::error::Error;
+
+pub fn apply<T: ValidIsbn>(v: &T, (format,): (IsbnFormat,)) -> Result<(), Error> {
+    if !v.validate_isbn(format) {
+        return Err(Error::new(format!("not a valid {format}")));
+    }
+    Ok(())
+}
+
+pub trait ValidIsbn {
+    fn validate_isbn(&self, format: IsbnFormat) -> bool;
+}
+
+#[derive(Clone, Copy)]
+pub enum IsbnFormat {
+    Any,
+    Isbn10,
+    Isbn13,
+}
+
+impl Display for IsbnFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IsbnFormat::Any => write!(f, "ISBN"),
+            IsbnFormat::Isbn10 => write!(f, "ISBN-10"),
+            IsbnFormat::Isbn13 => write!(f, "ISBN-13"),
+        }
+    }
+}
+
+fn is_isbn10(value: &str) -> bool {
+    let Some(digits) = strip_hyphens(value, 4) else {
+        return false;
+    };
+    if digits.len() != 10 {
+        return false;
+    }
+    let mut sum = 0u32;
+    for (i, &b) in digits.iter().enumerate() {
+        let value = match b {
+            b'0'..=b'9' => (b - b'0') as u32,
+            // `X` represents a check digit of 10
+            b'X' | b'x' if i == 9 => 10,
+            _ => return false,
+        };
+        sum += value * (10 - i as u32);
+    }
+    sum % 11 == 0
+}
+
+fn is_isbn13(value: &str) -> bool {
+    let Some(digits) = strip_hyphens(value, 5) else {
+        return false;
+    };
+    if digits.len() != 13 || !digits.iter().all(u8::is_ascii_digit) {
+        return false;
+    }
+    // the prefix is its own group
+    if value.contains('-') && value.as_bytes()[3] != b'-' {
+        return false;
+    }
+    if !digits.starts_with(b"978") && !digits.starts_with(b"979") {
+        return false;
+    }
+    let sum: u32 = digits
+        .iter()
+        .enumerate()
+        .map(|(i, &b)| (b - b'0') as u32 * if i % 2 == 0 { 1 } else { 3 })
+        .sum();
+    sum % 10 == 0
+}
+
+/// Removes the hyphens from an ISBN, which must be placed between exactly `groups` non-empty groups,
+/// with the check digit in the last group.
+fn strip_hyphens(value: &str, groups: usize) -> Option<Vec<u8>> {
+    if !value.contains('-') {
+        return Some(value.as_bytes().to_vec());
+    }
+    let parts = value.split('-').collect::<Vec<_>>();
+    if parts.len() != groups
+        || parts.iter().any(|part| part.is_empty())
+        || parts[groups - 1].len() != 1
+    {
+        return None;
+    }
+    Some(parts.concat().into_bytes())
+}
+
+impl<T: AsStr> ValidIsbn for T {
+    fn validate_isbn(&self, format: IsbnFormat) -> bool {
+        let v = self.as_str();
+        match format {
+            IsbnFormat::Any => is_isbn10(v) || is_isbn13(v),
+            IsbnFormat::Isbn10 => is_isbn10(v),
+            IsbnFormat::Isbn13 => is_isbn13(v),
+        }
+    }
+}
+
+impl<T: ValidIsbn> ValidIsbn for Option<T> {
+    fn validate_isbn(&self, format: IsbnFormat) -> bool {
+        match self {
+            Some(value) => value.validate_isbn(format),
+            None => true,
+        }
+    }
+}
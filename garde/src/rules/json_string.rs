@@ -0,0 +1,112 @@
+//! JSON string validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(json_string)]
+//!     v: String,
+//!     #[garde(json_string(schema_type = "object"))]
+//!     w: String,
+//! }
+//! ```
+//!
+//! The value must be a string containing valid JSON, which is parsed using [`serde_json`].
+//! The optional `schema_type` restricts the type of the top-level JSON value, and is one of
+//! `"object"`, `"array"`, `"string"`, `"number"`, `"boolean"`, or `"null"`.
+//!
+//! The entrypoint is the [`JsonString`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(json_string)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+
+use std::fmt::Display;
+
+use super::AsStr;
+use crate::error::Error;
+
+pub fn apply<T: JsonString>(v: &T, (schema_type,): (Option<JsonType>,)) -> Result<(), Error> {
+    if let Err(e) = v.validate_json_string(schema_type) {
+        return Err(match e {
+            InvalidJson::Parse(e) => Error::new(format!("not valid JSON: {e}")),
+            InvalidJson::Type { expected, actual } => {
+                Error::new(format!("JSON value must be {expected}, found {actual}"))
+            }
+        });
+    }
+    Ok(())
+}
+
+pub trait JsonString {
+    fn validate_json_string(&self, schema_type: Option<JsonType>) -> Result<(), InvalidJson>;
+}
+
+impl<T: AsStr> JsonString for T {
+    fn validate_json_string(&self, schema_type: Option<JsonType>) -> Result<(), InvalidJson> {
+        let value = serde_json::from_str::<serde_json::Value>(self.as_str())?;
+        let actual = JsonType::of(&value);
+        match schema_type {
+            Some(expected) if expected != actual => Err(InvalidJson::Type { expected, actual }),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<T: JsonString> JsonString for Option<T> {
+    fn validate_json_string(&self, schema_type: Option<JsonType>) -> Result<(), InvalidJson> {
+        match self {
+            Some(value) => value.validate_json_string(schema_type),
+            None => Ok(()),
+        }
+    }
+}
+
+/// The type of a JSON value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JsonType {
+    Object,
+    Array,
+    String,
+    Number,
+    Boolean,
+    Null,
+}
+
+impl JsonType {
+    fn of(value: &serde_json::Value) -> Self {
+        match value {
+            serde_json::Value::Object(_) => JsonType::Object,
+            serde_json::Value::Array(_) => JsonType::Array,
+            serde_json::Value::String(_) => JsonType::String,
+            serde_json::Value::Number(_) => JsonType::Number,
+            serde_json::Value::Bool(_) => JsonType::Boolean,
+            serde_json::Value::Null => JsonType::Null,
+        }
+    }
+}
+
+impl Display for JsonType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JsonType::Object => write!(f, "an object"),
+            JsonType::Array => write!(f, "an array"),
+            JsonType::String => write!(f, "a string"),
+            JsonType::Number => write!(f, "a number"),
+            JsonType::Boolean => write!(f, "a boolean"),
+            JsonType::Null => write!(f, "null"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum InvalidJson {
+    Parse(serde_json::Error),
+    Type {
+        expected: JsonType,
+        actual: JsonType,
+    },
+}
+
+impl From<serde_json::Error> for InvalidJson {
+    fn from(value: serde_json::Error) -> Self {
+        Self::Parse(value)
+    }
+}
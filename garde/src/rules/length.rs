@@ -54,7 +54,7 @@ pub mod graphemes;
 pub use graphemes::HasGraphemes;
 
 pub mod simple;
-pub use simple::HasSimpleLength;
+pub use simple::{ExactSizeLength, HasSimpleLength};
 
 pub mod utf16;
 pub use utf16::HasUtf16CodeUnits;
@@ -63,9 +63,25 @@ use crate::error::Error;
 
 fn check_len(len: usize, min: usize, max: usize) -> Result<(), Error> {
     if len < min {
-        Err(Error::new(format!("length is lower than {min}")))
+        Err(Error::new(format!("length is lower than {min}"))
+            .with_metadata("min", min)
+            .with_metadata("actual_length", len))
     } else if len > max {
-        Err(Error::new(format!("length is greater than {max}")))
+        Err(Error::new(format!("length is greater than {max}"))
+            .with_metadata("max", max)
+            .with_metadata("actual_length", len))
+    } else {
+        Ok(())
+    }
+}
+
+fn check_exact_len(len: usize, expected: usize) -> Result<(), Error> {
+    if len != expected {
+        Err(Error::new(format!(
+            "expected a length of exactly {expected}, got {len}"
+        ))
+        .with_metadata("equal", expected)
+        .with_metadata("actual_length", len))
     } else {
         Ok(())
     }
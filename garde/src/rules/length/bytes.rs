@@ -8,14 +8,27 @@ pub fn apply<T: Bytes>(v: &T, (min, max): (usize, usize)) -> Result<(), Error> {
     v.validate_num_bytes(min, max)
 }
 
+pub fn apply_exact<T: Bytes>(v: &T, (len,): (usize,)) -> Result<(), Error> {
+    v.validate_exact_num_bytes(len)
+}
+
 pub trait Bytes {
     fn validate_num_bytes(&self, min: usize, max: usize) -> Result<(), Error>;
+
+    /// Used by `exact`. Defaults to [`Bytes::validate_num_bytes`] with `len` as both bounds.
+    fn validate_exact_num_bytes(&self, len: usize) -> Result<(), Error> {
+        self.validate_num_bytes(len, len)
+    }
 }
 
 impl<T: HasBytes> Bytes for T {
     fn validate_num_bytes(&self, min: usize, max: usize) -> Result<(), Error> {
         super::check_len(self.num_bytes(), min, max)
     }
+
+    fn validate_exact_num_bytes(&self, len: usize) -> Result<(), Error> {
+        super::check_exact_len(self.num_bytes(), len)
+    }
 }
 
 impl<T: Bytes> Bytes for Option<T> {
@@ -25,6 +38,13 @@ impl<T: Bytes> Bytes for Option<T> {
             None => Ok(()),
         }
     }
+
+    fn validate_exact_num_bytes(&self, len: usize) -> Result<(), Error> {
+        match self {
+            Some(v) => v.validate_exact_num_bytes(len),
+            None => Ok(()),
+        }
+    }
 }
 
 pub trait HasBytes {
@@ -48,6 +68,7 @@ impl_via_len!(in<'a> std::borrow::Cow<'a, str>);
 impl_via_len!(std::rc::Rc<str>);
 impl_via_len!(std::sync::Arc<str>);
 impl_via_len!(std::boxed::Box<str>);
+impl_via_len!(compact_str::CompactString);
 impl_via_len!(in<'a> &'a [u8]);
 impl_via_len!(std::rc::Rc<[u8]>);
 impl_via_len!(std::sync::Arc<[u8]>);
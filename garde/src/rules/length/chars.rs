@@ -8,14 +8,27 @@ pub fn apply<T: Chars>(v: &T, (min, max): (usize, usize)) -> Result<(), Error> {
     v.validate_num_chars(min, max)
 }
 
+pub fn apply_exact<T: Chars>(v: &T, (len,): (usize,)) -> Result<(), Error> {
+    v.validate_exact_num_chars(len)
+}
+
 pub trait Chars {
     fn validate_num_chars(&self, min: usize, max: usize) -> Result<(), Error>;
+
+    /// Used by `exact`. Defaults to [`Chars::validate_num_chars`] with `len` as both bounds.
+    fn validate_exact_num_chars(&self, len: usize) -> Result<(), Error> {
+        self.validate_num_chars(len, len)
+    }
 }
 
 impl<T: HasChars> Chars for T {
     fn validate_num_chars(&self, min: usize, max: usize) -> Result<(), Error> {
         super::check_len(self.num_chars(), min, max)
     }
+
+    fn validate_exact_num_chars(&self, len: usize) -> Result<(), Error> {
+        super::check_exact_len(self.num_chars(), len)
+    }
 }
 
 impl<T: Chars> Chars for Option<T> {
@@ -25,6 +38,13 @@ impl<T: Chars> Chars for Option<T> {
             None => Ok(()),
         }
     }
+
+    fn validate_exact_num_chars(&self, len: usize) -> Result<(), Error> {
+        match self {
+            Some(v) => v.validate_exact_num_chars(len),
+            None => Ok(()),
+        }
+    }
 }
 
 pub trait HasChars {
@@ -48,6 +68,7 @@ impl_via_chars!(in<'a> std::borrow::Cow<'a, str>);
 impl_via_chars!(std::rc::Rc<str>);
 impl_via_chars!(std::sync::Arc<str>);
 impl_via_chars!(std::boxed::Box<str>);
+impl_via_chars!(compact_str::CompactString);
 
 macro_rules! impl_via_len {
     ($(in<$lifetime:lifetime>)? $T:ty) => {
@@ -8,14 +8,27 @@ pub fn apply<T: Graphemes>(v: &T, (min, max): (usize, usize)) -> Result<(), Erro
     v.validate_num_graphemes(min, max)
 }
 
+pub fn apply_exact<T: Graphemes>(v: &T, (len,): (usize,)) -> Result<(), Error> {
+    v.validate_exact_num_graphemes(len)
+}
+
 pub trait Graphemes {
     fn validate_num_graphemes(&self, min: usize, max: usize) -> Result<(), Error>;
+
+    /// Used by `exact`. Defaults to [`Graphemes::validate_num_graphemes`] with `len` as both bounds.
+    fn validate_exact_num_graphemes(&self, len: usize) -> Result<(), Error> {
+        self.validate_num_graphemes(len, len)
+    }
 }
 
 impl<T: HasGraphemes> Graphemes for T {
     fn validate_num_graphemes(&self, min: usize, max: usize) -> Result<(), Error> {
         super::check_len(self.num_graphemes(), min, max)
     }
+
+    fn validate_exact_num_graphemes(&self, len: usize) -> Result<(), Error> {
+        super::check_exact_len(self.num_graphemes(), len)
+    }
 }
 
 impl<T: Graphemes> Graphemes for Option<T> {
@@ -25,6 +38,13 @@ impl<T: Graphemes> Graphemes for Option<T> {
             None => Ok(()),
         }
     }
+
+    fn validate_exact_num_graphemes(&self, len: usize) -> Result<(), Error> {
+        match self {
+            Some(v) => v.validate_exact_num_graphemes(len),
+            None => Ok(()),
+        }
+    }
 }
 
 pub trait HasGraphemes {
@@ -50,3 +70,4 @@ impl_str!(in<'a> std::borrow::Cow<'a, str>);
 impl_str!(std::rc::Rc<str>);
 impl_str!(std::sync::Arc<str>);
 impl_str!(std::boxed::Box<str>);
+impl_str!(compact_str::CompactString);
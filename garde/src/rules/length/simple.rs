@@ -9,14 +9,27 @@ pub fn apply<T: Simple>(v: &T, (min, max): (usize, usize)) -> Result<(), Error>
     v.validate_length(min, max)
 }
 
+pub fn apply_exact<T: Simple>(v: &T, (len,): (usize,)) -> Result<(), Error> {
+    v.validate_exact_length(len)
+}
+
 pub trait Simple {
     fn validate_length(&self, min: usize, max: usize) -> Result<(), Error>;
+
+    /// Used by `exact`. Defaults to [`Simple::validate_length`] with `len` as both bounds.
+    fn validate_exact_length(&self, len: usize) -> Result<(), Error> {
+        self.validate_length(len, len)
+    }
 }
 
 impl<T: HasSimpleLength> Simple for T {
     fn validate_length(&self, min: usize, max: usize) -> Result<(), Error> {
         super::check_len(self.length(), min, max)
     }
+
+    fn validate_exact_length(&self, len: usize) -> Result<(), Error> {
+        super::check_exact_len(self.length(), len)
+    }
 }
 
 impl<T: Simple> Simple for Option<T> {
@@ -26,12 +39,61 @@ impl<T: Simple> Simple for Option<T> {
             None => Ok(()),
         }
     }
+
+    fn validate_exact_length(&self, len: usize) -> Result<(), Error> {
+        match self {
+            Some(v) => v.validate_exact_length(len),
+            None => Ok(()),
+        }
+    }
 }
 
 pub trait HasSimpleLength {
     fn length(&self) -> usize;
 }
 
+/// Opts an [`ExactSizeIterator`] into [`HasSimpleLength`], using [`ExactSizeIterator::len`] as its length.
+///
+/// A blanket implementation for every [`ExactSizeIterator`] would conflict with the implementations
+/// for types such as `&str`, so this marker trait has to be implemented explicitly:
+///
+/// ```rust
+/// #[derive(Clone)]
+/// struct Items(std::vec::IntoIter<u32>);
+///
+/// impl Iterator for Items {
+///     type Item = u32;
+///
+///     fn next(&mut self) -> Option<u32> {
+///         self.0.next()
+///     }
+///
+///     fn size_hint(&self) -> (usize, Option<usize>) {
+///         self.0.size_hint()
+///     }
+/// }
+///
+/// impl ExactSizeIterator for Items {}
+///
+/// impl garde::rules::length::ExactSizeLength for Items {}
+///
+/// #[derive(garde::Validate)]
+/// struct Test {
+///     #[garde(length(min = 1))]
+///     items: Items,
+/// }
+/// ```
+///
+/// Use this for types which already implement [`ExactSizeIterator`]. For anything else, such as
+/// collections which only produce an iterator, implement [`HasSimpleLength`] manually.
+pub trait ExactSizeLength: ExactSizeIterator {}
+
+impl<T: ExactSizeLength> HasSimpleLength for T {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
 macro_rules! impl_via_bytes {
     ($(in<$lifetime:lifetime>)? $T:ty) => {
         impl<$($lifetime)?> HasSimpleLength for $T {
@@ -50,6 +112,7 @@ impl_via_bytes!(in<'a> std::borrow::Cow<'a, str>);
 impl_via_bytes!(std::rc::Rc<str>);
 impl_via_bytes!(std::sync::Arc<str>);
 impl_via_bytes!(std::boxed::Box<str>);
+impl_via_bytes!(compact_str::CompactString);
 
 macro_rules! impl_via_len {
     (in<$lifetime:lifetime, $($generic:ident),*> $T:ty) => {
@@ -86,6 +149,18 @@ impl_via_len!(in<T> Vec<T>);
 impl_via_len!(in<'a, T> &'a Vec<T>);
 impl_via_len!(in<'a, T> &'a [T]);
 
+impl<A: smallvec::Array> HasSimpleLength for smallvec::SmallVec<A> {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<'a, T: Clone> HasSimpleLength for std::borrow::Cow<'a, [T]> {
+    fn length(&self) -> usize {
+        self.len()
+    }
+}
+
 impl<const N: usize, T> Simple for [T; N] {
     fn validate_length(&self, min: usize, max: usize) -> Result<(), Error> {
         super::check_len(self.len(), min, max)
@@ -6,14 +6,27 @@ pub fn apply<T: Utf16CodeUnits>(v: &T, (min, max): (usize, usize)) -> Result<(),
     v.validate_num_code_units(min, max)
 }
 
+pub fn apply_exact<T: Utf16CodeUnits>(v: &T, (len,): (usize,)) -> Result<(), Error> {
+    v.validate_exact_num_code_units(len)
+}
+
 pub trait Utf16CodeUnits {
     fn validate_num_code_units(&self, min: usize, max: usize) -> Result<(), Error>;
+
+    /// Used by `exact`. Defaults to [`Utf16CodeUnits::validate_num_code_units`] with `len` as both bounds.
+    fn validate_exact_num_code_units(&self, len: usize) -> Result<(), Error> {
+        self.validate_num_code_units(len, len)
+    }
 }
 
 impl<T: HasUtf16CodeUnits> Utf16CodeUnits for T {
     fn validate_num_code_units(&self, min: usize, max: usize) -> Result<(), Error> {
         super::check_len(self.num_code_units(), min, max)
     }
+
+    fn validate_exact_num_code_units(&self, len: usize) -> Result<(), Error> {
+        super::check_exact_len(self.num_code_units(), len)
+    }
 }
 
 impl<T: Utf16CodeUnits> Utf16CodeUnits for Option<T> {
@@ -23,6 +36,13 @@ impl<T: Utf16CodeUnits> Utf16CodeUnits for Option<T> {
             None => Ok(()),
         }
     }
+
+    fn validate_exact_num_code_units(&self, len: usize) -> Result<(), Error> {
+        match self {
+            Some(v) => v.validate_exact_num_code_units(len),
+            None => Ok(()),
+        }
+    }
 }
 
 pub trait HasUtf16CodeUnits {
@@ -46,3 +66,4 @@ impl_str!(in<'a> std::borrow::Cow<'a, str>);
 impl_str!(std::rc::Rc<str>);
 impl_str!(std::sync::Arc<str>);
 impl_str!(std::boxed::Box<str>);
+impl_str!(compact_str::CompactString);
@@ -0,0 +1,88 @@
+//! Luhn checksum validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(luhn, length(equal = 15))]
+//!     imei: String,
+//! }
+//! ```
+//!
+//! The value must consist of at least two ASCII digits, the last of which is a check digit
+//! computed using the [Luhn algorithm](https://en.wikipedia.org/wiki/Luhn_algorithm).
+//! This is used by IMEI codes, Canadian Social Insurance Numbers, and many other identifiers.
+//! Separators such as spaces or hyphens are not accepted.
+//!
+//! Unlike the `credit_card` rule, this does not check the length or issuer of the number.
+//!
+//! The entrypoint is the [`ValidLuhn`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(luhn)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+
+use std::fmt::Display;
+
+use super::AsStr;
+use crate::error::Error;
+
+pub fn apply<T: ValidLuhn>(v: &T, _: ()) -> Result<(), Error> {
+    if let Err(e) = v.validate_luhn() {
+        return Err(Error::new(format!("not a valid Luhn number: {e}")));
+    }
+    Ok(())
+}
+
+pub trait ValidLuhn {
+    fn validate_luhn(&self) -> Result<(), InvalidLuhn>;
+}
+
+impl<T: AsStr> ValidLuhn for T {
+    fn validate_luhn(&self) -> Result<(), InvalidLuhn> {
+        let value = self.as_str();
+        if !value.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(InvalidLuhn::InvalidCharacter);
+        }
+        if value.len() < 2 {
+            return Err(InvalidLuhn::TooShort);
+        }
+        let sum: u32 = value
+            .bytes()
+            .rev()
+            .map(|b| u32::from(b - b'0'))
+            .enumerate()
+            .map(|(i, digit)| match i % 2 {
+                0 => digit,
+                _ if digit > 4 => digit * 2 - 9,
+                _ => digit * 2,
+            })
+            .sum();
+        if sum % 10 != 0 {
+            return Err(InvalidLuhn::Checksum);
+        }
+        Ok(())
+    }
+}
+
+impl<T: ValidLuhn> ValidLuhn for Option<T> {
+    fn validate_luhn(&self) -> Result<(), InvalidLuhn> {
+        match self {
+            Some(value) => value.validate_luhn(),
+            None => Ok(()),
+        }
+    }
+}
+
+pub enum InvalidLuhn {
+    InvalidCharacter,
+    TooShort,
+    Checksum,
+}
+
+impl Display for InvalidLuhn {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvalidLuhn::InvalidCharacter => write!(f, "must only contain digits"),
+            InvalidLuhn::TooShort => write!(f, "must contain at least two digits"),
+            InvalidLuhn::Checksum => write!(f, "invalid check digit"),
+        }
+    }
+}
@@ -0,0 +1,80 @@
+//! MAC address validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(mac_address)]
+//!     v: String,
+//!     #[garde(mac_address(format = "colon"))]
+//!     w: String,
+//! }
+//! ```
+//!
+//! By default, both colon-separated (`00:1A:2B:3C:4D:5E`) and hyphen-separated (`00-1A-2B-3C-4D-5E`)
+//! notations are accepted. The `format` argument restricts validation to either `"colon"` or `"hyphen"`.
+//!
+//! The entrypoint is the [`ValidMacAddress`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(mac_address)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+
+use std::fmt::Display;
+
+use super::AsStr;
+use crate::error::Error;
+
+pub fn apply<T: ValidMacAddress>(v: &T, (format,): (MacFormat,)) -> Result<(), Error> {
+    if !v.validate_mac_address(format) {
+        return Err(Error::new(format!("not a valid {format}")));
+    }
+    Ok(())
+}
+
+pub trait ValidMacAddress {
+    fn validate_mac_address(&self, format: MacFormat) -> bool;
+}
+
+#[derive(Clone, Copy)]
+pub enum MacFormat {
+    Any,
+    Colon,
+    Hyphen,
+}
+
+impl Display for MacFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MacFormat::Any => write!(f, "MAC address"),
+            MacFormat::Colon => write!(f, "colon-separated MAC address"),
+            MacFormat::Hyphen => write!(f, "hyphen-separated MAC address"),
+        }
+    }
+}
+
+fn is_mac_address(v: &str, separator: u8) -> bool {
+    // `XX?XX?XX?XX?XX?XX`, where `?` is the separator
+    v.len() == 17
+        && v.bytes().enumerate().all(|(i, b)| match i % 3 {
+            2 => b == separator,
+            _ => b.is_ascii_hexdigit(),
+        })
+}
+
+impl<T: AsStr> ValidMacAddress for T {
+    fn validate_mac_address(&self, format: MacFormat) -> bool {
+        let v = self.as_str();
+        match format {
+            MacFormat::Any => is_mac_address(v, b':') || is_mac_address(v, b'-'),
+            MacFormat::Colon => is_mac_address(v, b':'),
+            MacFormat::Hyphen => is_mac_address(v, b'-'),
+        }
+    }
+}
+
+impl<T: ValidMacAddress> ValidMacAddress for Option<T> {
+    fn validate_mac_address(&self, format: MacFormat) -> bool {
+        match self {
+            Some(value) => value.validate_mac_address(format),
+            None => true,
+        }
+    }
+}
@@ -18,7 +18,9 @@ use crate::Error;
 
 pub fn apply<T: Matches<O>, O>(v: &T, (field, value): (&str, &O)) -> Result<(), Error> {
     if !v.validate_matches(value) {
-        return Err(Error::new(format!("does not match {field} field")));
+        return Err(
+            Error::new(format!("does not match {field} field")).with_metadata("field", field)
+        );
     }
     Ok(())
 }
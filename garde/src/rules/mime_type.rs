@@ -0,0 +1,183 @@
+//! MIME type validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(mime_type)]
+//!     v: String,
+//!     #[garde(mime_type(allowed = ["image/jpeg", "image/png"]))]
+//!     w: String,
+//! }
+//! ```
+//!
+//! A MIME type has the form `type/subtype`, e.g. `image/jpeg`, as described by [RFC 6838](https://datatracker.ietf.org/doc/html/rfc6838#section-4.2).
+//! It may be followed by parameters, e.g. `text/plain; charset=utf-8`, whose values are either tokens or quoted strings.
+//!
+//! If `allowed` is specified, the `type/subtype` part must also be equal to one of the listed values.
+//! The comparison ignores ASCII case and parameters, so `Text/Plain; charset=utf-8` is allowed by `text/plain`.
+//!
+//! The entrypoint is the [`ValidMimeType`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(mime_type)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+
+use std::fmt::Display;
+
+use super::AsStr;
+use crate::error::Error;
+
+pub fn apply<T: ValidMimeType>(v: &T, (allowed,): (&[&str],)) -> Result<(), Error> {
+    if let Err(e) = v.validate_mime_type(allowed) {
+        return Err(match e {
+            InvalidMimeType::NotAllowed => {
+                Error::new(format!("MIME type must be one of: {}", allowed.join(", ")))
+            }
+            e => Error::new(format!("not a valid MIME type: {e}")),
+        });
+    }
+    Ok(())
+}
+
+pub trait ValidMimeType {
+    /// Validates the MIME type.
+    ///
+    /// If `allowed` is not empty, the `type/subtype` part must be equal to one of them, ignoring ASCII case.
+    fn validate_mime_type(&self, allowed: &[&str]) -> Result<(), InvalidMimeType>;
+}
+
+impl<T: AsStr> ValidMimeType for T {
+    fn validate_mime_type(&self, allowed: &[&str]) -> Result<(), InvalidMimeType> {
+        let essence = parse_mime_type(self.as_str())?;
+        if !allowed.is_empty() && !allowed.iter().any(|v| v.eq_ignore_ascii_case(essence)) {
+            return Err(InvalidMimeType::NotAllowed);
+        }
+        Ok(())
+    }
+}
+
+impl<T: ValidMimeType> ValidMimeType for Option<T> {
+    fn validate_mime_type(&self, allowed: &[&str]) -> Result<(), InvalidMimeType> {
+        match self {
+            Some(value) => value.validate_mime_type(allowed),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Parses the value, and returns its `type/subtype` part.
+fn parse_mime_type(value: &str) -> Result<&str, InvalidMimeType> {
+    let (essence, mut params) = value.split_once(';').unwrap_or((value, ""));
+    let essence = essence.trim_end_matches([' ', '\t']);
+
+    let Some((type_, subtype)) = essence.split_once('/') else {
+        return Err(InvalidMimeType::MissingSubtype);
+    };
+    if !is_restricted_name(type_) {
+        return Err(InvalidMimeType::InvalidType);
+    }
+    if !is_restricted_name(subtype) {
+        return Err(InvalidMimeType::InvalidSubtype);
+    }
+
+    while !params.is_empty() {
+        let rest = parse_parameter(params.trim_start_matches([' ', '\t']))
+            .ok_or(InvalidMimeType::InvalidParameter)?;
+        let rest = rest.trim_start_matches([' ', '\t']);
+        params = match rest.strip_prefix(';') {
+            Some(rest) => rest,
+            None if rest.is_empty() => rest,
+            None => return Err(InvalidMimeType::InvalidParameter),
+        };
+    }
+
+    Ok(essence)
+}
+
+/// `restricted-name` from RFC 6838, section 4.2.
+fn is_restricted_name(value: &str) -> bool {
+    let bytes = value.as_bytes();
+    match bytes.first() {
+        Some(b) if b.is_ascii_alphanumeric() => {}
+        _ => return false,
+    }
+    bytes.len() <= 127
+        && bytes.iter().all(|&b| {
+            b.is_ascii_alphanumeric()
+                || matches!(
+                    b,
+                    b'!' | b'#' | b'$' | b'&' | b'-' | b'^' | b'_' | b'.' | b'+'
+                )
+        })
+}
+
+/// `token` from RFC 9110, section 5.6.2.
+fn is_token_char(b: u8) -> bool {
+    b.is_ascii_alphanumeric()
+        || matches!(
+            b,
+            b'!' | b'#'
+                | b'$'
+                | b'%'
+                | b'&'
+                | b'\''
+                | b'*'
+                | b'+'
+                | b'-'
+                | b'.'
+                | b'^'
+                | b'_'
+                | b'`'
+                | b'|'
+                | b'~'
+        )
+}
+
+/// Parses a `name=value` parameter, and returns the remaining input.
+fn parse_parameter(input: &str) -> Option<&str> {
+    let name_len = input.bytes().take_while(|&b| is_token_char(b)).count();
+    if name_len == 0 {
+        return None;
+    }
+    let rest = input[name_len..].strip_prefix('=')?;
+
+    let rest = match rest.strip_prefix('"') {
+        Some(quoted) => {
+            let mut escaped = false;
+            let end = quoted.bytes().position(|b| {
+                let end = !escaped && b == b'"';
+                escaped = !escaped && b == b'\\';
+                end
+            })?;
+            &quoted[end + 1..]
+        }
+        None => {
+            let value_len = rest.bytes().take_while(|&b| is_token_char(b)).count();
+            if value_len == 0 {
+                return None;
+            }
+            &rest[value_len..]
+        }
+    };
+
+    Some(rest)
+}
+
+#[derive(Debug)]
+pub enum InvalidMimeType {
+    MissingSubtype,
+    InvalidType,
+    InvalidSubtype,
+    InvalidParameter,
+    NotAllowed,
+}
+
+impl Display for InvalidMimeType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvalidMimeType::MissingSubtype => write!(f, "value is missing a subtype"),
+            InvalidMimeType::InvalidType => write!(f, "value has an invalid type"),
+            InvalidMimeType::InvalidSubtype => write!(f, "value has an invalid subtype"),
+            InvalidMimeType::InvalidParameter => write!(f, "value has an invalid parameter"),
+            InvalidMimeType::NotAllowed => write!(f, "value is not one of the allowed types"),
+        }
+    }
+}
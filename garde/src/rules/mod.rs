@@ -1,25 +1,72 @@
 //! ## Validation rules
 
+pub mod alphabetic;
 pub mod alphanumeric;
 pub mod ascii;
+#[cfg(feature = "base64")]
+pub mod base64;
+pub mod case;
+#[cfg(feature = "cidr")]
+pub mod cidr;
 pub mod contains;
+#[cfg(feature = "country-code")]
+pub mod country_code;
 #[cfg(feature = "credit-card")]
 pub mod credit_card;
+#[cfg(feature = "date")]
+pub mod date;
+#[cfg(feature = "date")]
+pub mod datetime;
+pub mod domain;
 #[cfg(feature = "email")]
 pub mod email;
+pub mod finite;
+pub mod geolocation;
+pub mod hex;
+#[cfg(feature = "iban")]
+pub mod iban;
 pub mod inner;
 pub mod ip;
+pub mod isbn;
+#[cfg(feature = "serde_json")]
+pub mod json_string;
 pub mod length;
+pub mod luhn;
+pub mod mac_address;
 pub mod matches;
+pub mod mime_type;
+pub mod multiple_of;
+pub mod non_default;
+pub mod numeric_string;
+pub mod one_of;
+#[cfg(feature = "password")]
+pub mod password;
 pub mod pattern;
 #[cfg(feature = "phone-number")]
 pub mod phone_number;
+pub mod port;
+pub mod precision;
 pub mod prefix;
 pub mod range;
 pub mod required;
+pub mod rule;
+#[cfg(feature = "semver")]
+pub mod semver;
+pub mod slug;
+pub mod sorted;
 pub mod suffix;
+#[cfg(feature = "date")]
+pub mod time;
+#[cfg(feature = "tz")]
+pub mod timezone;
+pub mod unique;
 #[cfg(feature = "url")]
 pub mod url;
+#[cfg(feature = "uuid")]
+pub mod uuid;
+pub mod whitespace;
+
+pub use self::rule::{All, Any, Rule};
 
 pub trait AsStr {
     fn as_str(&self) -> &str;
@@ -42,3 +89,9 @@ impl<'a> AsStr for std::borrow::Cow<'a, str> {
         std::borrow::Cow::as_ref(self)
     }
 }
+
+impl AsStr for compact_str::CompactString {
+    fn as_str(&self) -> &str {
+        compact_str::CompactString::as_str(self)
+    }
+}
@@ -0,0 +1,94 @@
+//! Multiple validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(range(min = 6), multiple_of(6))]
+//!     v: u64,
+//!     #[garde(multiple_of(0.25))]
+//!     w: f64,
+//! }
+//! ```
+//!
+//! For floating-point numbers, the check allows for a small rounding error,
+//! so that e.g. `0.3` is considered a multiple of `0.1`.
+//!
+//! The entrypoint is the [`MultipleOf`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(multiple_of(...))]` rule.
+//!
+//! This trait is implemented for all primitive integer and floating-point types.
+
+use std::fmt::Display;
+
+use crate::error::Error;
+
+pub fn apply<T: MultipleOf>(v: &T, (divisor,): (T::Size,)) -> Result<(), Error> {
+    if !v.validate_multiple_of(divisor) {
+        return Err(
+            Error::new(format!("not a multiple of {divisor}")).with_metadata("divisor", divisor)
+        );
+    }
+    Ok(())
+}
+
+pub trait MultipleOf {
+    type Size: Copy + Display;
+
+    fn validate_multiple_of(&self, divisor: Self::Size) -> bool;
+}
+
+macro_rules! impl_for_int {
+    ($($T:ident),*) => {
+        $(
+            impl MultipleOf for $T {
+                type Size = $T;
+
+                fn validate_multiple_of(&self, divisor: Self::Size) -> bool {
+                    match divisor {
+                        // only zero is a multiple of zero
+                        0 => *self == 0,
+                        // `MIN % -1` overflows, but `MIN` is a multiple of `-1`
+                        _ => self.checked_rem(divisor).map_or(true, |r| r == 0),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_for_int!(u8, u16, u32, u64, usize, u128, i8, i16, i32, i64, isize, i128);
+
+macro_rules! impl_for_float {
+    ($($T:ident),*) => {
+        $(
+            impl MultipleOf for $T {
+                type Size = $T;
+
+                fn validate_multiple_of(&self, divisor: Self::Size) -> bool {
+                    if divisor == 0.0 {
+                        return *self == 0.0;
+                    }
+                    let quotient = self / divisor;
+                    if !quotient.is_finite() {
+                        return false;
+                    }
+                    // tolerance is relative to the magnitude of the quotient
+                    let epsilon = $T::EPSILON * 4.0 * quotient.abs().max(1.0);
+                    (quotient - quotient.round()).abs() <= epsilon
+                }
+            }
+        )*
+    };
+}
+
+impl_for_float!(f32, f64);
+
+impl<T: MultipleOf> MultipleOf for Option<T> {
+    type Size = T::Size;
+
+    fn validate_multiple_of(&self, divisor: Self::Size) -> bool {
+        match self {
+            Some(value) => value.validate_multiple_of(divisor),
+            None => true,
+        }
+    }
+}
@@ -0,0 +1,39 @@
+//! Non-default value validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(non_default)]
+//!     id: u64,
+//!     #[garde(non_default)]
+//!     name: String,
+//!     #[garde(inner(non_default))]
+//!     quantity: Option<u32>,
+//! }
+//! ```
+//!
+//! The value must not be equal to `Default::default()`, such as `0` for numbers, `""` for strings, or an empty `Vec`.
+//! For an `Option`, the default is `None`, so `None` is rejected. Use `inner(non_default)` to only check the value when it is present.
+//!
+//! The entrypoint is the [`IsNonDefault`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(non_default)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: Default + PartialEq`.
+
+use crate::error::Error;
+
+pub fn apply<T: IsNonDefault>(v: &T, _: ()) -> Result<(), Error> {
+    if !v.is_non_default() {
+        return Err(Error::new("value must not be the default value"));
+    }
+    Ok(())
+}
+
+pub trait IsNonDefault {
+    fn is_non_default(&self) -> bool;
+}
+
+impl<T: Default + PartialEq> IsNonDefault for T {
+    fn is_non_default(&self) -> bool {
+        *self != T::default()
+    }
+}
@@ -0,0 +1,115 @@
+//! String-encoded number validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(numeric(u64))]
+//!     v: String,
+//!     #[garde(numeric(f64, finite, positive))]
+//!     w: String,
+//! }
+//! ```
+//!
+//! The first argument is the type the string is parsed as, using its `FromStr` implementation.
+//! The parsed value may then be checked with any of the following rules, which behave the same as the rules of the same name:
+//! - `finite`, which only has an effect on floating-point numbers. Note that `"inf"` and `"NaN"` parse successfully as floats.
+//! - One of `positive`, `non_negative`, `negative`, or `non_positive`.
+//!
+//! The entrypoint is the [`ParseNumeric`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(numeric(...))]` rule.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+//! The numbers which may be parsed implement the [`Number`] trait, which is implemented for all primitive integer and floating-point types.
+
+use std::marker::PhantomData;
+
+use super::finite::NotFinite;
+use super::range::sign::{Sign, Signed};
+use super::AsStr;
+use crate::error::Error;
+
+pub fn apply<T: ParseNumeric, N: Number>(
+    v: &T,
+    (_, finite, sign): (PhantomData<N>, bool, Option<Sign>),
+) -> Result<(), Error> {
+    let value = match v.parse_numeric::<N>() {
+        Ok(Some(value)) => value,
+        Ok(None) => return Ok(()),
+        Err(e) => return Err(Error::new(format!("not a valid `{}`: {e}", N::NAME))),
+    };
+    if finite {
+        if let Err(e) = value.validate_finite() {
+            return Err(Error::new(format!("expected a finite number, got {e}")));
+        }
+    }
+    if let Some(sign) = sign {
+        super::range::sign::apply(&value, (sign,))?;
+    }
+    Ok(())
+}
+
+pub trait ParseNumeric {
+    /// Parses the value as `N`, or returns `None` if there is no value to parse.
+    fn parse_numeric<N: Number>(&self) -> Result<Option<N>, String>;
+}
+
+impl<T: AsStr> ParseNumeric for T {
+    fn parse_numeric<N: Number>(&self) -> Result<Option<N>, String> {
+        N::parse_number(self.as_str()).map(Some)
+    }
+}
+
+impl<T: ParseNumeric> ParseNumeric for Option<T> {
+    fn parse_numeric<N: Number>(&self) -> Result<Option<N>, String> {
+        match self {
+            Some(value) => value.parse_numeric(),
+            None => Ok(None),
+        }
+    }
+}
+
+pub trait Number: Signed + Sized {
+    /// The name of the type, used in error messages.
+    const NAME: &'static str;
+
+    fn parse_number(value: &str) -> Result<Self, String>;
+
+    fn validate_finite(&self) -> Result<(), NotFinite> {
+        Ok(())
+    }
+}
+
+macro_rules! impl_for_int {
+    ($($T:ident),*) => {
+        $(
+            impl Number for $T {
+                const NAME: &'static str = stringify!($T);
+
+                fn parse_number(value: &str) -> Result<Self, String> {
+                    value.parse().map_err(|e: std::num::ParseIntError| e.to_string())
+                }
+            }
+        )*
+    };
+}
+
+impl_for_int!(i8, i16, i32, i64, isize, i128, u8, u16, u32, u64, usize, u128);
+
+macro_rules! impl_for_float {
+    ($($T:ident),*) => {
+        $(
+            impl Number for $T {
+                const NAME: &'static str = stringify!($T);
+
+                fn parse_number(value: &str) -> Result<Self, String> {
+                    value.parse().map_err(|e: std::num::ParseFloatError| e.to_string())
+                }
+
+                fn validate_finite(&self) -> Result<(), NotFinite> {
+                    super::finite::IsFinite::validate_finite(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_for_float!(f32, f64);
@@ -0,0 +1,92 @@
+//! Set membership validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(one_of(["production", "staging", "development"]))]
+//!     v: String,
+//!     #[garde(one_of([1, 2, 4, 8]))]
+//!     w: u32,
+//!     #[garde(not_one_of(["admin", "root"]))]
+//!     x: String,
+//! }
+//! ```
+//!
+//! The `not_one_of` rule inverts the check, and is implemented in the [`not`] module.
+//!
+//! The entrypoint is the [`OneOf`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(one_of(...))]` rule.
+//!
+//! This trait is implemented for all primitive numeric types, `bool`, `char`,
+//! and has a blanket implementation for all `T: garde::rules::AsStr` with `&str` values.
+
+use std::fmt::Debug;
+
+use super::AsStr;
+use crate::error::Error;
+
+pub fn apply<T: OneOf<U>, U: Debug>(v: &T, (values,): (&[U],)) -> Result<(), Error> {
+    if !v.validate_one_of(values) {
+        return Err(Error::new(format!("must be one of {values:?}")));
+    }
+    Ok(())
+}
+
+pub mod not {
+    use std::fmt::Debug;
+
+    use super::OneOf;
+    use crate::error::Error;
+
+    pub fn apply<T: OneOf<U>, U: Debug>(v: &T, (values,): (&[U],)) -> Result<(), Error> {
+        if !v.validate_not_one_of(values) {
+            return Err(Error::new(format!("must not be one of {values:?}")));
+        }
+        Ok(())
+    }
+}
+
+pub trait OneOf<U> {
+    fn validate_one_of(&self, values: &[U]) -> bool;
+
+    fn validate_not_one_of(&self, values: &[U]) -> bool {
+        !self.validate_one_of(values)
+    }
+}
+
+impl<'a, T: AsStr> OneOf<&'a str> for T {
+    fn validate_one_of(&self, values: &[&'a str]) -> bool {
+        values.contains(&self.as_str())
+    }
+}
+
+macro_rules! impl_for_primitive {
+    ($($T:ident),*) => {
+        $(
+            impl OneOf<$T> for $T {
+                fn validate_one_of(&self, values: &[$T]) -> bool {
+                    values.contains(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_for_primitive!(
+    u8, u16, u32, u64, usize, u128, i8, i16, i32, i64, isize, i128, f32, f64, bool, char
+);
+
+impl<T: OneOf<U>, U> OneOf<U> for Option<T> {
+    fn validate_one_of(&self, values: &[U]) -> bool {
+        match self {
+            Some(value) => value.validate_one_of(values),
+            None => true,
+        }
+    }
+
+    fn validate_not_one_of(&self, values: &[U]) -> bool {
+        match self {
+            Some(value) => value.validate_not_one_of(values),
+            None => true,
+        }
+    }
+}
@@ -0,0 +1,222 @@
+//! Password strength validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(password(require_uppercase, require_lowercase, require_digit, require_special))]
+//!     v: String,
+//!     #[garde(password(min_entropy_bits = 40.0, min_complexity_score = 3))]
+//!     w: String,
+//! }
+//! ```
+//!
+//! The arguments may be combined, and at least one is required:
+//! - `require_uppercase`, `require_lowercase`, `require_digit`, `require_special`: the password must contain
+//!   at least one character of the given category. Special characters are any characters which are not
+//!   alphanumeric and not whitespace.
+//! - `min_complexity_score`: the password must contain characters from at least this many of the four
+//!   categories above, so it is a number between `1` and `4`.
+//! - `min_entropy_bits`: the estimated entropy of the password must be at least this many bits.
+//!
+//! The entropy is estimated from the length of the password and the size of the character pool it draws from,
+//! i.e. `length * log2(pool)`. This is a lightweight estimate, which does not detect dictionary words or
+//! repeated patterns, so it should be combined with other checks where that matters.
+//!
+//! The entrypoint is the [`Password`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(password(...))]` rule.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+//!
+//! If the `common-passwords` feature is enabled, the `not_common_password` rule additionally rejects
+//! passwords which are on a list of commonly used passwords:
+//!
+//! ```rust
+//! # #[cfg(feature = "common-passwords")]
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(not_common_password)]
+//!     v: String,
+//!     #[garde(not_common_password(case_insensitive))]
+//!     w: String,
+//! }
+//! ```
+//!
+//! It is implemented in the `common` module.
+
+use std::fmt::Display;
+
+#[cfg(feature = "common-passwords")]
+pub mod common;
+
+use super::AsStr;
+use crate::error::Error;
+
+pub fn apply<T: Password>(v: &T, (requirements,): (&Requirements,)) -> Result<(), Error> {
+    if let Err(e) = v.validate_password(requirements) {
+        return Err(Error::new(format!("password is too weak: {e}")));
+    }
+    Ok(())
+}
+
+/// The requirements a password must meet.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Requirements {
+    pub min_entropy_bits: Option<f64>,
+    pub require_uppercase: bool,
+    pub require_lowercase: bool,
+    pub require_digit: bool,
+    pub require_special: bool,
+    pub min_complexity_score: Option<u8>,
+}
+
+pub trait Password {
+    fn validate_password(&self, requirements: &Requirements) -> Result<(), WeakPassword>;
+}
+
+impl<T: AsStr> Password for T {
+    fn validate_password(&self, requirements: &Requirements) -> Result<(), WeakPassword> {
+        let stats = Stats::of(self.as_str());
+
+        let mut unmet = Vec::new();
+        if requirements.require_uppercase && !stats.uppercase {
+            unmet.push(Requirement::Uppercase);
+        }
+        if requirements.require_lowercase && !stats.lowercase {
+            unmet.push(Requirement::Lowercase);
+        }
+        if requirements.require_digit && !stats.digit {
+            unmet.push(Requirement::Digit);
+        }
+        if requirements.require_special && !stats.special {
+            unmet.push(Requirement::Special);
+        }
+        if let Some(min) = requirements.min_complexity_score {
+            let score = stats.complexity_score();
+            if score < min {
+                unmet.push(Requirement::Complexity { min, score });
+            }
+        }
+        if let Some(min) = requirements.min_entropy_bits {
+            let bits = stats.entropy_bits();
+            if bits < min {
+                unmet.push(Requirement::Entropy { min, bits });
+            }
+        }
+
+        match unmet.is_empty() {
+            true => Ok(()),
+            false => Err(WeakPassword(unmet)),
+        }
+    }
+}
+
+impl<T: Password> Password for Option<T> {
+    fn validate_password(&self, requirements: &Requirements) -> Result<(), WeakPassword> {
+        match self {
+            Some(value) => value.validate_password(requirements),
+            None => Ok(()),
+        }
+    }
+}
+
+/// The requirements which a password did not meet.
+#[derive(Debug)]
+pub struct WeakPassword(pub Vec<Requirement>);
+
+impl Display for WeakPassword {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, requirement) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{requirement}")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub enum Requirement {
+    Uppercase,
+    Lowercase,
+    Digit,
+    Special,
+    Complexity { min: u8, score: u8 },
+    Entropy { min: f64, bits: f64 },
+}
+
+impl Display for Requirement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Requirement::Uppercase => write!(f, "must contain an uppercase letter"),
+            Requirement::Lowercase => write!(f, "must contain a lowercase letter"),
+            Requirement::Digit => write!(f, "must contain a digit"),
+            Requirement::Special => write!(f, "must contain a special character"),
+            Requirement::Complexity { min, score } => write!(
+                f,
+                "must contain at least {min} of uppercase letters, lowercase letters, digits, and special characters, but contains {score}"
+            ),
+            Requirement::Entropy { min, bits } => write!(
+                f,
+                "must have at least {min} bits of entropy, but has {bits:.1}"
+            ),
+        }
+    }
+}
+
+#[derive(Default)]
+struct Stats {
+    len: usize,
+    uppercase: bool,
+    lowercase: bool,
+    digit: bool,
+    special: bool,
+    other: bool,
+}
+
+impl Stats {
+    fn of(value: &str) -> Self {
+        let mut stats = Stats::default();
+        for c in value.chars() {
+            stats.len += 1;
+            if c.is_ascii_digit() {
+                stats.digit = true;
+            } else if c.is_uppercase() {
+                stats.uppercase = true;
+            } else if c.is_lowercase() {
+                stats.lowercase = true;
+            } else if !c.is_alphanumeric() && !c.is_whitespace() {
+                stats.special = true;
+            } else {
+                stats.other = true;
+            }
+        }
+        stats
+    }
+
+    fn complexity_score(&self) -> u8 {
+        [self.uppercase, self.lowercase, self.digit, self.special]
+            .into_iter()
+            .filter(|v| *v)
+            .count() as u8
+    }
+
+    fn entropy_bits(&self) -> f64 {
+        // approximate pool sizes of each category, based on the printable ASCII range
+        let pool = [
+            (self.uppercase, 26),
+            (self.lowercase, 26),
+            (self.digit, 10),
+            (self.special, 33),
+            (self.other, 26),
+        ]
+        .into_iter()
+        .filter(|(present, _)| *present)
+        .map(|(_, size)| size)
+        .sum::<u32>();
+
+        match pool {
+            0 => 0.0,
+            pool => self.len as f64 * f64::from(pool).log2(),
+        }
+    }
+}
@@ -0,0 +1,63 @@
+//! Implemented by string-like types which are not a commonly used password.
+//!
+//! This backs the `not_common_password` rule. The list of common passwords is embedded at compile time,
+//! as a sorted table generated by the build script from `common-passwords.txt`.
+//! The list currently holds 895 of the most commonly used passwords found in data breaches.
+//! `cargo x passwords` replaces it with the
+//! [10,000 most common passwords](https://github.com/danielmiessler/SecLists/blob/master/Passwords/Common-Credentials/10k-most-common.txt)
+//! list of [SecLists](https://github.com/danielmiessler/SecLists), which is available under the
+//! [MIT license](https://github.com/danielmiessler/SecLists/blob/master/LICENSE).
+//! All entries are lowercase, so with `case_insensitive`, the value is lowercased before it is looked up.
+
+use crate::error::Error;
+use crate::rules::AsStr;
+
+include!(concat!(env!("OUT_DIR"), "/common_passwords.rs"));
+
+fn is_listed(value: &str) -> bool {
+    COMMON_PASSWORDS.binary_search(&value).is_ok()
+}
+
+pub fn apply<T: CommonPassword>(v: &T, (case_insensitive,): (bool,)) -> Result<(), Error> {
+    // The message intentionally doesn't mention the list, so that it can't be used to probe it.
+    if v.is_common_password(case_insensitive) {
+        return Err(Error::new("password is too common"));
+    }
+    Ok(())
+}
+
+pub trait CommonPassword {
+    fn is_common_password(&self, case_insensitive: bool) -> bool;
+}
+
+impl<T: AsStr> CommonPassword for T {
+    fn is_common_password(&self, case_insensitive: bool) -> bool {
+        let value = self.as_str();
+        match case_insensitive {
+            true => is_listed(&value.to_lowercase()),
+            false => is_listed(value),
+        }
+    }
+}
+
+impl<T: CommonPassword> CommonPassword for Option<T> {
+    fn is_common_password(&self, case_insensitive: bool) -> bool {
+        match self {
+            Some(value) => value.is_common_password(case_insensitive),
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn list_is_sorted_lowercase_and_unique() {
+        let list = include_str!("common-passwords.txt");
+        let entries = list.lines().collect::<Vec<_>>();
+        assert!(entries.windows(2).all(|w| w[0] < w[1]));
+        assert!(entries
+            .iter()
+            .all(|v| !v.is_empty() && *v == v.to_lowercase()));
+    }
+}
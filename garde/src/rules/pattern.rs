@@ -28,6 +28,41 @@
 //! }
 //! ```
 //!
+//! A closure or function taking a `&str` and returning a `bool` may be used by wrapping it in [`FnMatcher`].
+//! Closures written directly in the attribute are wrapped automatically:
+//!
+//! ```rust
+//! fn is_http(v: &str) -> bool {
+//!     v.starts_with("http")
+//! }
+//!
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(pattern(|v: &str| v.starts_with("http")))]
+//!     v: String,
+//!     #[garde(pattern(garde::rules::pattern::FnMatcher::new(is_http)))]
+//!     w: String,
+//! }
+//! ```
+//!
+//! To find out which part of the value didn't match, use [`PatternWithGroups`] (if the `regex` feature is enabled).
+//! It reports the first named capture group which fails to match in the error message, and in the `group` metadata of the error:
+//!
+//! ```rust
+//! use garde::rules::pattern::PatternWithGroups;
+//! use once_cell::sync::Lazy;
+//!
+//! static DATE: Lazy<PatternWithGroups> = Lazy::new(|| {
+//!     PatternWithGroups::new(r"^(?P<year>\d{4})-(?P<month>\d{2})-(?P<day>\d{2})$").unwrap()
+//! });
+//!
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(pattern(DATE))]
+//!     v: String,
+//! }
+//! ```
+//!
 //! The entrypoint is the [`Pattern`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(pattern(...))]` rule.
 //!
 //! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
@@ -37,27 +72,82 @@ use crate::error::Error;
 
 pub fn apply<T: Pattern, M: Matcher>(v: &T, (pat,): (&M,)) -> Result<(), Error> {
     if !v.validate_pattern(pat) {
-        return Err(Error::new(format!(
-            "does not match pattern /{}/",
-            pat.as_str()
-        )));
+        let message = match pat.pattern() {
+            Some(pat) => format!("does not match pattern /{pat}/"),
+            None => "does not match pattern".to_owned(),
+        };
+        return Err(match v.failed_group(pat) {
+            Some(group) => {
+                Error::new(format!("{message}: invalid `{group}`")).with_metadata("group", group)
+            }
+            None => Error::new(message),
+        });
     }
     Ok(())
 }
 
-pub trait Matcher: AsStr {
+pub trait Matcher {
     /// Returns true if and only if there is a match for the pattern anywhere in the haystack given.
     fn is_match(&self, haystack: &str) -> bool;
+
+    /// Returns the pattern to display in the error message, if it has a textual representation.
+    ///
+    /// Defaults to `None`, in which case the error message doesn't include the pattern.
+    fn pattern(&self) -> Option<&str> {
+        None
+    }
+
+    /// Returns the name of the first capture group which fails to match the haystack, if the matcher can tell.
+    ///
+    /// This is only called when [`Matcher::is_match`] returns `false`. Defaults to `None`.
+    fn failed_group(&self, haystack: &str) -> Option<&str> {
+        let _ = haystack;
+        None
+    }
+}
+
+/// A [`Matcher`] which calls a function with the haystack.
+///
+/// ```rust
+/// use garde::rules::pattern::{FnMatcher, Matcher};
+///
+/// let matcher = FnMatcher::new(|v: &str| v.starts_with("http"));
+/// assert!(matcher.is_match("https://example.com"));
+/// assert!(!matcher.is_match("ftp://example.com"));
+/// ```
+pub struct FnMatcher<F>(pub F);
+
+impl<F: Fn(&str) -> bool> FnMatcher<F> {
+    /// Wraps `f`, which is helpful for inferring the argument type of a closure.
+    pub fn new(f: F) -> Self {
+        Self(f)
+    }
+}
+
+impl<F: Fn(&str) -> bool> Matcher for FnMatcher<F> {
+    fn is_match(&self, haystack: &str) -> bool {
+        (self.0)(haystack)
+    }
 }
 
 pub trait Pattern {
     fn validate_pattern<M: Matcher>(&self, matcher: &M) -> bool;
+
+    /// Returns the name of the capture group which caused the value to not match, see [`Matcher::failed_group`].
+    fn failed_group<'m, M: Matcher>(&self, matcher: &'m M) -> Option<&'m str> {
+        let _ = matcher;
+        None
+    }
 }
 
 impl<T: AsStr> Pattern for T {
     fn validate_pattern<M: Matcher>(&self, matcher: &M) -> bool {
         matcher.is_match(self.as_str())
     }
+
+    fn failed_group<'m, M: Matcher>(&self, matcher: &'m M) -> Option<&'m str> {
+        matcher.failed_group(self.as_str())
+    }
 }
 
 impl<T: Pattern> Pattern for Option<T> {
@@ -67,6 +157,10 @@ impl<T: Pattern> Pattern for Option<T> {
             None => true,
         }
     }
+
+    fn failed_group<'m, M: Matcher>(&self, matcher: &'m M) -> Option<&'m str> {
+        self.as_ref().and_then(|value| value.failed_group(matcher))
+    }
 }
 
 #[cfg(all(
@@ -112,6 +206,10 @@ pub mod regex_js_sys {
         fn is_match(&self, haystack: &str) -> bool {
             self.0.is_match(haystack)
         }
+
+        fn pattern(&self) -> Option<&str> {
+            self.0.pattern()
+        }
     }
 
     unsafe impl<T> Send for SyncWrapper<T> {}
@@ -131,6 +229,9 @@ pub mod regex_js_sys {
     pub use crate::__init_js_sys_pattern as init_pattern;
 }
 
+#[cfg(feature = "regex")]
+pub use self::regex::PatternWithGroups;
+
 #[cfg(feature = "regex")]
 #[doc(hidden)]
 pub mod regex {
@@ -142,12 +243,95 @@ pub mod regex {
         fn is_match(&self, haystack: &str) -> bool {
             self.is_match(haystack)
         }
+
+        fn pattern(&self) -> Option<&str> {
+            Some(self.as_str())
+        }
     }
 
     impl<T: Matcher> Matcher for once_cell::sync::Lazy<T> {
         fn is_match(&self, haystack: &str) -> bool {
             once_cell::sync::Lazy::force(self).is_match(haystack)
         }
+
+        fn pattern(&self) -> Option<&str> {
+            once_cell::sync::Lazy::force(self).pattern()
+        }
+
+        fn failed_group(&self, haystack: &str) -> Option<&str> {
+            once_cell::sync::Lazy::force(self).failed_group(haystack)
+        }
+    }
+
+    /// A regular expression which reports the named capture group that failed to match.
+    ///
+    /// For each named capture group at the top level of the pattern, the part of the pattern which ends
+    /// with that group is compiled separately. When the whole pattern does not match, the first group
+    /// whose part doesn't match is reported in the error message, and in the `group` metadata of the error.
+    ///
+    /// ```rust
+    /// use garde::rules::pattern::{Matcher, PatternWithGroups};
+    ///
+    /// let pattern = PatternWithGroups::new(r"^(?P<year>\d{4})-(?P<month>\d{2})-(?P<day>\d{2})$").unwrap();
+    /// assert!(pattern.is_match("2024-01-31"));
+    /// assert_eq!(pattern.failed_group("2024-1-31"), Some("month"));
+    /// assert_eq!(pattern.failed_group("2024-01-31T00:00"), None);
+    /// ```
+    pub struct PatternWithGroups {
+        regex: Regex,
+        groups: Vec<(String, Regex)>,
+    }
+
+    impl PatternWithGroups {
+        pub fn new(pattern: &str) -> Result<Self, ::regex::Error> {
+            let regex = Regex::new(pattern)?;
+            let groups = prefixes(pattern)
+                .into_iter()
+                .map(|(name, prefix)| Ok((name, Regex::new(prefix)?)))
+                .collect::<Result<_, ::regex::Error>>()?;
+            Ok(Self { regex, groups })
+        }
+    }
+
+    /// Returns each top-level named group, and the part of the pattern which ends with it.
+    fn prefixes(pattern: &str) -> Vec<(String, &str)> {
+        use regex_syntax::ast::{parse::Parser, Ast, GroupKind};
+
+        let Ok(ast) = Parser::new().parse(pattern) else {
+            return Vec::new();
+        };
+        let asts = match &ast {
+            Ast::Concat(concat) => concat.asts.as_slice(),
+            ast => std::slice::from_ref(ast),
+        };
+        asts.iter()
+            .filter_map(|ast| match ast {
+                Ast::Group(group) => match &group.kind {
+                    GroupKind::CaptureName { name, .. } => {
+                        Some((name.name.clone(), &pattern[..group.span.end.offset]))
+                    }
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect()
+    }
+
+    impl Matcher for PatternWithGroups {
+        fn is_match(&self, haystack: &str) -> bool {
+            self.regex.is_match(haystack)
+        }
+
+        fn pattern(&self) -> Option<&str> {
+            Some(self.regex.as_str())
+        }
+
+        fn failed_group(&self, haystack: &str) -> Option<&str> {
+            self.groups
+                .iter()
+                .find(|(_, prefix)| !prefix.is_match(haystack))
+                .map(|(name, _)| name.as_str())
+        }
     }
 
     impl AsStr for Regex {
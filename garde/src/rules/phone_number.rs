@@ -5,21 +5,33 @@
 //! struct Test {
 //!     #[garde(phone_number)]
 //!     v: String,
+//!     #[garde(phone_number(region = "US"))]
+//!     w: String,
 //! }
 //! ```
 //!
+//! Without a `region`, the phone number must be in international format, e.g. `+1 415 555 2671`.
+//! The `region` argument is an ISO 3166-1 alpha-2 region code, which is used to parse phone numbers
+//! written in the local format of that region, e.g. `(415) 555-2671` for `"US"`.
+//! Phone numbers in international format are still accepted, even if they are from a different region.
+//!
 //! The entrypoint is the [`PhoneNumber`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(phone_number)]` rule.
 //!
 //! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
 
 use std::fmt::Display;
-use std::str::FromStr;
+
+use phonenumber::country;
 
 use super::AsStr;
 use crate::error::Error;
 
-pub fn apply<T: PhoneNumber>(v: &T, _: ()) -> Result<(), Error> {
-    match v.validate_phone_number() {
+pub fn apply<T: PhoneNumber>(v: &T, (region,): (Option<&str>,)) -> Result<(), Error> {
+    let result = match region {
+        Some(region) => v.validate_phone_number_in_region(region),
+        None => v.validate_phone_number(),
+    };
+    match result {
         Ok(true) => Ok(()),
         Ok(false) => Err(Error::new("not a valid phone number")),
         Err(e) => Err(Error::new(format!("not a valid phone number: {e}"))),
@@ -30,13 +42,29 @@ pub trait PhoneNumber {
     type Error: Display;
 
     fn validate_phone_number(&self) -> Result<bool, Self::Error>;
+
+    /// Validates the phone number, using `region` to parse numbers which are not in international format.
+    ///
+    /// Defaults to [`PhoneNumber::validate_phone_number`], which only accepts numbers in international format.
+    fn validate_phone_number_in_region(&self, region: &str) -> Result<bool, Self::Error> {
+        let _ = region;
+        self.validate_phone_number()
+    }
 }
 
 impl<T: AsStr> PhoneNumber for T {
-    type Error = phonenumber::ParseError;
+    type Error = InvalidPhoneNumber;
 
     fn validate_phone_number(&self) -> Result<bool, Self::Error> {
-        let number = phonenumber::PhoneNumber::from_str(self.as_str())?;
+        let number = phonenumber::parse(None, self.as_str())?;
+        Ok(number.is_valid())
+    }
+
+    fn validate_phone_number_in_region(&self, region: &str) -> Result<bool, Self::Error> {
+        let region = region
+            .parse::<country::Id>()
+            .map_err(|_| InvalidPhoneNumber::UnknownRegion)?;
+        let number = phonenumber::parse(Some(region), self.as_str())?;
         Ok(number.is_valid())
     }
 }
@@ -50,4 +78,31 @@ impl<T: PhoneNumber> PhoneNumber for Option<T> {
             None => Ok(true),
         }
     }
+
+    fn validate_phone_number_in_region(&self, region: &str) -> Result<bool, Self::Error> {
+        match self {
+            Some(value) => value.validate_phone_number_in_region(region),
+            None => Ok(true),
+        }
+    }
+}
+
+pub enum InvalidPhoneNumber {
+    UnknownRegion,
+    Parse(phonenumber::ParseError),
+}
+
+impl Display for InvalidPhoneNumber {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvalidPhoneNumber::UnknownRegion => write!(f, "unknown region"),
+            InvalidPhoneNumber::Parse(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl From<phonenumber::ParseError> for InvalidPhoneNumber {
+    fn from(value: phonenumber::ParseError) -> Self {
+        Self::Parse(value)
+    }
 }
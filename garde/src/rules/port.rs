@@ -0,0 +1,57 @@
+//! Port number validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(port)]
+//!     v: u16,
+//!     #[garde(port(unprivileged))]
+//!     w: u32,
+//! }
+//! ```
+//!
+//! A valid port is in the range `1..=65535`. The `unprivileged` argument further restricts it to `1024..=65535`.
+//!
+//! The entrypoint is the [`ValidPort`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(port)]` rule.
+//!
+//! This trait is implemented for `u16`, `u32`, and `u64`.
+
+use crate::error::Error;
+
+pub fn apply<T: ValidPort>(v: &T, (unprivileged,): (bool,)) -> Result<(), Error> {
+    if !v.validate_port(unprivileged) {
+        return Err(Error::new(match unprivileged {
+            true => "not an unprivileged port (1024-65535)",
+            false => "not a valid port (1-65535)",
+        }));
+    }
+    Ok(())
+}
+
+pub trait ValidPort {
+    fn validate_port(&self, unprivileged: bool) -> bool;
+}
+
+macro_rules! impl_for_int {
+    ($($T:ident),*) => {
+        $(
+            impl ValidPort for $T {
+                fn validate_port(&self, unprivileged: bool) -> bool {
+                    let min = if unprivileged { 1024 } else { 1 };
+                    (min..=65535).contains(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_for_int!(u16, u32, u64);
+
+impl<T: ValidPort> ValidPort for Option<T> {
+    fn validate_port(&self, unprivileged: bool) -> bool {
+        match self {
+            Some(value) => value.validate_port(unprivileged),
+            None => true,
+        }
+    }
+}
@@ -0,0 +1,111 @@
+//! Decimal precision validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(precision(max_decimal_places = 2))]
+//!     v: String,
+//!     #[garde(precision(max_decimal_places = 2))]
+//!     w: f64,
+//! }
+//! ```
+//!
+//! Strings must be decimal numbers, such as `"10.5"` or `"-0.25"`, with an optional sign and no exponent.
+//! Trailing zeros in the fractional part are not counted, so `"10.50"` has one decimal place.
+//!
+//! Floating-point numbers are checked using their shortest representation which round-trips,
+//! which is the same one used by their `Display` implementation. Because most decimal fractions
+//! cannot be represented exactly, the result of arithmetic may have more decimal places than expected,
+//! e.g. `0.1 + 0.2` is `0.30000000000000004`. `NaN` and infinities are rejected.
+//!
+//! The entrypoint is the [`HasPrecision`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(precision(...))]` rule.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`, and is implemented for `f32` and `f64`.
+//! With the `rust_decimal` feature, it is also implemented for [`rust_decimal::Decimal`].
+
+use super::AsStr;
+use crate::error::Error;
+
+pub fn apply<T: Precision>(v: &T, (max_decimal_places,): (usize,)) -> Result<(), Error> {
+    v.validate_precision(max_decimal_places)
+}
+
+pub trait Precision {
+    fn validate_precision(&self, max_decimal_places: usize) -> Result<(), Error>;
+}
+
+impl<T: HasPrecision> Precision for T {
+    fn validate_precision(&self, max_decimal_places: usize) -> Result<(), Error> {
+        match self.decimal_places() {
+            Some(places) if places > max_decimal_places => Err(Error::new(format!(
+                "too many decimal places, expected at most {max_decimal_places}, got {places}"
+            ))
+            .with_metadata("max_decimal_places", max_decimal_places)
+            .with_metadata("actual_decimal_places", places)),
+            Some(_) => Ok(()),
+            None => Err(Error::new("not a valid decimal number")),
+        }
+    }
+}
+
+impl<T: Precision> Precision for Option<T> {
+    fn validate_precision(&self, max_decimal_places: usize) -> Result<(), Error> {
+        match self {
+            Some(v) => v.validate_precision(max_decimal_places),
+            None => Ok(()),
+        }
+    }
+}
+
+pub trait HasPrecision {
+    /// Returns the number of decimal places, not counting trailing zeros,
+    /// or `None` if the value is not a valid decimal number.
+    fn decimal_places(&self) -> Option<usize>;
+}
+
+impl<T: AsStr> HasPrecision for T {
+    fn decimal_places(&self) -> Option<usize> {
+        decimal_places(self.as_str())
+    }
+}
+
+macro_rules! impl_for_float {
+    ($($T:ident),*) => {
+        $(
+            impl HasPrecision for $T {
+                fn decimal_places(&self) -> Option<usize> {
+                    if !self.is_finite() {
+                        return None;
+                    }
+                    // `Display` never uses an exponent for floats
+                    decimal_places(&self.to_string())
+                }
+            }
+        )*
+    };
+}
+
+impl_for_float!(f32, f64);
+
+#[cfg(feature = "rust_decimal")]
+impl HasPrecision for rust_decimal::Decimal {
+    fn decimal_places(&self) -> Option<usize> {
+        Some(self.normalize().scale() as usize)
+    }
+}
+
+fn decimal_places(value: &str) -> Option<usize> {
+    let value = value.strip_prefix(['+', '-']).unwrap_or(value);
+    let (integer, fraction) = value.split_once('.').unwrap_or((value, ""));
+    if integer.is_empty() && fraction.is_empty() {
+        return None;
+    }
+    if !integer
+        .bytes()
+        .chain(fraction.bytes())
+        .all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+    Some(fraction.trim_end_matches('0').len())
+}
@@ -9,9 +9,13 @@
 //!     v: String,
 //!     #[garde(prefix(PRE))]
 //!     w: String,
+//!     #[garde(not_prefix("test_"))]
+//!     x: String,
 //! }
 //! ```
 //!
+//! The `not_prefix` rule inverts the check, and is implemented in the [`not`] module.
+//!
 //! The entrypoint is the [`Prefix`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(prefix)]` rule.
 //!
 //! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
@@ -26,8 +30,24 @@ pub fn apply<T: Prefix>(v: &T, (pat,): (&str,)) -> Result<(), Error> {
     Ok(())
 }
 
+pub mod not {
+    use super::Prefix;
+    use crate::error::Error;
+
+    pub fn apply<T: Prefix>(v: &T, (pat,): (&str,)) -> Result<(), Error> {
+        if !v.validate_not_prefix(pat) {
+            return Err(Error::new(format!("must not begin with \"{pat}\"")));
+        }
+        Ok(())
+    }
+}
+
 pub trait Prefix {
     fn validate_prefix(&self, pat: &str) -> bool;
+
+    fn validate_not_prefix(&self, pat: &str) -> bool {
+        !self.validate_prefix(pat)
+    }
 }
 
 impl<T: AsStr> Prefix for T {
@@ -43,4 +63,11 @@ impl<T: Prefix> Prefix for Option<T> {
             None => true,
         }
     }
+
+    fn validate_not_prefix(&self, pat: &str) -> bool {
+        match self {
+            Some(value) => value.validate_not_prefix(pat),
+            None => true,
+        }
+    }
 }
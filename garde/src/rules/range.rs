@@ -5,35 +5,92 @@
 //! struct Test {
 //!     #[garde(range(min=10,max=100))]
 //!     v: u64,
+//!     #[garde(range(exclusive_min=0.0, max=1.0))]
+//!     w: f64,
 //! }
 //! ```
 //!
+//! `min` and `max` are inclusive, while `exclusive_min` and `exclusive_max` exclude the bound itself.
+//! Only one of `min` and `exclusive_min` may be used, and likewise for `max` and `exclusive_max`.
+//!
 //! The entrypoint is the [`Bounds`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(range(...))]` rule.
 //!
-//! This trait is implemented for all primitive integer types.
+//! This trait is implemented for all primitive number types and [`std::time::Duration`].
+//! The bounds may be any expression of the same type as the field, such as `Duration::from_secs(1)`:
+//!
+//! ```rust
+//! use std::time::Duration;
+//!
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(range(min = Duration::from_secs(1), max = Duration::from_secs(3600)))]
+//!     timeout: Duration,
+//! }
+//! ```
+//!
+//! The `positive`, `non_negative`, `negative` and `non_positive` rules are shorthands for comparing a number against zero:
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(positive)]
+//!     v: u64,
+//!     #[garde(non_positive)]
+//!     w: f32,
+//! }
+//! ```
+//!
+//! They are implemented in the [`sign`] module.
+
+use std::fmt::Debug;
+use std::ops::Bound;
+use std::time::Duration;
 
-use std::fmt::Display;
+pub mod sign;
 
 use crate::error::Error;
 
 #[inline]
 pub fn apply<T: Bounds>(
     v: &T,
-    (min, max): (Option<T::Size>, Option<T::Size>),
+    (lower_bound, upper_bound): (Bound<T::Size>, Bound<T::Size>),
 ) -> Result<(), Error> {
-    let min = min.unwrap_or(T::MIN);
-    let max = max.unwrap_or(T::MAX);
-    if let Err(e) = v.validate_bounds(min, max) {
-        match e {
-            OutOfBounds::Lower => return Err(Error::new(format!("lower than {min}"))),
-            OutOfBounds::Upper => return Err(Error::new(format!("greater than {max}"))),
+    if let Err(e) = v.validate_exclusive_bounds(lower_bound, upper_bound) {
+        let lower_bound = map_bound(lower_bound, T::format_bound);
+        let upper_bound = map_bound(upper_bound, T::format_bound);
+        match (e, lower_bound, upper_bound) {
+            (OutOfBounds::Lower, Bound::Excluded(min), _) => {
+                return Err(Error::new(format!("lower than or equal to {min}"))
+                    .with_metadata("exclusive_min", min))
+            }
+            (OutOfBounds::Lower, Bound::Included(min), _) => {
+                return Err(Error::new(format!("lower than {min}")).with_metadata("min", min))
+            }
+            (OutOfBounds::Upper, _, Bound::Excluded(max)) => {
+                return Err(Error::new(format!("greater than or equal to {max}"))
+                    .with_metadata("exclusive_max", max))
+            }
+            (OutOfBounds::Upper, _, Bound::Included(max)) => {
+                return Err(Error::new(format!("greater than {max}")).with_metadata("max", max))
+            }
+            (OutOfBounds::Lower, Bound::Unbounded, _)
+            | (OutOfBounds::Upper, _, Bound::Unbounded) => {}
         }
     }
     Ok(())
 }
 
+// `Bound::map` requires Rust 1.77.
+fn map_bound<T, U>(bound: Bound<T>, f: impl FnOnce(T) -> U) -> Bound<U> {
+    match bound {
+        Bound::Included(v) => Bound::Included(f(v)),
+        Bound::Excluded(v) => Bound::Excluded(f(v)),
+        Bound::Unbounded => Bound::Unbounded,
+    }
+}
+
 pub trait Bounds: PartialOrd {
-    type Size: Copy + Sized + Display;
+    type Size: Copy + Sized + Debug;
 
     const MIN: Self::Size;
     const MAX: Self::Size;
@@ -43,6 +100,43 @@ pub trait Bounds: PartialOrd {
         lower_bound: Self::Size,
         upper_bound: Self::Size,
     ) -> Result<(), OutOfBounds>;
+
+    /// Checks that the value is within the bounds, each of which may be inclusive, exclusive, or absent.
+    ///
+    /// The default implementation is based on [`Bounds::validate_bounds`]. A value is equal to an exclusive bound
+    /// if it is within the inclusive range from that bound to itself.
+    fn validate_exclusive_bounds(
+        &self,
+        lower_bound: Bound<Self::Size>,
+        upper_bound: Bound<Self::Size>,
+    ) -> Result<(), OutOfBounds> {
+        let inclusive = |bound: Bound<Self::Size>, default: Self::Size| match bound {
+            Bound::Included(v) | Bound::Excluded(v) => v,
+            Bound::Unbounded => default,
+        };
+        self.validate_bounds(
+            inclusive(lower_bound, Self::MIN),
+            inclusive(upper_bound, Self::MAX),
+        )?;
+        if let Bound::Excluded(min) = lower_bound {
+            if self.validate_bounds(min, min).is_ok() {
+                return Err(OutOfBounds::Lower);
+            }
+        }
+        if let Bound::Excluded(max) = upper_bound {
+            if self.validate_bounds(max, max).is_ok() {
+                return Err(OutOfBounds::Upper);
+            }
+        }
+        Ok(())
+    }
+
+    /// Formats a bound for the error message.
+    ///
+    /// Defaults to the [`Debug`] representation, numbers use their [`Display`][std::fmt::Display] representation instead.
+    fn format_bound(bound: Self::Size) -> String {
+        format!("{bound:?}")
+    }
 }
 
 pub enum OutOfBounds {
@@ -64,13 +158,19 @@ macro_rules! impl_for_int {
                     lower_bound: Self::Size,
                     upper_bound: Self::Size,
                 ) -> Result<(), OutOfBounds> {
-                    if self < &lower_bound {
-                        Err(OutOfBounds::Lower)
-                    } else if self > &upper_bound {
-                        Err(OutOfBounds::Upper)
-                    } else {
-                        Ok(())
-                    }
+                    check_bounds(self, Bound::Included(lower_bound), Bound::Included(upper_bound))
+                }
+
+                fn validate_exclusive_bounds(
+                    &self,
+                    lower_bound: Bound<Self::Size>,
+                    upper_bound: Bound<Self::Size>,
+                ) -> Result<(), OutOfBounds> {
+                    check_bounds(self, lower_bound, upper_bound)
+                }
+
+                fn format_bound(bound: Self::Size) -> String {
+                    bound.to_string()
                 }
             }
         )*
@@ -79,6 +179,50 @@ macro_rules! impl_for_int {
 
 impl_for_int!(u8, u16, u32, u64, usize, u128, i8, i16, i32, i64, isize, i128, f32, f64);
 
+impl Bounds for Duration {
+    type Size = Duration;
+
+    const MIN: Self::Size = Duration::ZERO;
+    const MAX: Self::Size = Duration::MAX;
+
+    fn validate_bounds(
+        &self,
+        lower_bound: Self::Size,
+        upper_bound: Self::Size,
+    ) -> Result<(), OutOfBounds> {
+        check_bounds(
+            self,
+            Bound::Included(lower_bound),
+            Bound::Included(upper_bound),
+        )
+    }
+
+    fn validate_exclusive_bounds(
+        &self,
+        lower_bound: Bound<Self::Size>,
+        upper_bound: Bound<Self::Size>,
+    ) -> Result<(), OutOfBounds> {
+        check_bounds(self, lower_bound, upper_bound)
+    }
+}
+
+fn check_bounds<T: PartialOrd>(
+    v: &T,
+    lower_bound: Bound<T>,
+    upper_bound: Bound<T>,
+) -> Result<(), OutOfBounds> {
+    match lower_bound {
+        Bound::Included(min) if v < &min => return Err(OutOfBounds::Lower),
+        Bound::Excluded(min) if v <= &min => return Err(OutOfBounds::Lower),
+        _ => {}
+    }
+    match upper_bound {
+        Bound::Included(max) if v > &max => Err(OutOfBounds::Upper),
+        Bound::Excluded(max) if v >= &max => Err(OutOfBounds::Upper),
+        _ => Ok(()),
+    }
+}
+
 impl<T: Bounds> Bounds for Option<T> {
     type Size = T::Size;
 
@@ -95,4 +239,19 @@ impl<T: Bounds> Bounds for Option<T> {
             None => Ok(()),
         }
     }
+
+    fn validate_exclusive_bounds(
+        &self,
+        lower_bound: Bound<Self::Size>,
+        upper_bound: Bound<Self::Size>,
+    ) -> Result<(), OutOfBounds> {
+        match self {
+            Some(value) => value.validate_exclusive_bounds(lower_bound, upper_bound),
+            None => Ok(()),
+        }
+    }
+
+    fn format_bound(bound: Self::Size) -> String {
+        T::format_bound(bound)
+    }
 }
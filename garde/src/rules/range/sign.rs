@@ -0,0 +1,89 @@
+//! Implemented by numeric types which can be compared against zero.
+//!
+//! This backs the `positive`, `non_negative`, `negative` and `non_positive` rules.
+
+use std::fmt::Display;
+
+use crate::error::Error;
+
+pub fn apply<T: Signed>(v: &T, (sign,): (Sign,)) -> Result<(), Error> {
+    if !v.validate_sign(sign) {
+        return Err(Error::new(format!("expected a {sign} number")));
+    }
+    Ok(())
+}
+
+pub trait Signed {
+    fn validate_sign(&self, sign: Sign) -> bool;
+}
+
+#[derive(Clone, Copy)]
+pub enum Sign {
+    /// `> 0`
+    Positive,
+    /// `>= 0`
+    NonNegative,
+    /// `< 0`
+    Negative,
+    /// `<= 0`
+    NonPositive,
+}
+
+impl Display for Sign {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Sign::Positive => write!(f, "positive"),
+            Sign::NonNegative => write!(f, "non-negative"),
+            Sign::Negative => write!(f, "negative"),
+            Sign::NonPositive => write!(f, "non-positive"),
+        }
+    }
+}
+
+macro_rules! impl_for_num {
+    ($zero:literal: $($T:ident),*) => {
+        $(
+            impl Signed for $T {
+                fn validate_sign(&self, sign: Sign) -> bool {
+                    match sign {
+                        Sign::Positive => *self > $zero,
+                        Sign::NonNegative => *self >= $zero,
+                        Sign::Negative => *self < $zero,
+                        Sign::NonPositive => *self <= $zero,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_for_num!(0: i8, i16, i32, i64, isize, i128);
+impl_for_num!(0.0: f32, f64);
+
+macro_rules! impl_for_unsigned {
+    ($($T:ident),*) => {
+        $(
+            impl Signed for $T {
+                fn validate_sign(&self, sign: Sign) -> bool {
+                    match sign {
+                        Sign::Positive => *self != 0,
+                        Sign::NonNegative => true,
+                        Sign::Negative => false,
+                        Sign::NonPositive => *self == 0,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_for_unsigned!(u8, u16, u32, u64, usize, u128);
+
+impl<T: Signed> Signed for Option<T> {
+    fn validate_sign(&self, sign: Sign) -> bool {
+        match self {
+            Some(value) => value.validate_sign(sign),
+            None => true,
+        }
+    }
+}
@@ -0,0 +1,87 @@
+//! First-class rule values.
+//!
+//! ```rust
+//! use garde::rules::{All, Any, Rule};
+//!
+//! fn non_empty(v: &String, _: &()) -> garde::Result {
+//!     match v.is_empty() {
+//!         true => Err(garde::Error::new("empty")),
+//!         false => Ok(()),
+//!     }
+//! }
+//!
+//! fn short(v: &String, _: &()) -> garde::Result {
+//!     match v.len() > 16 {
+//!         true => Err(garde::Error::new("too long")),
+//!         false => Ok(()),
+//!     }
+//! }
+//!
+//! const USERNAME: All<fn(&String, &()) -> garde::Result, fn(&String, &()) -> garde::Result> =
+//!     All(non_empty, short);
+//!
+//! #[derive(garde::Validate)]
+//! struct User {
+//!     #[garde(rule(USERNAME))]
+//!     username: String,
+//! }
+//!
+//! assert!(USERNAME.validate(&String::from("bob"), &()).is_ok());
+//! assert!(Any(non_empty, short).validate(&String::new(), &()).is_ok());
+//! ```
+//!
+//! The entrypoint is the [`Rule`] trait. Any value implementing [`Rule`] for the type of a field
+//! may be used with the `#[garde(rule(..))]` modifier, including functions and closures
+//! with the same signature as a `custom` validator.
+
+use crate::Result;
+
+/// A rule which validates a `T` using a context `C`.
+pub trait Rule<T: ?Sized, C: ?Sized = ()> {
+    fn validate(&self, value: &T, ctx: &C) -> Result;
+}
+
+impl<T: ?Sized, C: ?Sized, F> Rule<T, C> for F
+where
+    F: Fn(&T, &C) -> Result,
+{
+    fn validate(&self, value: &T, ctx: &C) -> Result {
+        self(value, ctx)
+    }
+}
+
+/// Passes if both rules pass, returning the error of the first failing rule otherwise.
+///
+/// The second rule is not run if the first one fails.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct All<R1, R2>(pub R1, pub R2);
+
+impl<T: ?Sized, C: ?Sized, R1, R2> Rule<T, C> for All<R1, R2>
+where
+    R1: Rule<T, C>,
+    R2: Rule<T, C>,
+{
+    fn validate(&self, value: &T, ctx: &C) -> Result {
+        self.0.validate(value, ctx)?;
+        self.1.validate(value, ctx)
+    }
+}
+
+/// Passes if either rule passes, returning the error of the second rule if both fail.
+///
+/// The second rule is not run if the first one passes.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Any<R1, R2>(pub R1, pub R2);
+
+impl<T: ?Sized, C: ?Sized, R1, R2> Rule<T, C> for Any<R1, R2>
+where
+    R1: Rule<T, C>,
+    R2: Rule<T, C>,
+{
+    fn validate(&self, value: &T, ctx: &C) -> Result {
+        match self.0.validate(value, ctx) {
+            Ok(()) => Ok(()),
+            Err(_) => self.1.validate(value, ctx),
+        }
+    }
+}
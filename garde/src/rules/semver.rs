@@ -0,0 +1,125 @@
+//! Semantic version validation using the [`semver`] crate.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(semver)]
+//!     v: String,
+//!     #[garde(semver(req = ">=1.0.0"))]
+//!     w: String,
+//! }
+//! ```
+//!
+//! The `req` argument is optional. If present, it is parsed as a [`semver::VersionReq`],
+//! and the version must satisfy it. A string literal is parsed once, the first time it is used,
+//! any other expression is parsed every time the value is validated.
+//!
+//! The entrypoint is the [`ValidSemver`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(semver)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+
+use std::borrow::Cow;
+use std::fmt::Display;
+use std::sync::OnceLock;
+
+use super::AsStr;
+use crate::error::Error;
+
+pub fn apply<T: ValidSemver, R: AsVersionReq + ?Sized>(
+    v: &T,
+    (req,): (Option<&R>,),
+) -> Result<(), Error> {
+    let req = match req.map(AsVersionReq::as_version_req).transpose() {
+        Ok(req) => req,
+        Err(e) => return Err(Error::new(format!("invalid version requirement: {e}"))),
+    };
+    if let Err(e) = v.validate_semver(req.as_deref()) {
+        return Err(match (e, req) {
+            (InvalidSemver::Unsatisfied, Some(req)) => {
+                Error::new(format!("version does not satisfy \"{req}\""))
+            }
+            (e, _) => Error::new(format!("not a valid semantic version: {e}")),
+        });
+    }
+    Ok(())
+}
+
+/// Implemented by the types which may be used as the `req` of `#[garde(semver(req = ...))]`.
+pub trait AsVersionReq {
+    fn as_version_req(&self) -> Result<Cow<'_, semver::VersionReq>, semver::Error>;
+}
+
+impl AsVersionReq for semver::VersionReq {
+    fn as_version_req(&self) -> Result<Cow<'_, semver::VersionReq>, semver::Error> {
+        Ok(Cow::Borrowed(self))
+    }
+}
+
+impl AsVersionReq for str {
+    fn as_version_req(&self) -> Result<Cow<'_, semver::VersionReq>, semver::Error> {
+        semver::VersionReq::parse(self).map(Cow::Owned)
+    }
+}
+
+/// Parses a string literal `req` once, the first time it is used.
+///
+/// The literal is checked by the derive, so parsing it can't fail.
+#[doc(hidden)]
+pub struct StaticVersionReq(OnceLock<semver::VersionReq>);
+
+impl StaticVersionReq {
+    #[allow(clippy::new_without_default)]
+    pub const fn new() -> Self {
+        Self(OnceLock::new())
+    }
+
+    pub fn get(&self, req: &str) -> &semver::VersionReq {
+        self.0.get_or_init(|| {
+            semver::VersionReq::parse(req).expect("`req` is checked at compile time")
+        })
+    }
+}
+
+pub trait ValidSemver {
+    fn validate_semver(&self, req: Option<&semver::VersionReq>) -> Result<(), InvalidSemver>;
+}
+
+impl<T: AsStr> ValidSemver for T {
+    fn validate_semver(&self, req: Option<&semver::VersionReq>) -> Result<(), InvalidSemver> {
+        let version = semver::Version::parse(self.as_str())?;
+        match req {
+            Some(req) if !req.matches(&version) => Err(InvalidSemver::Unsatisfied),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<T: ValidSemver> ValidSemver for Option<T> {
+    fn validate_semver(&self, req: Option<&semver::VersionReq>) -> Result<(), InvalidSemver> {
+        match self {
+            Some(value) => value.validate_semver(req),
+            None => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum InvalidSemver {
+    Parse(semver::Error),
+    Unsatisfied,
+}
+
+impl Display for InvalidSemver {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvalidSemver::Parse(e) => write!(f, "{e}"),
+            InvalidSemver::Unsatisfied => write!(f, "version requirement not satisfied"),
+        }
+    }
+}
+
+impl From<semver::Error> for InvalidSemver {
+    fn from(value: semver::Error) -> Self {
+        Self::Parse(value)
+    }
+}
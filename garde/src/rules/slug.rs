@@ -0,0 +1,109 @@
+//! URL slug validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(slug)]
+//!     v: String,
+//!     #[garde(slug(allow_uppercase, allow_dots))]
+//!     w: String,
+//! }
+//! ```
+//!
+//! A slug consists of lowercase ASCII letters and digits, separated by single hyphens, e.g. `my-first-post`.
+//! It may not be empty, begin or end with a hyphen, or contain consecutive hyphens.
+//!
+//! The `allow_uppercase` argument also allows uppercase ASCII letters, and the `allow_dots` argument
+//! allows dots as separators, following the same rules as hyphens, e.g. `release-1.0`.
+//!
+//! The entrypoint is the [`ValidSlug`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(slug)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+
+use std::fmt::Display;
+
+use super::AsStr;
+use crate::error::Error;
+
+pub fn apply<T: ValidSlug>(
+    v: &T,
+    (allow_uppercase, allow_dots): (bool, bool),
+) -> Result<(), Error> {
+    if let Err(e) = v.validate_slug(allow_uppercase, allow_dots) {
+        return Err(Error::new(format!("not a valid slug: {e}")));
+    }
+    Ok(())
+}
+
+pub trait ValidSlug {
+    fn validate_slug(&self, allow_uppercase: bool, allow_dots: bool) -> Result<(), InvalidSlug>;
+}
+
+impl<T: AsStr> ValidSlug for T {
+    fn validate_slug(&self, allow_uppercase: bool, allow_dots: bool) -> Result<(), InvalidSlug> {
+        parse_slug(self.as_str(), allow_uppercase, allow_dots)
+    }
+}
+
+impl<T: ValidSlug> ValidSlug for Option<T> {
+    fn validate_slug(&self, allow_uppercase: bool, allow_dots: bool) -> Result<(), InvalidSlug> {
+        match self {
+            Some(value) => value.validate_slug(allow_uppercase, allow_dots),
+            None => Ok(()),
+        }
+    }
+}
+
+fn parse_slug(value: &str, allow_uppercase: bool, allow_dots: bool) -> Result<(), InvalidSlug> {
+    if value.is_empty() {
+        return Err(InvalidSlug::Empty);
+    }
+
+    let is_separator = |b: u8| b == b'-' || (allow_dots && b == b'.');
+    let mut previous_is_separator = false;
+    for (i, b) in value.bytes().enumerate() {
+        if is_separator(b) {
+            if i == 0 {
+                return Err(InvalidSlug::SeparatorAtEdge);
+            }
+            if previous_is_separator {
+                return Err(InvalidSlug::ConsecutiveSeparators);
+            }
+            previous_is_separator = true;
+        } else if b.is_ascii_lowercase()
+            || b.is_ascii_digit()
+            || (allow_uppercase && b.is_ascii_uppercase())
+        {
+            previous_is_separator = false;
+        } else {
+            return Err(InvalidSlug::InvalidCharacter);
+        }
+    }
+
+    if previous_is_separator {
+        return Err(InvalidSlug::SeparatorAtEdge);
+    }
+
+    Ok(())
+}
+
+#[derive(Debug)]
+pub enum InvalidSlug {
+    Empty,
+    InvalidCharacter,
+    SeparatorAtEdge,
+    ConsecutiveSeparators,
+}
+
+impl Display for InvalidSlug {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvalidSlug::Empty => write!(f, "value is empty"),
+            InvalidSlug::InvalidCharacter => write!(f, "value contains invalid characters"),
+            InvalidSlug::SeparatorAtEdge => write!(f, "value begins or ends with a separator"),
+            InvalidSlug::ConsecutiveSeparators => {
+                write!(f, "value contains consecutive separators")
+            }
+        }
+    }
+}
@@ -0,0 +1,117 @@
+//! Sort order validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(sorted)]
+//!     v: Vec<u64>,
+//!     #[garde(sorted(descending, strict))]
+//!     w: Vec<f64>,
+//! }
+//! ```
+//!
+//! By default, the items must be in ascending order, and adjacent items may be equal.
+//! The `descending` argument requires descending order instead, and the `strict` argument
+//! rejects adjacent items which are equal. Items which can't be compared, such as `NaN`, are out of order.
+//!
+//! The entrypoint is the [`IsSorted`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(sorted)]` rule.
+//!
+//! This trait is implemented for `std` sequences whose items are `PartialOrd`.
+
+use std::fmt::Display;
+
+use crate::error::Error;
+
+pub fn apply<T: IsSorted>(v: &T, (order, strict): (Order, bool)) -> Result<(), Error> {
+    if let Some(index) = v.first_unsorted(order, strict) {
+        let order = match strict {
+            true => format!("strictly {order}"),
+            false => order.to_string(),
+        };
+        return Err(Error::new(format!(
+            "not sorted in {order} order: item at index {index} is out of order with item at index {}",
+            index - 1
+        ))
+        .with_metadata("index", index));
+    }
+    Ok(())
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Order {
+    Ascending,
+    Descending,
+}
+
+impl Order {
+    fn is_sorted<T: PartialOrd>(self, a: &T, b: &T, strict: bool) -> bool {
+        match (self, strict) {
+            (Order::Ascending, false) => a <= b,
+            (Order::Ascending, true) => a < b,
+            (Order::Descending, false) => a >= b,
+            (Order::Descending, true) => a > b,
+        }
+    }
+}
+
+impl Display for Order {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Order::Ascending => write!(f, "ascending"),
+            Order::Descending => write!(f, "descending"),
+        }
+    }
+}
+
+pub trait IsSorted {
+    /// Returns the index of the first item which is out of order with the item before it.
+    fn first_unsorted(&self, order: Order, strict: bool) -> Option<usize>;
+}
+
+fn first_unsorted<'a, T: PartialOrd + 'a>(
+    iter: impl Iterator<Item = &'a T>,
+    order: Order,
+    strict: bool,
+) -> Option<usize> {
+    let mut previous = None;
+    for (index, item) in iter.enumerate() {
+        if let Some(previous) = previous {
+            if !order.is_sorted(previous, item, strict) {
+                return Some(index);
+            }
+        }
+        previous = Some(item);
+    }
+    None
+}
+
+macro_rules! impl_via_iter {
+    (in<$($lifetime:lifetime,)? T> $C:ty) => {
+        impl<$($lifetime,)? T: PartialOrd> IsSorted for $C {
+            fn first_unsorted(&self, order: Order, strict: bool) -> Option<usize> {
+                first_unsorted(self.iter(), order, strict)
+            }
+        }
+    };
+}
+
+impl_via_iter!(in<T> Vec<T>);
+impl_via_iter!(in<'a, T> &'a Vec<T>);
+impl_via_iter!(in<'a, T> &'a [T]);
+impl_via_iter!(in<T> std::collections::VecDeque<T>);
+impl_via_iter!(in<T> std::collections::LinkedList<T>);
+
+impl<const N: usize, T: PartialOrd> IsSorted for [T; N] {
+    fn first_unsorted(&self, order: Order, strict: bool) -> Option<usize> {
+        first_unsorted(self.iter(), order, strict)
+    }
+}
+
+impl<T: IsSorted> IsSorted for Option<T> {
+    fn first_unsorted(&self, order: Order, strict: bool) -> Option<usize> {
+        match self {
+            Some(value) => value.first_unsorted(order, strict),
+            None => None,
+        }
+    }
+}
@@ -9,9 +9,13 @@
 //!     v: String,
 //!     #[garde(suffix(SFX))]
 //!     w: String,
+//!     #[garde(not_suffix("_test"))]
+//!     x: String,
 //! }
 //! ```
 //!
+//! The `not_suffix` rule inverts the check, and is implemented in the [`not`] module.
+//!
 //! The entrypoint is the [`Suffix`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(suffix)]` rule.
 //!
 //! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
@@ -26,8 +30,24 @@ pub fn apply<T: Suffix>(v: &T, (pat,): (&str,)) -> Result<(), Error> {
     Ok(())
 }
 
+pub mod not {
+    use super::Suffix;
+    use crate::error::Error;
+
+    pub fn apply<T: Suffix>(v: &T, (pat,): (&str,)) -> Result<(), Error> {
+        if !v.validate_not_suffix(pat) {
+            return Err(Error::new(format!("must not end with \"{pat}\"")));
+        }
+        Ok(())
+    }
+}
+
 pub trait Suffix {
     fn validate_suffix(&self, pat: &str) -> bool;
+
+    fn validate_not_suffix(&self, pat: &str) -> bool {
+        !self.validate_suffix(pat)
+    }
 }
 
 impl<T: AsStr> Suffix for T {
@@ -43,4 +63,11 @@ impl<T: Suffix> Suffix for Option<T> {
             None => true,
         }
     }
+
+    fn validate_not_suffix(&self, pat: &str) -> bool {
+        match self {
+            Some(value) => value.validate_not_suffix(pat),
+            None => true,
+        }
+    }
 }
@@ -0,0 +1,63 @@
+//! Time of day validation using the [`chrono`] crate.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(time)]
+//!     v: String,
+//!     #[garde(time(format = "%H:%M", after = "08:00", before = "18:00"))]
+//!     w: String,
+//! }
+//! ```
+//!
+//! All arguments are optional:
+//! - `format`: a [`chrono` format string](chrono::format::strftime), `%H:%M:%S` by default.
+//! - `after`: the time must be later than this time.
+//! - `before`: the time must be earlier than this time.
+//!
+//! The bounds are written in the same format as the value, and are checked at compile time.
+//!
+//! The entrypoint is the [`ValidTime`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(time)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+
+use chrono::{NaiveTime, ParseResult};
+
+use super::date::check_bounds;
+use super::AsStr;
+use crate::error::Error;
+
+pub const DEFAULT_FORMAT: &str = "%H:%M:%S";
+
+pub fn apply<T: ValidTime>(
+    v: &T,
+    (format, after, before): (Option<&str>, Option<&str>, Option<&str>),
+) -> Result<(), Error> {
+    let format = format.unwrap_or(DEFAULT_FORMAT);
+    let parse = |v: &str| NaiveTime::parse_from_str(v, format);
+    match v.parse_time(format) {
+        Ok(Some(value)) => check_bounds(value, after, before, parse),
+        Ok(None) => Ok(()),
+        Err(e) => Err(Error::new(format!("not a valid time: {e}"))),
+    }
+}
+
+pub trait ValidTime {
+    /// Parses the value, or returns `None` if there is no value to parse.
+    fn parse_time(&self, format: &str) -> ParseResult<Option<NaiveTime>>;
+}
+
+impl<T: AsStr> ValidTime for T {
+    fn parse_time(&self, format: &str) -> ParseResult<Option<NaiveTime>> {
+        NaiveTime::parse_from_str(self.as_str(), format).map(Some)
+    }
+}
+
+impl<T: ValidTime> ValidTime for Option<T> {
+    fn parse_time(&self, format: &str) -> ParseResult<Option<NaiveTime>> {
+        match self {
+            Some(value) => value.parse_time(format),
+            None => Ok(None),
+        }
+    }
+}
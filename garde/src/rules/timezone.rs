@@ -0,0 +1,72 @@
+//! Timezone validation using the [`chrono_tz`] crate.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(timezone)]
+//!     v: String,
+//!     #[garde(timezone(utc_offset_only))]
+//!     w: String,
+//! }
+//! ```
+//!
+//! By default, the value must be an IANA timezone identifier, such as `America/New_York` or `UTC`.
+//! Identifiers are case-sensitive.
+//!
+//! The `utc_offset_only` argument instead requires a UTC offset in the `+HH:MM` or `-HH:MM` format,
+//! such as `+05:30`, where the hours are between `00` and `23`, and the minutes are between `00` and `59`.
+//!
+//! The entrypoint is the [`Timezone`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(timezone)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+
+use super::AsStr;
+use crate::error::Error;
+
+pub fn apply<T: Timezone>(v: &T, (utc_offset_only,): (bool,)) -> Result<(), Error> {
+    if !v.validate_timezone(utc_offset_only) {
+        return Err(match utc_offset_only {
+            true => Error::new("not a valid UTC offset"),
+            false => Error::new("not a valid IANA timezone"),
+        });
+    }
+    Ok(())
+}
+
+pub trait Timezone {
+    fn validate_timezone(&self, utc_offset_only: bool) -> bool;
+}
+
+impl<T: AsStr> Timezone for T {
+    fn validate_timezone(&self, utc_offset_only: bool) -> bool {
+        let value = self.as_str();
+        match utc_offset_only {
+            true => is_utc_offset(value),
+            false => value.parse::<chrono_tz::Tz>().is_ok(),
+        }
+    }
+}
+
+impl<T: Timezone> Timezone for Option<T> {
+    fn validate_timezone(&self, utc_offset_only: bool) -> bool {
+        match self {
+            Some(value) => value.validate_timezone(utc_offset_only),
+            None => true,
+        }
+    }
+}
+
+fn is_utc_offset(value: &str) -> bool {
+    let &[sign, h1, h2, b':', m1, m2] = value.as_bytes() else {
+        return false;
+    };
+    if sign != b'+' && sign != b'-' {
+        return false;
+    }
+    if ![h1, h2, m1, m2].iter().all(u8::is_ascii_digit) {
+        return false;
+    }
+    let hours = (h1 - b'0') * 10 + (h2 - b'0');
+    let minutes = (m1 - b'0') * 10 + (m2 - b'0');
+    hours <= 23 && minutes <= 59
+}
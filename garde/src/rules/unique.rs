@@ -0,0 +1,215 @@
+//! Uniqueness validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(unique)]
+//!     v: Vec<String>,
+//! }
+//! ```
+//!
+//! The `unique_by` rule compares the elements by a key, which is returned by the given function.
+//! It is implemented in the [`by`] module:
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(unique_by(|user: &User| &user.email))]
+//!     v: Vec<User>,
+//! }
+//!
+//! struct User {
+//!     email: String,
+//! }
+//! ```
+//!
+//! The entrypoint is the [`Unique`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(unique)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::unique::HasUniqueElements`,
+//! which is implemented for `std` sequences and sets whose items are `Eq + Hash`.
+//!
+//! The error contains the index of the first duplicate item, which is also available as the `index` metadata.
+
+use std::collections::HashSet;
+use std::hash::Hash;
+
+use crate::error::Error;
+
+pub fn apply<T: Unique>(v: &T, _: ()) -> Result<(), Error> {
+    if let Err(index) = v.validate_unique() {
+        return Err(
+            Error::new(format!("duplicate value found at index {index}"))
+                .with_metadata("index", index),
+        );
+    }
+    Ok(())
+}
+
+pub mod by {
+    use std::collections::HashSet;
+    use std::fmt::Display;
+    use std::hash::Hash;
+
+    use crate::error::Error;
+
+    pub fn apply<'a, T, F, K>(v: &'a T, (key,): (F,)) -> Result<(), Error>
+    where
+        T: UniqueBy,
+        F: Fn(&'a T::Item) -> K,
+        K: Eq + Hash + Display,
+    {
+        if let Some((index, duplicate)) = v.first_duplicate_by(key) {
+            return Err(Error::new(format!(
+                "duplicate value found at index {index}: '{duplicate}'"
+            ))
+            .with_metadata("index", index));
+        }
+        Ok(())
+    }
+
+    /// Implemented by collections which may be validated using `#[garde(unique_by(...))]`.
+    pub trait UniqueBy {
+        type Item;
+
+        /// Returns the index and key of the first item whose key is equal to the key of some item before it.
+        fn first_duplicate_by<'a, K, F>(&'a self, key: F) -> Option<(usize, K)>
+        where
+            F: Fn(&'a Self::Item) -> K,
+            K: Eq + Hash;
+    }
+
+    fn first_duplicate_by<'a, T: 'a, K: Eq + Hash>(
+        iter: impl Iterator<Item = &'a T>,
+        key: impl Fn(&'a T) -> K,
+    ) -> Option<(usize, K)> {
+        let mut seen = HashSet::new();
+        for (index, item) in iter.enumerate() {
+            if let Some(duplicate) = seen.replace(key(item)) {
+                return Some((index, duplicate));
+            }
+        }
+        None
+    }
+
+    macro_rules! impl_via_iter {
+        (in<$($lifetime:lifetime,)? T> $C:ty) => {
+            impl<$($lifetime,)? T> UniqueBy for $C {
+                type Item = T;
+
+                fn first_duplicate_by<'b, K, F>(&'b self, key: F) -> Option<(usize, K)>
+                where
+                    F: Fn(&'b Self::Item) -> K,
+                    K: Eq + Hash,
+                {
+                    first_duplicate_by(self.iter(), key)
+                }
+            }
+        };
+    }
+
+    impl_via_iter!(in<T> Vec<T>);
+    impl_via_iter!(in<'a, T> &'a Vec<T>);
+    impl_via_iter!(in<'a, T> &'a [T]);
+    impl_via_iter!(in<T> std::collections::VecDeque<T>);
+    impl_via_iter!(in<T> std::collections::LinkedList<T>);
+
+    impl<const N: usize, T> UniqueBy for [T; N] {
+        type Item = T;
+
+        fn first_duplicate_by<'a, K, F>(&'a self, key: F) -> Option<(usize, K)>
+        where
+            F: Fn(&'a Self::Item) -> K,
+            K: Eq + Hash,
+        {
+            first_duplicate_by(self.iter(), key)
+        }
+    }
+
+    impl<T: UniqueBy> UniqueBy for Option<T> {
+        type Item = T::Item;
+
+        fn first_duplicate_by<'a, K, F>(&'a self, key: F) -> Option<(usize, K)>
+        where
+            F: Fn(&'a Self::Item) -> K,
+            K: Eq + Hash,
+        {
+            match self {
+                Some(value) => value.first_duplicate_by(key),
+                None => None,
+            }
+        }
+    }
+}
+
+pub trait Unique {
+    /// Returns the index of the first duplicate item as the error.
+    fn validate_unique(&self) -> Result<(), usize>;
+}
+
+impl<T: HasUniqueElements> Unique for T {
+    fn validate_unique(&self) -> Result<(), usize> {
+        match self.first_duplicate() {
+            Some(index) => Err(index),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T: Unique> Unique for Option<T> {
+    fn validate_unique(&self) -> Result<(), usize> {
+        match self {
+            Some(value) => value.validate_unique(),
+            None => Ok(()),
+        }
+    }
+}
+
+pub trait HasUniqueElements {
+    /// Returns the index of the first item which is equal to some item before it.
+    fn first_duplicate(&self) -> Option<usize>;
+}
+
+fn first_duplicate<'a, T: Eq + Hash + 'a>(iter: impl Iterator<Item = &'a T>) -> Option<usize> {
+    let mut seen = HashSet::new();
+    iter.enumerate()
+        .find(|(_, item)| !seen.insert(*item))
+        .map(|(index, _)| index)
+}
+
+macro_rules! impl_via_iter {
+    (in<$($lifetime:lifetime,)? T> $C:ty) => {
+        impl<$($lifetime,)? T: Eq + Hash> HasUniqueElements for $C {
+            fn first_duplicate(&self) -> Option<usize> {
+                first_duplicate(self.iter())
+            }
+        }
+    };
+}
+
+impl_via_iter!(in<T> Vec<T>);
+impl_via_iter!(in<'a, T> &'a Vec<T>);
+impl_via_iter!(in<'a, T> &'a [T]);
+impl_via_iter!(in<T> std::collections::VecDeque<T>);
+impl_via_iter!(in<T> std::collections::LinkedList<T>);
+
+impl<const N: usize, T: Eq + Hash> HasUniqueElements for [T; N] {
+    fn first_duplicate(&self) -> Option<usize> {
+        first_duplicate(self.iter())
+    }
+}
+
+// Sets can never contain duplicates.
+macro_rules! impl_set {
+    (in<$($lifetime:lifetime,)? T $(, $S:ident)?> $C:ty) => {
+        impl<$($lifetime,)? T $(, $S)?> HasUniqueElements for $C {
+            fn first_duplicate(&self) -> Option<usize> {
+                None
+            }
+        }
+    };
+}
+
+impl_set!(in<T, S> std::collections::HashSet<T, S>);
+impl_set!(in<T> std::collections::BTreeSet<T>);
+impl_set!(in<'a, T, S> &'a std::collections::HashSet<T, S>);
+impl_set!(in<'a, T> &'a std::collections::BTreeSet<T>);
@@ -5,9 +5,19 @@
 //! struct Test {
 //!     #[garde(url)]
 //!     v: String,
+//!     #[garde(url(schemes = ["https"]))]
+//!     w: String,
+//!     #[garde(url(require_https, require_tld))]
+//!     x: String,
 //! }
 //! ```
 //!
+//! All arguments are optional:
+//! - `schemes`: the URL's scheme must be one of the listed values.
+//! - `require_https`: the URL's scheme must be `https`.
+//! - `require_tld`: the URL's host must be a domain with a top-level domain, e.g. `example.com`,
+//!   which rejects hosts such as `localhost` and IP addresses.
+//!
 //! The entrypoint is the [`Url`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(url)]` rule.
 //!
 //! The [`url`] crate only allows parsing from a `&str`, which is why this trait has a blanket implementation for all `T: garde::rules::AsStr`.
@@ -20,17 +30,50 @@ use std::fmt::Display;
 use super::AsStr;
 use crate::error::Error;
 
-pub fn apply<T: Url>(v: &T, _: ()) -> Result<(), Error> {
+pub fn apply<T: Url>(v: &T, (requirements,): (&UrlRequirements,)) -> Result<(), Error> {
     if let Err(e) = v.validate_url() {
         return Err(Error::new(format!("not a valid url: {e}")));
     }
+    if *requirements == UrlRequirements::default() {
+        return Ok(());
+    }
+    if let Err(e) = v.validate_url_requirements(requirements) {
+        return Err(match e {
+            InvalidUrl::Scheme => Error::new(format!(
+                "URL scheme must be one of: {}",
+                requirements.schemes.join(", ")
+            )),
+            InvalidUrl::NotHttps => Error::new("URL scheme must be https"),
+            InvalidUrl::NoTld => Error::new("URL host must have a top-level domain"),
+            e => Error::new(format!("not a valid url: {e}")),
+        });
+    }
     Ok(())
 }
 
+/// The requirements a URL must meet, in addition to being valid.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct UrlRequirements<'a> {
+    /// If not empty, the URL's scheme must be one of these.
+    pub schemes: &'a [&'a str],
+    /// The URL's scheme must be `https`.
+    pub require_https: bool,
+    /// The URL's host must be a domain with a top-level domain.
+    pub require_tld: bool,
+}
+
 pub trait Url {
     type Error: Display;
 
     fn validate_url(&self) -> Result<(), Self::Error>;
+
+    /// Checks the [`UrlRequirements`] of the `url` rule, which is only done if [`Url::validate_url`] succeeded.
+    ///
+    /// The default implementation can't inspect the URL, so it fails with [`InvalidUrl::Unsupported`].
+    fn validate_url_requirements(&self, requirements: &UrlRequirements) -> Result<(), InvalidUrl> {
+        let _ = requirements;
+        Err(InvalidUrl::Unsupported)
+    }
 }
 
 impl<T: AsStr> Url for T {
@@ -40,6 +83,31 @@ impl<T: AsStr> Url for T {
         let _ = url::Url::parse(self.as_str())?;
         Ok(())
     }
+
+    fn validate_url_requirements(&self, requirements: &UrlRequirements) -> Result<(), InvalidUrl> {
+        let url = url::Url::parse(self.as_str())?;
+        let schemes = requirements.schemes;
+        if !schemes.is_empty() && !schemes.contains(&url.scheme()) {
+            return Err(InvalidUrl::Scheme);
+        }
+        if requirements.require_https && url.scheme() != "https" {
+            return Err(InvalidUrl::NotHttps);
+        }
+        if requirements.require_tld && !has_tld(&url) {
+            return Err(InvalidUrl::NoTld);
+        }
+        Ok(())
+    }
+}
+
+fn has_tld(url: &url::Url) -> bool {
+    match url.host() {
+        Some(url::Host::Domain(domain)) => {
+            let domain = domain.strip_suffix('.').unwrap_or(domain);
+            matches!(domain.rsplit_once('.'), Some((name, tld)) if !name.is_empty() && !tld.is_empty())
+        }
+        _ => false,
+    }
 }
 
 impl<T: Url> Url for Option<T> {
@@ -51,4 +119,40 @@ impl<T: Url> Url for Option<T> {
             None => Ok(()),
         }
     }
+
+    fn validate_url_requirements(&self, requirements: &UrlRequirements) -> Result<(), InvalidUrl> {
+        match self {
+            Some(value) => value.validate_url_requirements(requirements),
+            None => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum InvalidUrl {
+    Parse(url::ParseError),
+    Scheme,
+    NotHttps,
+    NoTld,
+    /// The type does not support checking the requirements of the `url` rule.
+    Unsupported,
+}
+
+impl Display for InvalidUrl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InvalidUrl::Parse(e) => write!(f, "{e}"),
+            InvalidUrl::Scheme => write!(f, "scheme is not allowed"),
+            InvalidUrl::NotHttps => write!(f, "scheme is not https"),
+            InvalidUrl::NoTld => write!(f, "host has no top-level domain"),
+            InvalidUrl::Unsupported => write!(f, "requirements are not supported by this type"),
+        }
+    }
+}
+
+impl From<url::ParseError> for InvalidUrl {
+    fn from(value: url::ParseError) -> Self {
+        Self::Parse(value)
+    }
 }
@@ -0,0 +1,51 @@
+//! UUID validation using the [`uuid`] crate.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(uuid)]
+//!     v: String,
+//! }
+//! ```
+//!
+//! The entrypoint is the [`ValidUuid`] trait. Implementing this trait for a type allows that type to be used with the `#[garde(uuid)]` rule.
+//!
+//! This trait has a blanket implementation for all `T: garde::rules::AsStr`.
+
+use std::fmt::Display;
+
+use super::AsStr;
+use crate::error::Error;
+
+pub fn apply<T: ValidUuid>(v: &T, _: ()) -> Result<(), Error> {
+    if let Err(e) = v.validate_uuid() {
+        return Err(Error::new(format!("not a valid uuid: {e}")));
+    }
+    Ok(())
+}
+
+pub trait ValidUuid {
+    type Error: Display;
+
+    fn validate_uuid(&self) -> Result<(), Self::Error>;
+}
+
+impl<T: AsStr> ValidUuid for T {
+    type Error = uuid::Error;
+
+    fn validate_uuid(&self) -> Result<(), Self::Error> {
+        let _ = uuid::Uuid::parse_str(self.as_str())?;
+        Ok(())
+    }
+}
+
+impl<T: ValidUuid> ValidUuid for Option<T> {
+    type Error = T::Error;
+
+    fn validate_uuid(&self) -> Result<(), Self::Error> {
+        match self {
+            Some(value) => value.validate_uuid(),
+            None => Ok(()),
+        }
+    }
+}
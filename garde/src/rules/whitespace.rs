@@ -0,0 +1,27 @@
+//! Whitespace validation.
+//!
+//! ```rust
+//! #[derive(garde::Validate)]
+//! struct Test {
+//!     #[garde(no_whitespace)]
+//!     username: String,
+//!     #[garde(no_leading_whitespace, no_trailing_whitespace)]
+//!     display_name: String,
+//! }
+//! ```
+//!
+//! The entrypoints are the [`HasNoWhitespace`], [`HasNoLeadingWhitespace`], and [`HasNoTrailingWhitespace`] traits.
+//! Implementing these traits for a type allows that type to be used with the `#[garde(no_whitespace)]`,
+//! `#[garde(no_leading_whitespace)]`, and `#[garde(no_trailing_whitespace)]` rules respectively.
+//!
+//! All traits have a blanket implementation for all `T: garde::rules::AsStr`.
+//! Whitespace is defined according to [`char::is_whitespace`].
+
+pub mod none;
+pub use none::HasNoWhitespace;
+
+pub mod leading;
+pub use leading::HasNoLeadingWhitespace;
+
+pub mod trailing;
+pub use trailing::HasNoTrailingWhitespace;
@@ -0,0 +1,30 @@
+//! Implemented by string-like types which do not begin with whitespace.
+
+use crate::error::Error;
+use crate::rules::AsStr;
+
+pub fn apply<T: HasNoLeadingWhitespace>(v: &T, _: ()) -> Result<(), Error> {
+    if !v.validate_no_leading_whitespace() {
+        return Err(Error::new("begins with whitespace"));
+    }
+    Ok(())
+}
+
+pub trait HasNoLeadingWhitespace {
+    fn validate_no_leading_whitespace(&self) -> bool;
+}
+
+impl<T: AsStr> HasNoLeadingWhitespace for T {
+    fn validate_no_leading_whitespace(&self) -> bool {
+        !self.as_str().starts_with(char::is_whitespace)
+    }
+}
+
+impl<T: HasNoLeadingWhitespace> HasNoLeadingWhitespace for Option<T> {
+    fn validate_no_leading_whitespace(&self) -> bool {
+        match self {
+            Some(value) => value.validate_no_leading_whitespace(),
+            None => true,
+        }
+    }
+}
@@ -0,0 +1,30 @@
+//! Implemented by string-like types which contain no whitespace.
+
+use crate::error::Error;
+use crate::rules::AsStr;
+
+pub fn apply<T: HasNoWhitespace>(v: &T, _: ()) -> Result<(), Error> {
+    if !v.validate_no_whitespace() {
+        return Err(Error::new("contains whitespace"));
+    }
+    Ok(())
+}
+
+pub trait HasNoWhitespace {
+    fn validate_no_whitespace(&self) -> bool;
+}
+
+impl<T: AsStr> HasNoWhitespace for T {
+    fn validate_no_whitespace(&self) -> bool {
+        !self.as_str().chars().any(char::is_whitespace)
+    }
+}
+
+impl<T: HasNoWhitespace> HasNoWhitespace for Option<T> {
+    fn validate_no_whitespace(&self) -> bool {
+        match self {
+            Some(value) => value.validate_no_whitespace(),
+            None => true,
+        }
+    }
+}
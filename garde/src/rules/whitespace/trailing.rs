@@ -0,0 +1,30 @@
+//! Implemented by string-like types which do not end with whitespace.
+
+use crate::error::Error;
+use crate::rules::AsStr;
+
+pub fn apply<T: HasNoTrailingWhitespace>(v: &T, _: ()) -> Result<(), Error> {
+    if !v.validate_no_trailing_whitespace() {
+        return Err(Error::new("ends with whitespace"));
+    }
+    Ok(())
+}
+
+pub trait HasNoTrailingWhitespace {
+    fn validate_no_trailing_whitespace(&self) -> bool;
+}
+
+impl<T: AsStr> HasNoTrailingWhitespace for T {
+    fn validate_no_trailing_whitespace(&self) -> bool {
+        !self.as_str().ends_with(char::is_whitespace)
+    }
+}
+
+impl<T: HasNoTrailingWhitespace> HasNoTrailingWhitespace for Option<T> {
+    fn validate_no_trailing_whitespace(&self) -> bool {
+        match self {
+            Some(value) => value.validate_no_trailing_whitespace(),
+            None => true,
+        }
+    }
+}
@@ -0,0 +1,244 @@
+//! JSON Schema export.
+//!
+//! ```rust
+//! #[derive(garde::Validate, garde::JsonSchema)]
+//! struct User {
+//!     #[garde(length(min = 3, max = 25))]
+//!     username: String,
+//!     #[garde(email)]
+//!     email: String,
+//!     #[garde(range(min = 0, max = 100))]
+//!     score: u8,
+//! }
+//!
+//! let schema = garde::schema::json_schema::<User>();
+//! assert_eq!(schema["properties"]["username"]["minLength"], 3);
+//! assert_eq!(schema["properties"]["email"]["format"], "email");
+//! ```
+//!
+//! The `JsonSchema` derive reads the same `#[garde(...)]` attributes as the `Validate` derive,
+//! and produces a [JSON Schema (draft 7)](https://json-schema.org/specification-links#draft-7) object
+//! which describes the shape of the type, and the constraints placed on its fields.
+//!
+//! Rules which have a JSON Schema equivalent are mapped to it, for example `length` becomes `minLength`/`maxLength`
+//! (or `minItems`/`maxItems` for arrays), `range` becomes `minimum`/`maximum`, and `email` becomes `"format": "email"`.
+//! Rules which have no equivalent, custom rules, and bounds which depend on the context are not included in the schema.
+//! The derive assumes the default representation used by `serde`, so field renames are taken from `alias` and `rename_all`,
+//! and enums are externally tagged.
+//!
+//! The entrypoint is the [`JsonSchema`] trait. It is implemented for primitive types, strings, and standard collections,
+//! and the derive requires it to be implemented for the type of every field which is not skipped.
+
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::rc::Rc;
+use std::sync::Arc;
+
+use serde_json::{json, Map, Value};
+
+/// Returns the JSON Schema of `T`, including the `$schema` keyword.
+pub fn json_schema<T: JsonSchema + ?Sized>() -> Value {
+    let mut schema = T::json_schema();
+    if let Value::Object(object) = &mut schema {
+        object.insert(
+            "$schema".into(),
+            "http://json-schema.org/draft-07/schema#".into(),
+        );
+    }
+    schema
+}
+
+/// Implemented by types which can describe their JSON representation using a JSON Schema.
+pub trait JsonSchema {
+    fn json_schema() -> Value;
+}
+
+macro_rules! impl_json_schema {
+    ($ty:literal => $($T:ty),* $(,)?) => {
+        $(
+            impl JsonSchema for $T {
+                fn json_schema() -> Value {
+                    json!({ "type": $ty })
+                }
+            }
+        )*
+    };
+}
+
+impl_json_schema!("string" => String, str, char);
+impl_json_schema!("boolean" => bool);
+impl_json_schema!("integer" => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_json_schema!("number" => f32, f64);
+
+impl JsonSchema for Cow<'_, str> {
+    fn json_schema() -> Value {
+        str::json_schema()
+    }
+}
+
+impl<T: JsonSchema> JsonSchema for Option<T> {
+    fn json_schema() -> Value {
+        let mut schema = T::json_schema();
+        if let Some(ty) = schema.get_mut("type") {
+            match ty {
+                Value::String(name) => *ty = json!([name, "null"]),
+                Value::Array(types) if !types.contains(&json!("null")) => types.push(json!("null")),
+                _ => {}
+            }
+        }
+        schema
+    }
+}
+
+macro_rules! impl_json_schema_array {
+    ($($T:ty),* $(,)?) => {
+        $(
+            impl<T: JsonSchema> JsonSchema for $T {
+                fn json_schema() -> Value {
+                    json!({ "type": "array", "items": T::json_schema() })
+                }
+            }
+        )*
+    };
+}
+
+impl_json_schema_array!(Vec<T>, [T], VecDeque<T>);
+
+impl<T: JsonSchema, const N: usize> JsonSchema for [T; N] {
+    fn json_schema() -> Value {
+        json!({ "type": "array", "items": T::json_schema(), "minItems": N, "maxItems": N })
+    }
+}
+
+impl<T: JsonSchema, S> JsonSchema for HashSet<T, S> {
+    fn json_schema() -> Value {
+        json!({ "type": "array", "items": T::json_schema(), "uniqueItems": true })
+    }
+}
+
+impl<T: JsonSchema> JsonSchema for BTreeSet<T> {
+    fn json_schema() -> Value {
+        json!({ "type": "array", "items": T::json_schema(), "uniqueItems": true })
+    }
+}
+
+impl<K, V: JsonSchema, S> JsonSchema for HashMap<K, V, S> {
+    fn json_schema() -> Value {
+        json!({ "type": "object", "additionalProperties": V::json_schema() })
+    }
+}
+
+impl<K, V: JsonSchema> JsonSchema for BTreeMap<K, V> {
+    fn json_schema() -> Value {
+        json!({ "type": "object", "additionalProperties": V::json_schema() })
+    }
+}
+
+macro_rules! impl_json_schema_ptr {
+    ($($T:ty),* $(,)?) => {
+        $(
+            impl<T: JsonSchema + ?Sized> JsonSchema for $T {
+                fn json_schema() -> Value {
+                    T::json_schema()
+                }
+            }
+        )*
+    };
+}
+
+impl_json_schema_ptr!(&T, &mut T, Box<T>, Rc<T>, Arc<T>);
+
+#[doc(hidden)]
+pub fn __to_value<T: serde::Serialize + ?Sized>(value: &T) -> Value {
+    serde_json::to_value(value).unwrap_or(Value::Null)
+}
+
+#[doc(hidden)]
+pub fn __set(schema: &mut Value, key: &str, value: Value) {
+    if let Value::Object(object) = schema {
+        object.insert(key.into(), value);
+    }
+}
+
+/// Adds a property to an object schema, which is listed in `required` if `required` is `true`.
+#[doc(hidden)]
+pub fn __property(schema: &mut Value, name: &str, value: Value, required: bool) {
+    let Value::Object(object) = schema else {
+        return;
+    };
+    if let Value::Object(properties) = object
+        .entry("properties")
+        .or_insert_with(|| Value::Object(Map::new()))
+    {
+        properties.insert(name.into(), value);
+    }
+    if required {
+        if let Value::Array(names) = object.entry("required").or_insert_with(|| json!([])) {
+            names.push(name.into());
+        }
+    }
+}
+
+/// Sets the length keywords which match the type of the schema.
+#[doc(hidden)]
+pub fn __length(schema: &mut Value, min: Option<usize>, max: Option<usize>) {
+    let (min_key, max_key) = match type_of(schema) {
+        Some("array") => ("minItems", "maxItems"),
+        Some("object") => ("minProperties", "maxProperties"),
+        _ => ("minLength", "maxLength"),
+    };
+    if let Some(min) = min {
+        __set(schema, min_key, min.into());
+    }
+    if let Some(max) = max {
+        __set(schema, max_key, max.into());
+    }
+}
+
+/// Applies `f` to the subschema at `key`, such as `items` or `additionalProperties`.
+#[doc(hidden)]
+pub fn __subschema(schema: &mut Value, key: &str, f: impl FnOnce(&mut Value)) {
+    if let Value::Object(object) = schema {
+        match object.get_mut(key) {
+            Some(subschema @ Value::Object(_)) => f(subschema),
+            Some(_) => {}
+            None => {
+                let mut subschema = Value::Object(Map::new());
+                f(&mut subschema);
+                object.insert(key.into(), subschema);
+            }
+        }
+    }
+}
+
+/// Merges the properties of a flattened field into `schema`.
+#[doc(hidden)]
+pub fn __flatten(schema: &mut Value, flattened: Value) {
+    let (Value::Object(object), Value::Object(mut flattened)) = (schema, flattened) else {
+        return;
+    };
+    for key in ["properties", "required"] {
+        let Some(value) = flattened.remove(key) else {
+            continue;
+        };
+        match (object.get_mut(key), value) {
+            (Some(Value::Object(target)), Value::Object(source)) => target.extend(source),
+            (Some(Value::Array(target)), Value::Array(source)) => target.extend(source),
+            (None, value) => {
+                object.insert(key.into(), value);
+            }
+            _ => {}
+        }
+    }
+}
+
+fn type_of(schema: &Value) -> Option<&str> {
+    match schema.get("type")? {
+        Value::String(ty) => Some(ty),
+        Value::Array(types) => types
+            .iter()
+            .filter_map(Value::as_str)
+            .find(|ty| *ty != "null"),
+        _ => None,
+    }
+}
@@ -0,0 +1,228 @@
+//! Helpers for testing [`Validate`] implementations.
+//!
+//! This module is only available with the `testing` feature, which is meant to be enabled
+//! for `dev-dependencies`:
+//!
+//! ```toml
+//! [dev-dependencies]
+//! garde = { version = "*", features = ["testing"] }
+//! ```
+//!
+//! ```rust
+//! use garde::testing::{assert_invalid, assert_valid};
+//!
+//! #[derive(Debug, garde::Validate)]
+//! struct User {
+//!     #[garde(length(min = 3))]
+//!     name: String,
+//! }
+//!
+//! assert_valid(&User { name: "test".into() }, &());
+//! assert_invalid(&User { name: "a".into() }, &(), "name");
+//! ```
+//!
+//! With the `proptest` feature, `GardeStrategy` can be used to generate only values which pass validation.
+
+use std::fmt::Debug;
+
+use crate::{Report, Validate};
+
+/// Asserts that `value` passes validation.
+///
+/// On failure, the panic message contains the value and every error in the report.
+#[track_caller]
+pub fn assert_valid<T: Validate + Debug + ?Sized>(value: &T, ctx: &T::Context) {
+    if let Err(report) = value.validate_with(ctx) {
+        panic!(
+            "assertion failed: value is not valid\n\nvalue: {value:#?}\n\nerrors:\n{}",
+            format_report(&report)
+        );
+    }
+}
+
+/// Asserts that `value` fails validation, and that the report contains an error for `expected_field`.
+///
+/// `expected_field` is compared against the string representation of each error's [`Path`][`crate::Path`],
+/// such as `field`, `nested.field`, or `list[0]`.
+#[track_caller]
+pub fn assert_invalid<T: Validate + Debug + ?Sized>(
+    value: &T,
+    ctx: &T::Context,
+    expected_field: &str,
+) {
+    let report = match value.validate_with(ctx) {
+        Ok(()) => panic!(
+            "assertion failed: value is valid, expected an error for `{expected_field}`\n\nvalue: {value:#?}"
+        ),
+        Err(report) => report,
+    };
+
+    let found = report
+        .iter()
+        .any(|(path, _)| path.to_string() == expected_field);
+    if !found {
+        panic!(
+            "assertion failed: no error for `{expected_field}`\n\nvalue: {value:#?}\n\nerrors:\n{}",
+            format_report(&report)
+        );
+    }
+}
+
+fn format_report(report: &Report) -> String {
+    let mut out = String::new();
+    for (path, error) in report.iter() {
+        if path.is_empty() {
+            out.push_str(&format!("  - {error}\n"));
+        } else {
+            out.push_str(&format!("  - {path}: {error}\n"));
+        }
+    }
+    out
+}
+
+#[cfg(feature = "proptest")]
+pub use self::strategy::GardeStrategy;
+
+#[cfg(feature = "proptest")]
+mod strategy {
+    use std::fmt::Debug;
+
+    use proptest::strategy::{BoxedStrategy, NewTree, Strategy, ValueTree};
+    use proptest::test_runner::TestRunner;
+
+    use crate::Validate;
+
+    /// A [`Strategy`] which only generates values that pass validation.
+    ///
+    /// Values produced by the wrapped strategy which fail validation are rejected,
+    /// so the wrapped strategy should produce valid values most of the time.
+    ///
+    /// ```rust
+    /// use garde::testing::GardeStrategy;
+    /// use proptest::prelude::*;
+    ///
+    /// #[derive(Debug, garde::Validate)]
+    /// struct User {
+    ///     #[garde(length(min = 3, max = 8))]
+    ///     name: String,
+    /// }
+    ///
+    /// proptest! {
+    ///     # /*
+    ///     #[test]
+    ///     # */
+    ///     fn name_is_never_empty(
+    ///         user in GardeStrategy::new("[a-z]{0,10}".prop_map(|name| User { name }))
+    ///     ) {
+    ///         prop_assert!(!user.name.is_empty());
+    ///     }
+    /// }
+    /// # name_is_never_empty();
+    /// ```
+    #[must_use = "strategies do nothing unless used"]
+    pub struct GardeStrategy<T> {
+        inner: BoxedStrategy<T>,
+    }
+
+    impl<T> GardeStrategy<T>
+    where
+        T: Validate + Debug + 'static,
+        T::Context: 'static,
+    {
+        /// Wraps `strategy`, validating its values using the default context.
+        pub fn new(strategy: impl Strategy<Value = T> + 'static) -> Self
+        where
+            T::Context: Default,
+        {
+            Self::with_context(strategy, T::Context::default())
+        }
+
+        /// Wraps `strategy`, validating its values using `ctx`.
+        pub fn with_context(strategy: impl Strategy<Value = T> + 'static, ctx: T::Context) -> Self {
+            Self {
+                inner: strategy
+                    .prop_filter("value must pass validation", move |value| {
+                        value.validate_with(&ctx).is_ok()
+                    })
+                    .boxed(),
+            }
+        }
+    }
+
+    impl<T> Clone for GardeStrategy<T> {
+        fn clone(&self) -> Self {
+            Self {
+                inner: self.inner.clone(),
+            }
+        }
+    }
+
+    impl<T: Debug> Debug for GardeStrategy<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("GardeStrategy").finish_non_exhaustive()
+        }
+    }
+
+    impl<T: Debug> Strategy for GardeStrategy<T> {
+        type Tree = Box<dyn ValueTree<Value = T>>;
+        type Value = T;
+
+        fn new_tree(&self, runner: &mut TestRunner) -> NewTree<Self> {
+            self.inner.new_tree(runner)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Error, Path};
+
+    #[derive(Debug)]
+    struct Test {
+        valid: bool,
+    }
+
+    impl Validate for Test {
+        type Context = ();
+
+        fn validate_into(
+            &self,
+            _: &Self::Context,
+            parent: &mut dyn FnMut() -> Path,
+            report: &mut Report,
+        ) {
+            if !self.valid {
+                report.append(parent().join("field"), Error::new("invalid"));
+            }
+        }
+    }
+
+    #[test]
+    fn assert_valid_ok() {
+        assert_valid(&Test { valid: true }, &());
+    }
+
+    #[test]
+    #[should_panic(expected = "value is not valid")]
+    fn assert_valid_fails() {
+        assert_valid(&Test { valid: false }, &());
+    }
+
+    #[test]
+    fn assert_invalid_ok() {
+        assert_invalid(&Test { valid: false }, &(), "field");
+    }
+
+    #[test]
+    #[should_panic(expected = "value is valid")]
+    fn assert_invalid_fails_on_valid() {
+        assert_invalid(&Test { valid: true }, &(), "field");
+    }
+
+    #[test]
+    #[should_panic(expected = "no error for `other`")]
+    fn assert_invalid_fails_on_missing_field() {
+        assert_invalid(&Test { valid: false }, &(), "other");
+    }
+}
@@ -1,6 +1,47 @@
 //! ## Core validation traits and types
-
-use std::fmt::Debug;
+//!
+//! ### Tuples
+//!
+//! [`Validate`] is implemented for tuples of up to 12 elements. Every element must
+//! implement [`Validate`] with the same [`Context`][Validate::Context], which becomes
+//! the context of the tuple itself. Errors are reported at the element's index:
+//!
+//! ```rust
+//! use garde::Validate;
+//!
+//! #[derive(garde::Validate)]
+//! #[garde(transparent)]
+//! struct Name(#[garde(length(min = 1))] String);
+//!
+//! #[derive(garde::Validate)]
+//! #[garde(transparent)]
+//! struct Age(#[garde(range(max = 150))] u32);
+//!
+//! #[derive(garde::Validate)]
+//! struct Config {
+//!     #[garde(dive)]
+//!     pair: (Name, Age),
+//! }
+//!
+//! let config = Config {
+//!     pair: (Name("".into()), Age(200)),
+//! };
+//! let report = config.validate().unwrap_err();
+//! assert_eq!(
+//!     report.to_string(),
+//!     "pair[0]: length is lower than 1\npair[1]: greater than 150\n"
+//! );
+//! ```
+//!
+//! Elements with different contexts can't be combined in a tuple directly.
+//! Instead, wrap them in a struct which uses `dive_with` to pass
+//! the appropriate part of a shared context to each field.
+
+use std::fmt::{Debug, Display};
+use std::str::FromStr;
+
+#[cfg(feature = "serde")]
+use serde::de::DeserializeSeed as _;
 
 use crate::error::{Path, PathComponentKind};
 use crate::Report;
@@ -36,9 +77,9 @@ pub trait Validate {
     fn validate_with(&self, ctx: &Self::Context) -> Result<(), Report> {
         let mut report = Report::new();
         self.validate_into(ctx, &mut Path::empty, &mut report);
-        match report.is_empty() {
-            true => Ok(()),
-            false => Err(report),
+        match report.has_errors() {
+            false => Ok(()),
+            true => Err(report),
         }
     }
 
@@ -49,13 +90,173 @@ pub trait Validate {
         parent: &mut dyn FnMut() -> Path,
         report: &mut Report,
     );
+
+    /// Validates only the fields of `Self` which belong to `group`, returning an `Err`
+    /// with an aggregate of all errors if the validation failed.
+    ///
+    /// Fields are assigned to groups using `#[garde(group(...))]`. Fields without a group
+    /// are validated in every group.
+    ///
+    /// This method should not be implemented manually. Implement [`Validate::validate_group_into`] instead,
+    /// because [`Validate::validate_group`] has a default implementation that calls [`Validate::validate_group_into`].
+    fn validate_group(&self, group: &str, ctx: &Self::Context) -> Result<(), Report> {
+        let mut report = Report::new();
+        self.validate_group_into(group, ctx, &mut Path::empty, &mut report);
+        match report.has_errors() {
+            false => Ok(()),
+            true => Err(report),
+        }
+    }
+
+    /// Validates only the fields of `Self` which belong to `group`, aggregating all
+    /// validation errors into `Report`.
+    ///
+    /// The default implementation validates everything by calling [`Validate::validate_into`].
+    /// Groups only apply to the fields of the type they are declared on, values validated
+    /// through `dive` are validated in full.
+    fn validate_group_into(
+        &self,
+        group: &str,
+        ctx: &Self::Context,
+        parent: &mut dyn FnMut() -> Path,
+        report: &mut Report,
+    ) {
+        let _ = group;
+        self.validate_into(ctx, parent, report)
+    }
+}
+
+/// An asynchronous extension of [`Validate`].
+///
+/// This is used to run validation which requires `await`ing, such as looking up
+/// a value in a database. It is implemented by `derive(Validate)` for any type
+/// which uses the `async_custom` rule, or which is marked with `#[garde(async)]`.
+///
+/// Fields marked with `dive` are validated asynchronously if their type implements `AsyncValidate`.
+/// This is also the case for `Option`, `Box`, `Arc`, `Vec`, slices and arrays of such types.
+///
+/// The trait is defined using [`async_trait`][async_trait::async_trait], which means that
+/// it may also be implemented manually using `#[garde::external::async_trait::async_trait]`.
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+pub trait AsyncValidate: Validate + Sync
+where
+    Self::Context: Sync,
+{
+    /// Validates `Self`, returning an `Err` with an aggregate of all errors if
+    /// the validation failed.
+    ///
+    /// This method should not be implemented manually. Implement [`AsyncValidate::validate_into_async`] instead,
+    /// because [`AsyncValidate::validate_async`] has a default implementation that calls [`AsyncValidate::validate_into_async`].
+    async fn validate_async(&self) -> Result<(), Report>
+    where
+        Self::Context: Default + Send,
+    {
+        let ctx = Self::Context::default();
+        self.validate_with_async(&ctx).await
+    }
+
+    /// Validates `Self`, returning an `Err` with an aggregate of all errors if
+    /// the validation failed.
+    ///
+    /// This method should not be implemented manually. Implement [`AsyncValidate::validate_into_async`] instead,
+    /// because [`AsyncValidate::validate_with_async`] has a default implementation that calls [`AsyncValidate::validate_into_async`].
+    async fn validate_with_async(&self, ctx: &Self::Context) -> Result<(), Report> {
+        let mut report = Report::new();
+        self.validate_into(ctx, &mut Path::empty, &mut report);
+        self.validate_into_async(ctx, Path::empty(), &mut report)
+            .await;
+        match report.has_errors() {
+            false => Ok(()),
+            true => Err(report),
+        }
+    }
+
+    /// Runs the asynchronous rules of `Self`, aggregating all validation errors into `Report`.
+    ///
+    /// The synchronous rules are not run by this method, they are run by [`AsyncValidate::validate_with_async`]
+    /// before it calls this method.
+    async fn validate_into_async(&self, ctx: &Self::Context, parent: Path, report: &mut Report);
+}
+
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+impl<T: ?Sized + AsyncValidate> AsyncValidate for &T
+where
+    T::Context: Sync,
+{
+    async fn validate_into_async(&self, ctx: &Self::Context, parent: Path, report: &mut Report) {
+        <T as AsyncValidate>::validate_into_async(self, ctx, parent, report).await
+    }
+}
+
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+impl<T: AsyncValidate> AsyncValidate for std::boxed::Box<T>
+where
+    T::Context: Sync,
+{
+    async fn validate_into_async(&self, ctx: &Self::Context, parent: Path, report: &mut Report) {
+        <T as AsyncValidate>::validate_into_async(self, ctx, parent, report).await
+    }
+}
+
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+impl<T: AsyncValidate + Send> AsyncValidate for std::sync::Arc<T>
+where
+    T::Context: Sync,
+{
+    async fn validate_into_async(&self, ctx: &Self::Context, parent: Path, report: &mut Report) {
+        <T as AsyncValidate>::validate_into_async(self, ctx, parent, report).await
+    }
+}
+
+#[cfg(feature = "async")]
+#[async_trait::async_trait]
+impl<T: AsyncValidate> AsyncValidate for Option<T>
+where
+    T::Context: Sync,
+{
+    async fn validate_into_async(&self, ctx: &Self::Context, parent: Path, report: &mut Report) {
+        if let Some(value) = self {
+            value.validate_into_async(ctx, parent, report).await
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+macro_rules! impl_async_validate_list {
+    (<$T:ident $(, const $N:ident: usize)?> $Container:ty) => {
+        #[async_trait::async_trait]
+        impl<$T $(, const $N: usize)?> AsyncValidate for $Container
+        where
+            $T: AsyncValidate,
+            $T::Context: Sync,
+        {
+            async fn validate_into_async(&self, ctx: &Self::Context, parent: Path, report: &mut Report) {
+                for (index, item) in self.iter().enumerate() {
+                    <$T as AsyncValidate>::validate_into_async(item, ctx, parent.join(index), report).await;
+                }
+            }
+        }
+    };
 }
 
+#[cfg(feature = "async")]
+impl_async_validate_list!(<T> std::vec::Vec<T>);
+#[cfg(feature = "async")]
+impl_async_validate_list!(<T> [T]);
+#[cfg(feature = "async")]
+impl_async_validate_list!(<T, const N: usize> [T; N]);
+
 /// A struct which wraps a valid instance of some `T`.
 ///
-/// The only way to create an instance of this struct is through the `validate`
-/// function on the [`Unvalidated`] type. This ensures that if you have a `Valid<T>`,
-/// it was definitely validated at some point. This is commonly referred to as the
+/// The only safe way to create an instance of this struct is through the `validate`
+/// function on the [`Unvalidated`] type, or by transforming another `Valid<T>` with
+/// [`Valid::map`] or [`Valid::and_then`], which validate the result. This ensures that
+/// if you have a `Valid<T>`, it was definitely validated at some point, unless it was
+/// created with the `unsafe` [`Valid::map_unchecked`]. This is commonly referred to as the
 /// typestate pattern.
 #[derive(Debug, Clone, Copy)]
 pub struct Valid<T>(T);
@@ -65,6 +266,98 @@ impl<T: Validate> Valid<T> {
     pub fn into_inner(self) -> T {
         self.0
     }
+
+    /// Transforms the inner value using `f`, and validates the result using `ctx`.
+    ///
+    /// ```rust
+    /// # use garde::{Unvalidated, Validate};
+    /// #[derive(garde::Validate)]
+    /// struct Name(#[garde(length(min = 1))] String);
+    ///
+    /// let name = Unvalidated::new(Name("test".into())).validate().unwrap();
+    /// assert!(name.map(|name| Name(name.0.replace("test", "")), &()).is_err());
+    /// ```
+    pub fn map<U: Validate>(
+        self,
+        f: impl FnOnce(T) -> U,
+        ctx: &<U as Validate>::Context,
+    ) -> Result<Valid<U>, Report> {
+        Unvalidated(f(self.0)).validate_with(ctx)
+    }
+
+    /// Transforms the inner value using `f`, and validates the result using `ctx`.
+    ///
+    /// Unlike [`Valid::map`], `f` may itself fail with a [`Report`].
+    pub fn and_then<U: Validate>(
+        self,
+        f: impl FnOnce(T) -> Result<U, Report>,
+        ctx: &<U as Validate>::Context,
+    ) -> Result<Valid<U>, Report> {
+        Unvalidated(f(self.0)?).validate_with(ctx)
+    }
+
+    /// Transforms the inner value using `f`, without validating the result.
+    ///
+    /// # Safety
+    ///
+    /// This bypasses validation, so the caller must ensure that the value returned by `f`
+    /// is valid, e.g. because `f` only changes fields which have no rules.
+    /// Code which relies on a `Valid<U>` having been validated may misbehave otherwise.
+    pub unsafe fn map_unchecked<U>(self, f: impl FnOnce(T) -> U) -> Valid<U> {
+        Valid(f(self.0))
+    }
+}
+
+impl<T> AsRef<T> for Valid<T> {
+    fn as_ref(&self) -> &T {
+        &self.0
+    }
+}
+
+// Note: `TryFrom<T>` can't be implemented here, because it would conflict with
+// the blanket `impl<T, U: Into<T>> TryFrom<U> for T` in `core`.
+impl<T: Validate<Context = ()>> TryFrom<Unvalidated<T>> for Valid<T> {
+    type Error = Report;
+
+    fn try_from(value: Unvalidated<T>) -> Result<Self, Self::Error> {
+        value.validate()
+    }
+}
+
+impl<T: Validate<Context = ()> + FromStr> FromStr for Valid<T> {
+    type Err = ParseValidError<<T as FromStr>::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = T::from_str(s).map_err(ParseValidError::Parse)?;
+        Valid::try_from(Unvalidated(value)).map_err(ParseValidError::Invalid)
+    }
+}
+
+/// The error returned when parsing a [`Valid<T>`] from a string.
+#[derive(Debug, Clone)]
+pub enum ParseValidError<E> {
+    /// The string could not be parsed into a `T`.
+    Parse(E),
+    /// The parsed `T` is invalid.
+    Invalid(Report),
+}
+
+impl<E: Display> Display for ParseValidError<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseValidError::Parse(e) => Display::fmt(e, f),
+            ParseValidError::Invalid(report) => Display::fmt(report, f),
+        }
+    }
+}
+
+impl<E: std::error::Error + 'static> std::error::Error for ParseValidError<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ParseValidError::Parse(e) => Some(e),
+            ParseValidError::Invalid(report) => Some(report),
+        }
+    }
 }
 
 impl<T> std::ops::Deref for Valid<T> {
@@ -75,6 +368,92 @@ impl<T> std::ops::Deref for Valid<T> {
     }
 }
 
+/// Serializes the inner `T`.
+///
+/// Together with the [`Deserialize`][serde::Deserialize] implementation, this means that
+/// a serialized `Valid<T>` is validated again when it is deserialized.
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for Valid<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+/// Deserializes a `T`, and then validates it using the default context.
+///
+/// If validation fails, deserialization fails with an error containing the full [`Report`].
+/// To deserialize with a non-default context, use [`ValidWithContext`].
+#[cfg(feature = "serde")]
+impl<'de, T> serde::Deserialize<'de> for Valid<T>
+where
+    T: Validate + serde::Deserialize<'de>,
+    <T as Validate>::Context: Default,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let ctx = <T as Validate>::Context::default();
+        ValidWithContext::new(&ctx).deserialize(deserializer)
+    }
+}
+
+/// A [`DeserializeSeed`][serde::de::DeserializeSeed] which deserializes a `T`,
+/// and then validates it using the provided context.
+///
+/// ```rust
+/// use serde::de::DeserializeSeed as _;
+///
+/// #[derive(garde::Validate, serde::Deserialize)]
+/// #[garde(context(usize as ctx))]
+/// struct User {
+///     #[garde(length(max = *ctx))]
+///     name: String,
+/// }
+///
+/// let max_len = 5;
+/// let mut de = serde_json::Deserializer::from_str(r#"{ "name": "Bobby Tables" }"#);
+/// let result = garde::validate::ValidWithContext::<User>::new(&max_len).deserialize(&mut de);
+/// assert!(result.is_err());
+/// ```
+#[cfg(feature = "serde")]
+pub struct ValidWithContext<'a, T: Validate> {
+    ctx: &'a <T as Validate>::Context,
+}
+
+#[cfg(feature = "serde")]
+impl<'a, T: Validate> ValidWithContext<'a, T> {
+    /// Creates a `ValidWithContext<T>` which validates using `ctx`.
+    pub fn new(ctx: &'a <T as Validate>::Context) -> Self {
+        Self { ctx }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'a, 'de, T> serde::de::DeserializeSeed<'de> for ValidWithContext<'a, T>
+where
+    T: Validate + serde::Deserialize<'de>,
+{
+    type Value = Valid<T>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = T::deserialize(deserializer)?;
+        match value.validate_with(self.ctx) {
+            Ok(()) => Ok(Valid(value)),
+            Err(report) => Err(serde::de::Error::custom(format_args!(
+                "validation failed: {}",
+                report.to_string().trim_end()
+            ))),
+        }
+    }
+}
+
 /// A struct which wraps a potentially invalid instance of some `T`.
 ///
 /// Use the `validate` method to turn this type into a `Valid<T>`.
@@ -91,7 +470,7 @@ impl<T: Validate> Unvalidated<T> {
     }
 
     /// Validates `self`, transforming it into a `Valid<T>`.
-    /// This is the only way to create an instance of `Valid<T>`.
+    /// This is the only safe way to create an instance of `Valid<T>` from an unvalidated value.
     pub fn validate(self) -> Result<Valid<T>, Report>
     where
         <T as Validate>::Context: Default,
@@ -101,11 +480,40 @@ impl<T: Validate> Unvalidated<T> {
     }
 
     /// Validates `self`, transforming it into a `Valid<T>`.
-    /// This is the only way to create an instance of `Valid<T>`.
+    /// This is the only safe way to create an instance of `Valid<T>` from an unvalidated value.
     pub fn validate_with(self, ctx: &<T as Validate>::Context) -> Result<Valid<T>, Report> {
         self.0.validate_with(ctx)?;
         Ok(Valid(self.0))
     }
+
+    /// Validates `self` using the context returned by `ctx`, transforming it into a `Valid<T>`.
+    ///
+    /// The context is only constructed once validation actually happens, which is useful
+    /// when building it is expensive or depends on the value being validated.
+    pub fn validate_with_context(
+        self,
+        ctx: impl FnOnce() -> <T as Validate>::Context,
+    ) -> Result<Valid<T>, Report> {
+        let ctx = ctx();
+        self.validate_with(&ctx)
+    }
+
+    /// Modifies the inner value using `f` before it is validated.
+    ///
+    /// ```rust
+    /// # use garde::Unvalidated;
+    /// #[derive(garde::Validate)]
+    /// struct Name(#[garde(length(min = 1, max = 4))] String);
+    ///
+    /// let name = Unvalidated::new(Name("  test  ".into()))
+    ///     .map(|name| Name(name.0.trim().to_string()))
+    ///     .validate()
+    ///     .unwrap();
+    /// assert_eq!(name.0, "test");
+    /// ```
+    pub fn map(self, f: impl FnOnce(T) -> T) -> Self {
+        Self(f(self.0))
+    }
 }
 
 impl<T: Validate> From<T> for Unvalidated<T> {
@@ -131,6 +539,16 @@ impl<'a, T: ?Sized + Validate> Validate for &'a T {
     ) {
         <T as Validate>::validate_into(self, ctx, parent, report)
     }
+
+    fn validate_group_into(
+        &self,
+        group: &str,
+        ctx: &Self::Context,
+        parent: &mut dyn FnMut() -> Path,
+        report: &mut Report,
+    ) {
+        <T as Validate>::validate_group_into(self, group, ctx, parent, report)
+    }
 }
 
 impl<'a, T: ?Sized + Validate> Validate for &'a mut T {
@@ -144,8 +562,20 @@ impl<'a, T: ?Sized + Validate> Validate for &'a mut T {
     ) {
         <T as Validate>::validate_into(self, ctx, parent, report)
     }
+
+    fn validate_group_into(
+        &self,
+        group: &str,
+        ctx: &Self::Context,
+        parent: &mut dyn FnMut() -> Path,
+        report: &mut Report,
+    ) {
+        <T as Validate>::validate_group_into(self, group, ctx, parent, report)
+    }
 }
 
+// Note: The pointer impls require `T: Sized`, so trait objects such as
+// `Box<dyn Validate<Context = ()>>` can't be validated through them.
 impl<T: Validate> Validate for std::boxed::Box<T> {
     type Context = T::Context;
 
@@ -157,6 +587,16 @@ impl<T: Validate> Validate for std::boxed::Box<T> {
     ) {
         <T as Validate>::validate_into(self, ctx, parent, report)
     }
+
+    fn validate_group_into(
+        &self,
+        group: &str,
+        ctx: &Self::Context,
+        parent: &mut dyn FnMut() -> Path,
+        report: &mut Report,
+    ) {
+        <T as Validate>::validate_group_into(self, group, ctx, parent, report)
+    }
 }
 
 impl<T: Validate> Validate for std::rc::Rc<T> {
@@ -170,6 +610,16 @@ impl<T: Validate> Validate for std::rc::Rc<T> {
     ) {
         <T as Validate>::validate_into(self, ctx, parent, report)
     }
+
+    fn validate_group_into(
+        &self,
+        group: &str,
+        ctx: &Self::Context,
+        parent: &mut dyn FnMut() -> Path,
+        report: &mut Report,
+    ) {
+        <T as Validate>::validate_group_into(self, group, ctx, parent, report)
+    }
 }
 
 impl<T: Validate> Validate for std::sync::Arc<T> {
@@ -183,6 +633,16 @@ impl<T: Validate> Validate for std::sync::Arc<T> {
     ) {
         <T as Validate>::validate_into(self, ctx, parent, report)
     }
+
+    fn validate_group_into(
+        &self,
+        group: &str,
+        ctx: &Self::Context,
+        parent: &mut dyn FnMut() -> Path,
+        report: &mut Report,
+    ) {
+        <T as Validate>::validate_group_into(self, group, ctx, parent, report)
+    }
 }
 
 macro_rules! impl_validate_list {
@@ -332,7 +792,7 @@ impl<T: Validate> Validate for Option<T> {
     }
 }
 
-impl<'a, B: Validate> Validate for std::borrow::Cow<'a, B>
+impl<'a, B: ?Sized + Validate> Validate for std::borrow::Cow<'a, B>
 where
     B: ToOwned,
 {
@@ -347,3 +807,155 @@ where
         self.as_ref().validate_into(ctx, parent, report)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Error;
+
+    #[derive(Debug)]
+    struct Even(u32);
+
+    impl FromStr for Even {
+        type Err = std::num::ParseIntError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            s.parse().map(Even)
+        }
+    }
+
+    impl Validate for Even {
+        type Context = ();
+
+        fn validate_into(&self, _: &(), parent: &mut dyn FnMut() -> Path, report: &mut Report) {
+            if self.0 % 2 != 0 {
+                report.append(parent(), Error::new("not even"));
+            }
+        }
+    }
+
+    #[test]
+    #[allow(clippy::needless_borrows_for_generic_args)]
+    fn validate_reference() {
+        fn check(value: impl Validate<Context = ()>) -> Result<(), Report> {
+            value.validate()
+        }
+
+        let value = Even(1);
+        assert_eq!(check(&value).unwrap_err().to_string(), "not even\n");
+        assert!(check(&&Even(2)).is_ok());
+        assert!(check(&mut Even(4)).is_ok());
+    }
+
+    #[test]
+    fn valid_try_from() {
+        assert_eq!(Valid::try_from(Unvalidated::new(Even(2))).unwrap().0 .0, 2);
+
+        let report = Valid::try_from(Unvalidated::new(Even(1))).unwrap_err();
+        assert_eq!(report.to_string(), "not even\n");
+    }
+
+    #[test]
+    fn valid_from_str() {
+        assert_eq!("2".parse::<Valid<Even>>().unwrap().0 .0, 2);
+
+        assert!(matches!(
+            "a".parse::<Valid<Even>>(),
+            Err(ParseValidError::Parse(_))
+        ));
+        assert!(matches!(
+            "1".parse::<Valid<Even>>(),
+            Err(ParseValidError::Invalid(_))
+        ));
+    }
+
+    #[test]
+    fn valid_map() {
+        let value = Unvalidated::new(Even(2)).validate().unwrap();
+        assert_eq!(value.as_ref().0, 2);
+
+        let value = value.map(|v| Even(v.0 + 2), &()).unwrap();
+        assert_eq!(value.0 .0, 4);
+
+        let report = value.map(|v| Even(v.0 + 1), &()).unwrap_err();
+        assert_eq!(report.to_string(), "not even\n");
+
+        let value = Unvalidated::new(Even(2)).validate().unwrap();
+        let value = value.and_then(|v| Ok(Even(v.0 * 3)), &()).unwrap();
+        assert_eq!(value.into_inner().0, 6);
+
+        let value = Unvalidated::new(Even(2)).validate().unwrap();
+        // Safety: adding 2 to an even number keeps it even
+        let value = unsafe { value.map_unchecked(|v| Even(v.0 + 2)) };
+        assert_eq!(value.into_inner().0, 4);
+    }
+
+    #[test]
+    fn unvalidated_map() {
+        let value = Unvalidated::new(Even(1)).map(|v| Even(v.0 + 1));
+        assert_eq!(value.validate_with_context(|| ()).unwrap().0 .0, 2);
+
+        let value = Unvalidated::new(Even(2)).map(|v| Even(v.0 + 1));
+        assert!(value.validate_with_context(|| ()).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    mod serde {
+        use super::*;
+
+        #[derive(Debug, ::serde::Serialize, ::serde::Deserialize)]
+        struct Test(u32);
+
+        impl Validate for Test {
+            type Context = u32;
+
+            fn validate_into(
+                &self,
+                ctx: &Self::Context,
+                parent: &mut dyn FnMut() -> Path,
+                report: &mut Report,
+            ) {
+                if self.0 > *ctx {
+                    report.append(parent(), Error::new(format!("greater than {ctx}")));
+                }
+            }
+        }
+
+        #[test]
+        fn deserialize_valid() {
+            let value: Valid<Test> = serde_json::from_str("0").unwrap();
+            assert_eq!(value.0 .0, 0);
+
+            let err = serde_json::from_str::<Valid<Test>>("1").unwrap_err();
+            assert_eq!(err.to_string(), "validation failed: greater than 0");
+        }
+
+        #[test]
+        fn deserialize_valid_with_context() {
+            let mut de = serde_json::Deserializer::from_str("10");
+            let value = ValidWithContext::<Test>::new(&10)
+                .deserialize(&mut de)
+                .unwrap();
+            assert_eq!(value.0 .0, 10);
+
+            let mut de = serde_json::Deserializer::from_str("11");
+            let err = ValidWithContext::<Test>::new(&10)
+                .deserialize(&mut de)
+                .unwrap_err();
+            assert_eq!(err.to_string(), "validation failed: greater than 10");
+        }
+
+        #[test]
+        fn serialize_roundtrip_revalidates() {
+            let value = Unvalidated::new(Test(5)).validate_with(&10).unwrap();
+            let json = serde_json::to_string(&value).unwrap();
+            assert_eq!(json, "5");
+
+            let mut de = serde_json::Deserializer::from_str(&json);
+            let err = ValidWithContext::<Test>::new(&4)
+                .deserialize(&mut de)
+                .unwrap_err();
+            assert_eq!(err.to_string(), "validation failed: greater than 4");
+        }
+    }
+}
@@ -0,0 +1,23 @@
+//! Rules which are built on top of another feature must also work when only their own feature is enabled.
+//!
+//! CI runs this test with `--no-default-features` and each of these features on its own.
+
+#![cfg(feature = "derive")]
+
+#[cfg(feature = "common-passwords")]
+#[test]
+fn common_passwords() {
+    use garde::Validate as _;
+
+    #[derive(garde::Validate)]
+    struct Test {
+        #[garde(not_common_password)]
+        password: String,
+    }
+
+    assert!(Test {
+        password: "password".into()
+    }
+    .validate()
+    .is_err());
+}
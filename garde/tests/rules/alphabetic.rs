@@ -0,0 +1,60 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(alphabetic)]
+    field: &'a str,
+
+    #[garde(inner(alphabetic))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn alphabetic_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: "abcd",
+                inner: &["abcd"],
+            },
+            Test {
+                field: "café",
+                inner: &["ひらがな", "Ελληνικά"],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn alphabetic_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                field: "abcd0123",
+                inner: &["abcd0123"]
+            },
+            Test {
+                field: "ひら がな",
+                inner: &["😂"]
+            },
+        ],
+        &()
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+struct Ascii<'a> {
+    #[garde(alphabetic(ascii))]
+    field: &'a str,
+}
+
+#[test]
+fn alphabetic_ascii_valid() {
+    util::check_ok(&[Ascii { field: "abcd" }], &())
+}
+
+#[test]
+fn alphabetic_ascii_invalid() {
+    util::check_fail!(&[Ascii { field: "abcd0123" }, Ascii { field: "café" }], &())
+}
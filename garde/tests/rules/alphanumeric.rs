@@ -12,10 +12,16 @@ struct Test<'a> {
 #[test]
 fn alphanumeric_valid() {
     util::check_ok(
-        &[Test {
-            field: "abcd0123",
-            inner: &["abcd0123"],
-        }],
+        &[
+            Test {
+                field: "abcd0123",
+                inner: &["abcd0123"],
+            },
+            Test {
+                field: "café",
+                inner: &["ひらがな", "٣٤٥"],
+            },
+        ],
         &(),
     )
 }
@@ -30,3 +36,26 @@ fn alphanumeric_invalid() {
         &()
     )
 }
+
+#[derive(Debug, garde::Validate)]
+struct Ascii<'a> {
+    #[garde(alphanumeric(ascii))]
+    field: &'a str,
+}
+
+#[test]
+fn alphanumeric_ascii_valid() {
+    util::check_ok(&[Ascii { field: "abcd0123" }], &())
+}
+
+#[test]
+fn alphanumeric_ascii_invalid() {
+    util::check_fail!(
+        &[
+            Ascii { field: "!!!!" },
+            Ascii { field: "café" },
+            Ascii { field: "٣٤٥" },
+        ],
+        &()
+    )
+}
@@ -0,0 +1,199 @@
+use super::util;
+
+struct Context {
+    taken: Vec<&'static str>,
+}
+
+async fn is_available(value: &str, ctx: &Context) -> garde::Result {
+    if ctx.taken.contains(&value) {
+        return Err(garde::Error::new(format!("`{value}` is already taken")));
+    }
+    Ok(())
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(Context as ctx))]
+struct Test<'a> {
+    #[garde(length(min = 1), async_custom(is_available))]
+    name: &'a str,
+    #[garde(async_custom(|value: &u32, _: &Context| {
+        let value = *value;
+        async move {
+            if value % 2 != 0 {
+                return Err(garde::Error::new("not even"));
+            }
+            Ok(())
+        }
+    }))]
+    even: u32,
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(Context))]
+enum Enum<'a> {
+    Struct {
+        #[garde(async_custom(is_available))]
+        name: &'a str,
+    },
+    Tuple(
+        #[garde(skip)] u32,
+        #[garde(async_custom(is_available))] &'a str,
+    ),
+    Unit,
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(Context))]
+struct Nested<'a> {
+    #[garde(async_custom(is_available))]
+    name: &'a str,
+    #[garde(dive)]
+    inner: Test<'a>,
+    #[garde(dive)]
+    list: Vec<Enum<'a>>,
+    #[garde(dive)]
+    optional: Option<Box<Test<'a>>>,
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(Context))]
+#[garde(async)]
+struct Wrapper<'a> {
+    #[garde(dive)]
+    inner: Test<'a>,
+    #[garde(length(min = 1))]
+    sync_only: &'a str,
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(Context))]
+struct Message<'a> {
+    #[garde(
+        length(min = 1),
+        async_custom(is_available),
+        message("{value:?} is not available")
+    )]
+    name: &'a str,
+}
+
+fn ctx() -> Context {
+    Context {
+        taken: vec!["admin", "root"],
+    }
+}
+
+#[test]
+fn async_custom_valid() {
+    util::check_ok_async(
+        &[Test {
+            name: "user",
+            even: 2,
+        }],
+        &ctx(),
+    );
+    util::check_ok_async(
+        &[
+            Enum::Struct { name: "user" },
+            Enum::Tuple(0, "user"),
+            Enum::Unit,
+        ],
+        &ctx(),
+    )
+}
+
+#[test]
+fn async_custom_invalid() {
+    util::check_fail_async!(
+        &[
+            Test {
+                name: "admin",
+                even: 1,
+            },
+            Test { name: "", even: 3 },
+        ],
+        &ctx()
+    )
+}
+
+#[test]
+fn async_custom_enum_invalid() {
+    util::check_fail_async!(
+        &[Enum::Struct { name: "root" }, Enum::Tuple(0, "admin")],
+        &ctx()
+    )
+}
+
+#[test]
+fn async_custom_is_skipped_in_sync_validation() {
+    util::check_ok(
+        &[Test {
+            name: "admin",
+            even: 1,
+        }],
+        &ctx(),
+    )
+}
+
+#[test]
+fn async_custom_nested_valid() {
+    util::check_ok_async(
+        &[Nested {
+            name: "user",
+            inner: Test {
+                name: "user",
+                even: 2,
+            },
+            list: vec![Enum::Struct { name: "user" }, Enum::Unit],
+            optional: Some(Box::new(Test {
+                name: "user",
+                even: 4,
+            })),
+        }],
+        &ctx(),
+    );
+    util::check_ok_async(
+        &[Wrapper {
+            inner: Test {
+                name: "user",
+                even: 2,
+            },
+            sync_only: "a",
+        }],
+        &ctx(),
+    )
+}
+
+#[test]
+fn async_custom_nested_invalid() {
+    util::check_fail_async!(
+        &[Nested {
+            name: "root",
+            inner: Test {
+                name: "admin",
+                even: 1,
+            },
+            list: vec![Enum::Unit, Enum::Tuple(0, "admin")],
+            optional: Some(Box::new(Test { name: "", even: 3 })),
+        }],
+        &ctx()
+    )
+}
+
+#[test]
+fn async_custom_async_attr_invalid() {
+    util::check_fail_async!(
+        &[Wrapper {
+            inner: Test {
+                name: "admin",
+                even: 1,
+            },
+            sync_only: "",
+        }],
+        &ctx()
+    )
+}
+
+#[test]
+fn async_custom_message_invalid() {
+    util::check_fail_async!(&[Message { name: "admin" }, Message { name: "" }], &ctx())
+}
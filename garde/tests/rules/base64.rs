@@ -0,0 +1,58 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(base64)]
+    field: &'a str,
+
+    #[garde(base64(url_safe, padding = "optional"))]
+    url_safe: &'a str,
+
+    #[garde(base64(padding = "none"))]
+    unpadded: &'a str,
+
+    #[garde(inner(base64))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn base64_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: "aGVsbG8gd29ybGQ=",
+                url_safe: "-_-_",
+                unpadded: "aGVsbG8gd29ybGQ",
+                inner: &["aGVsbG8gd29ybGQ="],
+            },
+            Test {
+                field: "",
+                url_safe: "aGVsbG8gd29ybGQ=",
+                unpadded: "",
+                inner: &[""],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn base64_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                field: "aGVsbG8gd29ybGQ",
+                url_safe: "+/+/",
+                unpadded: "aGVsbG8gd29ybGQ=",
+                inner: &["aGVsbG8gd29ybGQ"],
+            },
+            Test {
+                field: "not base64!",
+                url_safe: "a",
+                unpadded: "a",
+                inner: &["not base64!"],
+            },
+        ],
+        &()
+    )
+}
@@ -0,0 +1,61 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(byte_length(min = 1, max = 4))]
+    field: String,
+    #[garde(byte_length(equal = 2))]
+    str_field: &'a str,
+    #[garde(byte_length(exact = 2))]
+    exact: &'a str,
+    #[garde(length(chars, max = 2), byte_length(max = 4))]
+    composed: &'a str,
+    #[garde(inner(byte_length(max = 1)))]
+    inner: &'a [&'a [u8]],
+}
+
+#[test]
+fn byte_length_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: "abcd".into(),
+                str_field: "ab",
+                exact: "ab",
+                composed: "éé",
+                inner: &[b"a", b""],
+            },
+            Test {
+                field: "é".into(),
+                str_field: "ü",
+                exact: "ü",
+                composed: "ab",
+                inner: &[],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn byte_length_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                field: "".into(),
+                str_field: "a",
+                exact: "a",
+                composed: "abc",
+                inner: &[b"ab"],
+            },
+            Test {
+                field: "ééé".into(),
+                str_field: "üü",
+                exact: "üü",
+                composed: "😀😀",
+                inner: &[b"", b"abc"],
+            },
+        ],
+        &()
+    )
+}
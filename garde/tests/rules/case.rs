@@ -0,0 +1,75 @@
+use garde::external::compact_str::CompactString;
+
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Lowercase<'a> {
+    #[garde(lowercase)]
+    field: &'a str,
+
+    #[garde(inner(lowercase))]
+    inner: &'a [&'a str],
+
+    #[garde(lowercase)]
+    compact: CompactString,
+}
+
+#[test]
+fn lowercase_valid() {
+    util::check_ok(
+        &[Lowercase {
+            field: "a-slug_123",
+            inner: &["ünïcødé"],
+            compact: CompactString::new("usd"),
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn lowercase_invalid() {
+    util::check_fail!(
+        &[Lowercase {
+            field: "a-Slug",
+            inner: &["ÜNÏCØDÉ"],
+            compact: CompactString::new("USD"),
+        }],
+        &()
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+struct Uppercase<'a> {
+    #[garde(uppercase)]
+    field: &'a str,
+
+    #[garde(inner(uppercase))]
+    inner: &'a [&'a str],
+
+    #[garde(uppercase)]
+    compact: CompactString,
+}
+
+#[test]
+fn uppercase_valid() {
+    util::check_ok(
+        &[Uppercase {
+            field: "USD-123",
+            inner: &["ÜNÏCØDÉ"],
+            compact: CompactString::new("EUR"),
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn uppercase_invalid() {
+    util::check_fail!(
+        &[Uppercase {
+            field: "Usd",
+            inner: &["ünïcødé"],
+            compact: CompactString::new("eur"),
+        }],
+        &()
+    )
+}
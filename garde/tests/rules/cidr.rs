@@ -0,0 +1,55 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(cidr)]
+    field: &'a str,
+    #[garde(cidr(version = "ipv4"))]
+    v4: String,
+    #[garde(cidr(version = "ipv6"))]
+    v6: Option<String>,
+    #[garde(inner(cidr))]
+    inner: Vec<String>,
+}
+
+#[test]
+fn cidr_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: "192.168.1.0/24",
+                v4: "10.0.0.0/8".into(),
+                v6: None,
+                inner: vec!["0.0.0.0/0".into(), "::/0".into()],
+            },
+            Test {
+                field: "2001:db8::/32",
+                v4: "192.168.1.1/32".into(),
+                v6: Some("fe80::1/128".into()),
+                inner: vec![],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn cidr_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                field: "192.168.1.0",
+                v4: "2001:db8::/32".into(),
+                v6: Some("192.168.1.0/24".into()),
+                inner: vec!["10.0.0.0/33".into()],
+            },
+            Test {
+                field: "",
+                v4: "256.0.0.0/8".into(),
+                v6: Some("2001:db8::/129".into()),
+                inner: vec!["10.0.0.0/8".into(), "10.0.0.0/".into()],
+            },
+        ],
+        &()
+    )
+}
@@ -0,0 +1,68 @@
+use garde::Validate;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(length(min = 2))]
+    length: &'a str,
+    #[garde(range(max = 10))]
+    range: u32,
+    #[garde(ascii, length(max = 4), code("INVALID_NAME"))]
+    name: &'a str,
+    #[garde(inner(length(min = 1)), code("EMPTY_TAG"))]
+    tags: &'a [&'a str],
+    #[garde(
+        length(min = 3, code = "USERNAME_TOO_SHORT"),
+        ascii,
+        code("INVALID_USERNAME")
+    )]
+    username: &'a str,
+    #[garde(
+        email(code = "INVALID_EMAIL"),
+        not(contains("+"), code = "EMAIL_ALIAS")
+    )]
+    email: &'a str,
+}
+
+#[test]
+fn code_valid() {
+    let value = Test {
+        length: "ab",
+        range: 10,
+        name: "abc",
+        tags: &["a"],
+        username: "abc",
+        email: "test@example.com",
+    };
+    assert!(value.validate().is_ok());
+}
+
+#[test]
+fn code_invalid() {
+    let value = Test {
+        length: "a",
+        range: 11,
+        name: "💩💩💩",
+        tags: &[""],
+        username: "ü",
+        email: "test+alias",
+    };
+    let report = value.validate().unwrap_err();
+    let codes = report
+        .iter()
+        .map(|(path, error)| (path.to_string(), error.code()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        codes,
+        [
+            ("email".to_string(), Some("INVALID_EMAIL")),
+            ("email".to_string(), Some("EMAIL_ALIAS")),
+            ("length".to_string(), Some("GARDE_LENGTH")),
+            ("name".to_string(), Some("INVALID_NAME")),
+            ("name".to_string(), Some("INVALID_NAME")),
+            ("range".to_string(), Some("GARDE_RANGE")),
+            ("tags[0]".to_string(), Some("EMPTY_TAG")),
+            ("username".to_string(), Some("INVALID_USERNAME")),
+            ("username".to_string(), Some("USERNAME_TOO_SHORT")),
+        ]
+    );
+}
@@ -0,0 +1,60 @@
+use super::util;
+
+const TERMS: &[&str] = &["foo", "bar"];
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(contains_all(["foo", "bar", "baz"]))]
+    field: &'a str,
+
+    #[garde(contains_all(TERMS))]
+    field_path: &'a str,
+
+    #[garde(contains_all(["foo", "bar"]))]
+    field_option: Option<&'a str>,
+
+    #[garde(inner(contains_all(["foo", "bar"])))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn contains_all_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: "_baz_bar_foo_",
+                field_path: "foobar",
+                field_option: Some("barfoo"),
+                inner: &["_foo_bar_"],
+            },
+            Test {
+                field: "foobarbaz",
+                field_path: "foobar",
+                field_option: None,
+                inner: &[],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn contains_all_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                field: "_foo_",
+                field_path: "_foo_",
+                field_option: Some("_bar_"),
+                inner: &["_____"]
+            },
+            Test {
+                field: "_____",
+                field_path: "_____",
+                field_option: Some("_____"),
+                inner: &["_foo_"]
+            },
+        ],
+        &()
+    )
+}
@@ -0,0 +1,52 @@
+use super::util;
+
+const TERMS: &[&str] = &["spam", "scam"];
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(contains_any(["spam", "scam", "phishing"]))]
+    field: &'a str,
+
+    #[garde(contains_any(TERMS))]
+    field_path: &'a str,
+
+    #[garde(contains_any(["spam", "scam"]))]
+    field_option: Option<&'a str>,
+
+    #[garde(inner(contains_any(["spam", "scam"])))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn contains_any_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: "_phishing_",
+                field_path: "_scam_",
+                field_option: Some("_spam_scam_"),
+                inner: &["_spam_"],
+            },
+            Test {
+                field: "spam",
+                field_path: "spam",
+                field_option: None,
+                inner: &[],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn contains_any_invalid() {
+    util::check_fail!(
+        &[Test {
+            field: "_____",
+            field_path: "_____",
+            field_option: Some("_____"),
+            inner: &["_____"]
+        }],
+        &()
+    )
+}
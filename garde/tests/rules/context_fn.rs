@@ -0,0 +1,81 @@
+use super::util;
+
+struct CheckoutContext {
+    card: CardContext,
+    max_transfer: u32,
+    max_note_length: usize,
+}
+
+struct CardContext {
+    max_amount: u32,
+}
+
+fn card_context(ctx: &CheckoutContext) -> &CardContext {
+    &ctx.card
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(CardContext as ctx))]
+struct CardPayment {
+    #[garde(range(max = ctx.max_amount))]
+    amount: u32,
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(CheckoutContext as ctx))]
+enum Payment {
+    #[garde(context_fn(card_context))]
+    Card(#[garde(dive)] CardPayment),
+    #[garde(context_fn(card_context))]
+    Cards {
+        #[garde(dive)]
+        primary: CardPayment,
+        #[garde(range(max = ctx.max_amount))]
+        surcharge: u32,
+    },
+    #[garde(context_fn(|ctx: &CheckoutContext| &ctx.max_transfer))]
+    Transfer(#[garde(range(max = *ctx))] u32),
+    Note(#[garde(length(max = ctx.max_note_length))] String),
+    Cash,
+}
+
+fn ctx() -> CheckoutContext {
+    CheckoutContext {
+        card: CardContext { max_amount: 100 },
+        max_transfer: 1000,
+        max_note_length: 5,
+    }
+}
+
+#[test]
+fn context_fn_valid() {
+    util::check_ok(
+        &[
+            Payment::Card(CardPayment { amount: 100 }),
+            Payment::Cards {
+                primary: CardPayment { amount: 50 },
+                surcharge: 100,
+            },
+            Payment::Transfer(1000),
+            Payment::Note("hello".into()),
+            Payment::Cash,
+        ],
+        &ctx(),
+    )
+}
+
+#[test]
+fn context_fn_invalid() {
+    util::check_fail!(
+        &[
+            Payment::Card(CardPayment { amount: 101 }),
+            Payment::Cards {
+                primary: CardPayment { amount: 200 },
+                surcharge: 101,
+            },
+            Payment::Transfer(1001),
+            Payment::Note("too long".into()),
+        ],
+        &ctx()
+    )
+}
@@ -0,0 +1,65 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(country_code)]
+    field: &'a str,
+
+    #[garde(country_code(alpha = 3))]
+    alpha3: &'a str,
+
+    #[garde(country_code(case_insensitive))]
+    case_insensitive: &'a str,
+
+    #[garde(country_code(alpha = 3, case_insensitive))]
+    alpha3_case_insensitive: &'a str,
+
+    #[garde(inner(country_code))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn country_code_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: "DE",
+                alpha3: "DEU",
+                case_insensitive: "de",
+                alpha3_case_insensitive: "deu",
+                inner: &["AD", "ZW"],
+            },
+            Test {
+                field: "US",
+                alpha3: "USA",
+                case_insensitive: "Us",
+                alpha3_case_insensitive: "USA",
+                inner: &[],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn country_code_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                field: "de",
+                alpha3: "DE",
+                case_insensitive: "XX",
+                alpha3_case_insensitive: "xxx",
+                inner: &["UK"],
+            },
+            Test {
+                field: "DEU",
+                alpha3: "deu",
+                case_insensitive: "d",
+                alpha3_case_insensitive: "de",
+                inner: &["ÄÖ"],
+            },
+        ],
+        &()
+    )
+}
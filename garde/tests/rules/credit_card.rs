@@ -42,3 +42,53 @@ fn credit_card_invalid() {
         &()
     )
 }
+
+#[derive(Debug, garde::Validate)]
+struct Networks<'a> {
+    #[garde(credit_card(networks = ["visa", "mastercard"]))]
+    field: &'a str,
+
+    #[garde(credit_card(networks = ["amex"]))]
+    amex: Option<&'a str>,
+
+    #[garde(inner(credit_card(networks = ["discover", "jcb", "unionpay"])))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn credit_card_networks_valid() {
+    util::check_ok(
+        &[
+            Networks {
+                field: "4539571147647251",
+                amex: Some("343380440754432"),
+                inner: &["6011111111111117", "3530111333300000"],
+            },
+            Networks {
+                field: "5555555555554444",
+                amex: None,
+                inner: &[],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn credit_card_networks_invalid() {
+    util::check_fail!(
+        &[
+            Networks {
+                field: "343380440754432",
+                amex: Some("4539571147647251"),
+                inner: &["5555555555554444", "6011111111111118"],
+            },
+            Networks {
+                field: "6011111111111117",
+                amex: Some("5555555555554444"),
+                inner: &["4539571147647251"],
+            },
+        ],
+        &()
+    )
+}
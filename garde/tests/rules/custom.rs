@@ -108,3 +108,76 @@ fn multi_custom_invalid() {
         &ctx
     )
 }
+
+struct Pool {
+    reserved: Vec<&'static str>,
+}
+
+struct BorrowedContext<'a> {
+    pool: &'a Pool,
+}
+
+fn not_reserved(value: &str, ctx: &BorrowedContext<'_>) -> garde::Result {
+    if ctx.pool.reserved.contains(&value) {
+        return Err(garde::Error::new("is reserved"));
+    }
+    Ok(())
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(BorrowedContext<'a>))]
+struct Borrowed<'a> {
+    #[garde(custom(not_reserved))]
+    field: &'a str,
+}
+
+#[test]
+fn borrowed_context_valid() {
+    let pool = Pool {
+        reserved: vec!["admin"],
+    };
+    util::check_ok(
+        &[Borrowed { field: "user" }],
+        &BorrowedContext { pool: &pool },
+    )
+}
+
+#[test]
+fn borrowed_context_invalid() {
+    let pool = Pool {
+        reserved: vec!["admin"],
+    };
+    util::check_fail!(
+        &[Borrowed { field: "admin" }],
+        &BorrowedContext { pool: &pool }
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(BorrowedContext<'_>))]
+struct ElidedBorrowed<'a> {
+    #[garde(custom(not_reserved))]
+    field: &'a str,
+}
+
+#[test]
+fn elided_context_lifetime_valid() {
+    let pool = Pool {
+        reserved: vec!["admin"],
+    };
+    util::check_ok(
+        &[ElidedBorrowed { field: "user" }],
+        &BorrowedContext { pool: &pool },
+    )
+}
+
+#[test]
+fn elided_context_lifetime_invalid() {
+    let pool = Pool {
+        reserved: vec!["admin"],
+    };
+    util::check_fail!(
+        &[ElidedBorrowed { field: "admin" }],
+        &BorrowedContext { pool: &pool }
+    )
+}
@@ -0,0 +1,61 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(date)]
+    field: &'a str,
+    #[garde(date(format = "%d.%m.%Y", after = "01.01.2000", before = "01.01.2100"))]
+    bounded: String,
+    #[garde(date)]
+    optional: Option<String>,
+    #[garde(inner(date(format = "%Y/%m/%d")))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn date_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: "2024-02-29",
+                bounded: "02.01.2000".into(),
+                optional: None,
+                inner: &["2024/01/01", "1999/12/31"],
+            },
+            Test {
+                field: "0001-01-01",
+                bounded: "31.12.2099".into(),
+                optional: Some("2000-01-01".into()),
+                inner: &[],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn date_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                field: "2023-02-29",
+                bounded: "01.01.2000".into(),
+                optional: Some("".into()),
+                inner: &["2024-01-01"],
+            },
+            Test {
+                field: "2024-13-01",
+                bounded: "01.01.2100".into(),
+                optional: Some("2024-01-01T00:00:00".into()),
+                inner: &["2024/01/01", "2024/1/32"],
+            },
+            Test {
+                field: "yesterday",
+                bounded: "2024-01-01".into(),
+                optional: None,
+                inner: &[],
+            },
+        ],
+        &()
+    )
+}
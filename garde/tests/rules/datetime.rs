@@ -0,0 +1,57 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(datetime)]
+    field: &'a str,
+    #[garde(datetime(format = "%Y-%m-%dT%H:%M:%SZ", after = "2000-01-01T00:00:00Z"))]
+    utc: String,
+    #[garde(datetime(before = "2000-01-01T00:00:00+00:00"))]
+    offset: Option<String>,
+    #[garde(inner(datetime(format = "%Y-%m-%d %H:%M")))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn datetime_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: "2024-01-01T12:00:00Z",
+                utc: "2000-01-01T00:00:01Z".into(),
+                offset: None,
+                inner: &["2024-01-01 12:00"],
+            },
+            Test {
+                field: "2024-01-01T12:00:00.123+05:30",
+                utc: "2024-06-30T23:59:59Z".into(),
+                // 1999-12-31T23:00:00 in UTC
+                offset: Some("2000-01-01T01:00:00+02:00".into()),
+                inner: &[],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn datetime_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                field: "2024-01-01 12:00:00",
+                utc: "2000-01-01T00:00:00Z".into(),
+                // 2000-01-01T00:00:00 in UTC
+                offset: Some("1999-12-31T19:00:00-05:00".into()),
+                inner: &["2024-01-01T12:00"],
+            },
+            Test {
+                field: "2024-01-01T12:00:00",
+                utc: "2024-01-01T12:00:00+01:00".into(),
+                offset: Some("2024-01-01T00:00:00Z".into()),
+                inner: &["2024-01-01 12:00", "2024-01-01 25:00"],
+            },
+        ],
+        &()
+    )
+}
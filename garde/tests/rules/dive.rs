@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::rc::Rc;
 use std::sync::Arc;
 
@@ -68,3 +69,198 @@ fn email_invalid() {
         &()
     )
 }
+
+#[derive(Debug, garde::Validate)]
+struct CowTest<'a> {
+    #[garde(dive)]
+    cow: Cow<'a, Inner<'a>>,
+    #[garde(dive)]
+    cow_slice: Cow<'a, [Inner<'a>]>,
+}
+
+#[test]
+fn cow_valid() {
+    let inner = Inner { field: "asdf" };
+    util::check_ok(
+        &[CowTest {
+            cow: Cow::Borrowed(&inner),
+            cow_slice: Cow::Owned(vec![inner]),
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn cow_invalid() {
+    let inner = Inner { field: "" };
+    util::check_fail!(
+        &[CowTest {
+            cow: Cow::Owned(inner),
+            cow_slice: Cow::Borrowed(&[inner]),
+        }],
+        &()
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+struct ArrayTest<'a> {
+    #[garde(dive, length(equal = 3))]
+    array: [Inner<'a>; 3],
+}
+
+#[test]
+fn array_valid() {
+    let inner = Inner { field: "asdf" };
+    util::check_ok(
+        &[ArrayTest {
+            array: [inner, inner, inner],
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn array_invalid() {
+    let valid = Inner { field: "asdf" };
+    let invalid = Inner { field: "" };
+    util::check_fail!(
+        &[ArrayTest {
+            array: [valid, valid, invalid],
+        }],
+        &()
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+struct Repository<T, M> {
+    #[garde(dive)]
+    items: Vec<T>,
+    #[garde(dive)]
+    default: Option<T>,
+    #[garde(skip)]
+    #[allow(dead_code)]
+    meta: M,
+}
+
+#[test]
+fn generic_valid() {
+    let inner = Inner { field: "asdf" };
+    util::check_ok(
+        &[Repository {
+            items: vec![inner, inner],
+            default: None,
+            meta: (),
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn generic_invalid() {
+    let valid = Inner { field: "asdf" };
+    let invalid = Inner { field: "" };
+    util::check_fail!(
+        &[Repository {
+            items: vec![valid, invalid],
+            default: Some(invalid),
+            meta: (),
+        }],
+        &()
+    )
+}
+
+#[derive(Debug)]
+struct NoValidate;
+
+#[derive(Debug)]
+struct Tagged<T> {
+    name: &'static str,
+    tag: std::marker::PhantomData<T>,
+}
+
+impl<T> garde::Validate for Tagged<T> {
+    type Context = ();
+
+    fn validate_into(
+        &self,
+        _: &Self::Context,
+        parent: &mut dyn FnMut() -> garde::Path,
+        report: &mut garde::Report,
+    ) {
+        if self.name.is_empty() {
+            report.append(parent(), garde::Error::new("empty name"));
+        }
+    }
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(bound = "")]
+struct CustomBound<T> {
+    #[garde(dive)]
+    tagged: Tagged<T>,
+}
+
+#[test]
+fn custom_bound_valid() {
+    util::check_ok(
+        &[CustomBound::<NoValidate> {
+            tagged: Tagged {
+                name: "asdf",
+                tag: std::marker::PhantomData,
+            },
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn custom_bound_invalid() {
+    util::check_fail!(
+        &[CustomBound::<NoValidate> {
+            tagged: Tagged {
+                name: "",
+                tag: std::marker::PhantomData,
+            },
+        }],
+        &()
+    )
+}
+
+#[derive(Debug)]
+struct Limits {
+    max: usize,
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(Limits as ctx))]
+struct Limited<'a> {
+    #[garde(length(max = ctx.max))]
+    field: &'a str,
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(Limits))]
+struct GenericContext<T> {
+    #[garde(dive)]
+    item: T,
+}
+
+#[test]
+fn generic_context_valid() {
+    util::check_ok(
+        &[GenericContext {
+            item: Limited { field: "asdf" },
+        }],
+        &Limits { max: 4 },
+    )
+}
+
+#[test]
+fn generic_context_invalid() {
+    util::check_fail!(
+        &[GenericContext {
+            item: Limited { field: "asdf" },
+        }],
+        &Limits { max: 3 }
+    )
+}
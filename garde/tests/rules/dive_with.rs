@@ -0,0 +1,61 @@
+use super::util;
+
+struct UserContext {
+    payment: PaymentContext,
+}
+
+struct PaymentContext {
+    max_amount: u32,
+}
+
+fn payment_context(ctx: &UserContext) -> &PaymentContext {
+    &ctx.payment
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(PaymentContext as ctx))]
+struct Payment {
+    #[garde(range(max = ctx.max_amount))]
+    amount: u32,
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(UserContext))]
+struct Test {
+    #[garde(dive_with(payment_context))]
+    payment: Payment,
+    #[garde(dive_with(payment_context))]
+    payments: Vec<Payment>,
+    #[garde(dive_with(|_: &UserContext| PaymentContext { max_amount: 10 }))]
+    small: Payment,
+}
+
+fn ctx() -> UserContext {
+    UserContext {
+        payment: PaymentContext { max_amount: 100 },
+    }
+}
+
+#[test]
+fn dive_with_valid() {
+    util::check_ok(
+        &[Test {
+            payment: Payment { amount: 100 },
+            payments: vec![Payment { amount: 0 }, Payment { amount: 50 }],
+            small: Payment { amount: 10 },
+        }],
+        &ctx(),
+    )
+}
+
+#[test]
+fn dive_with_invalid() {
+    util::check_fail!(
+        &[Test {
+            payment: Payment { amount: 101 },
+            payments: vec![Payment { amount: 0 }, Payment { amount: 200 }],
+            small: Payment { amount: 11 },
+        }],
+        &ctx()
+    )
+}
@@ -0,0 +1,52 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(domain)]
+    field: &'a str,
+    #[garde(domain(require_tld))]
+    require_tld: &'a str,
+    #[garde(inner(domain))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn domain_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: "localhost",
+                require_tld: "example.com",
+                inner: &["sub.example.co.uk", "xn--bcher-kva.example"],
+            },
+            Test {
+                field: "example.com.",
+                require_tld: "a-b.c0",
+                inner: &["1.2", "bücher.example"],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn domain_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                field: "",
+                require_tld: "localhost",
+                inner: &["-example.com"],
+            },
+            Test {
+                field: "example..com",
+                require_tld: "under_score.com",
+                inner: &[
+                    "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa.com",
+                    "example-.com",
+                ],
+            },
+        ],
+        &()
+    )
+}
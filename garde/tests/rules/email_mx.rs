@@ -0,0 +1,33 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(email(check_mx))]
+    field: &'a str,
+    #[garde(email(check_mx))]
+    optional: Option<&'a str>,
+}
+
+// These cases don't reach the resolver, so they don't depend on the network.
+
+#[test]
+fn email_mx_skips_address_literals() {
+    util::check_ok_async(
+        &[Test {
+            field: "email@[127.0.0.1]",
+            optional: Some("email@[2001:dB8::1]"),
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn email_mx_skips_invalid_emails() {
+    util::check_fail_async!(
+        &[Test {
+            field: "invalid.com",
+            optional: Some("invalid.com"),
+        }],
+        &()
+    )
+}
@@ -0,0 +1,55 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(finite)]
+    field: f64,
+    #[garde(finite, range(min = 0.0, max = 100.0))]
+    bounded: f32,
+    #[garde(finite)]
+    optional: Option<f64>,
+    #[garde(inner(finite))]
+    inner: &'a [f32],
+}
+
+#[test]
+fn finite_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: 0.0,
+                bounded: 100.0,
+                optional: None,
+                inner: &[f32::MIN, f32::MAX],
+            },
+            Test {
+                field: -1e300,
+                bounded: 0.5,
+                optional: Some(f64::EPSILON),
+                inner: &[],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn finite_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                field: f64::NAN,
+                bounded: f32::INFINITY,
+                optional: Some(f64::NEG_INFINITY),
+                inner: &[f32::NAN],
+            },
+            Test {
+                field: f64::INFINITY,
+                bounded: f32::NAN,
+                optional: Some(f64::NAN),
+                inner: &[1.0, f32::NEG_INFINITY],
+            },
+        ],
+        &()
+    )
+}
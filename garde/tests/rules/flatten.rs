@@ -0,0 +1,75 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Address<'a> {
+    #[garde(length(min = 1))]
+    street: &'a str,
+    #[garde(length(min = 1))]
+    city: &'a str,
+}
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(length(min = 1))]
+    name: &'a str,
+    #[garde(dive, flatten)]
+    address: Address<'a>,
+    #[garde(dive)]
+    billing: Address<'a>,
+}
+
+#[derive(Debug, garde::Validate)]
+struct Tuple<'a>(
+    #[garde(length(min = 1))] &'a str,
+    #[garde(dive, flatten)] Address<'a>,
+);
+
+#[test]
+fn flatten_valid() {
+    util::check_ok(
+        &[Test {
+            name: "a",
+            address: Address {
+                street: "a",
+                city: "a",
+            },
+            billing: Address {
+                street: "a",
+                city: "a",
+            },
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn flatten_invalid() {
+    util::check_fail!(
+        &[Test {
+            name: "",
+            address: Address {
+                street: "",
+                city: "",
+            },
+            billing: Address {
+                street: "",
+                city: "",
+            },
+        }],
+        &()
+    )
+}
+
+#[test]
+fn flatten_tuple_invalid() {
+    util::check_fail!(
+        &[Tuple(
+            "",
+            Address {
+                street: "",
+                city: ""
+            }
+        )],
+        &()
+    )
+}
@@ -0,0 +1,61 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(latitude)]
+    lat: f64,
+    #[garde(longitude)]
+    lon: f32,
+    #[garde(coordinate_pair)]
+    position: (f64, f64),
+    #[garde(latitude)]
+    optional: Option<f64>,
+    #[garde(inner(longitude))]
+    inner: &'a [f64],
+}
+
+#[test]
+fn geolocation_valid() {
+    util::check_ok(
+        &[
+            Test {
+                lat: 0.0,
+                lon: 0.0,
+                position: (52.52, 13.405),
+                optional: None,
+                inner: &[],
+            },
+            Test {
+                lat: -90.0,
+                lon: 180.0,
+                position: (90.0, -180.0),
+                optional: Some(90.0),
+                inner: &[-180.0, 180.0],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn geolocation_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                lat: 90.1,
+                lon: -180.1,
+                position: (91.0, 0.0),
+                optional: Some(-91.0),
+                inner: &[181.0],
+            },
+            Test {
+                lat: f64::NAN,
+                lon: f32::INFINITY,
+                position: (0.0, 181.0),
+                optional: Some(f64::NEG_INFINITY),
+                inner: &[f64::NAN],
+            },
+        ],
+        &()
+    )
+}
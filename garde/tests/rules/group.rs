@@ -0,0 +1,77 @@
+use garde::Validate;
+
+#[derive(Debug, garde::Validate)]
+struct Form<'a> {
+    #[garde(group("account"), length(min = 3))]
+    username: &'a str,
+    #[garde(group("account", "security"), length(min = 8))]
+    password: &'a str,
+    #[garde(group("profile"), ascii)]
+    bio: &'a str,
+    #[garde(length(min = 1))]
+    session: &'a str,
+}
+
+#[derive(Debug, garde::Validate)]
+enum Step<'a> {
+    Account {
+        #[garde(group("account"), length(min = 3))]
+        username: &'a str,
+    },
+}
+
+fn paths(report: garde::Report) -> Vec<String> {
+    report.iter().map(|(path, _)| path.to_string()).collect()
+}
+
+const INVALID: Form<'static> = Form {
+    username: "a",
+    password: "a",
+    bio: "😂",
+    session: "",
+};
+
+#[test]
+fn group_validate_all() {
+    let report = INVALID.validate().unwrap_err();
+    assert_eq!(paths(report), ["bio", "password", "session", "username"]);
+}
+
+#[test]
+fn group_validate_one() {
+    let report = INVALID.validate_group("account", &()).unwrap_err();
+    assert_eq!(paths(report), ["password", "session", "username"]);
+
+    let report = INVALID.validate_group("security", &()).unwrap_err();
+    assert_eq!(paths(report), ["password", "session"]);
+
+    let report = INVALID.validate_group("profile", &()).unwrap_err();
+    assert_eq!(paths(report), ["bio", "session"]);
+
+    let report = INVALID.validate_group("unknown", &()).unwrap_err();
+    assert_eq!(paths(report), ["session"]);
+}
+
+#[test]
+fn group_valid() {
+    let form = Form {
+        username: "abc",
+        bio: "😂",
+        ..INVALID
+    };
+    assert!(form.validate_group("account", &()).is_err());
+    let form = Form {
+        password: "abcdefgh",
+        session: "a",
+        ..form
+    };
+    assert!(form.validate_group("account", &()).is_ok());
+    assert!(form.validate_group("profile", &()).is_err());
+}
+
+#[test]
+fn group_enum() {
+    let step = Step::Account { username: "a" };
+    assert!(step.validate_group("account", &()).is_err());
+    assert!(step.validate_group("profile", &()).is_ok());
+}
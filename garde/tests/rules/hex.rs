@@ -0,0 +1,50 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(hex)]
+    field: &'a str,
+
+    #[garde(hex, length(equal = 64))]
+    sha256: &'a str,
+
+    #[garde(inner(hex))]
+    inner: &'a [&'a str],
+
+    #[garde(hex, length(min = 1))]
+    non_empty: &'a str,
+}
+
+#[test]
+fn hex_valid() {
+    util::check_ok(
+        &[Test {
+            field: "0123456789abcdefABCDEF",
+            sha256: "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+            inner: &["deadBEEF", ""],
+            non_empty: "0",
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn hex_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                field: "0x1234",
+                sha256: "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b85",
+                inner: &["g"],
+                non_empty: "",
+            },
+            Test {
+                field: "12 34",
+                sha256: "z3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+                inner: &["１２"],
+                non_empty: "x",
+            },
+        ],
+        &()
+    )
+}
@@ -0,0 +1,44 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(iban)]
+    field: &'a str,
+
+    #[garde(iban(country = "DE"))]
+    german: &'a str,
+
+    #[garde(inner(iban))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn iban_valid() {
+    util::check_ok(
+        &[Test {
+            field: "GB82WEST12345698765432",
+            german: "DE44500105175407324931",
+            inner: &["NL91ABNA0417164300", "FR1420041010050500013M02606"],
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn iban_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                field: "GB82WEST12345698765433",
+                german: "GB82WEST12345698765432",
+                inner: &["not an iban"],
+            },
+            Test {
+                field: "XX82WEST12345698765432",
+                german: "DE44500105175407324932",
+                inner: &["NL91ABNA041716430"],
+            },
+        ],
+        &()
+    )
+}
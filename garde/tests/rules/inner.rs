@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use super::util;
 
 #[derive(Debug, garde::Validate)]
@@ -119,3 +121,65 @@ fn alphanumeric_none_valid() {
         &(),
     )
 }
+
+#[derive(Debug, garde::Validate)]
+struct Map<'a> {
+    #[garde(inner(key(alphanumeric, length(max = 4)), value(inner(length(min = 1)))))]
+    inner: BTreeMap<&'a str, Vec<&'a str>>,
+    #[garde(inner(value(ascii)))]
+    values: &'a BTreeMap<u32, &'a str>,
+}
+
+#[test]
+fn map_valid() {
+    util::check_ok(
+        &[Map {
+            inner: BTreeMap::from([("abcd", vec!["a"]), ("0123", vec![])]),
+            values: &BTreeMap::from([(0, "a")]),
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn map_invalid() {
+    util::check_fail!(
+        &[Map {
+            inner: BTreeMap::from([("a b", vec!["a"]), ("abcde", vec!["a", ""])]),
+            values: &BTreeMap::from([(0, "😂")]),
+        }],
+        &()
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+struct Matrix {
+    #[garde(
+        length(min = 1),
+        inner(length(equal = 2), inner(ascii, length(min = 1)))
+    )]
+    matrix: Vec<Vec<String>>,
+}
+
+#[test]
+fn matrix_valid() {
+    util::check_ok(
+        &[Matrix {
+            matrix: vec![vec!["a".into(), "b".into()], vec!["c".into(), "d".into()]],
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn matrix_invalid() {
+    util::check_fail!(
+        &[
+            Matrix { matrix: vec![] },
+            Matrix {
+                matrix: vec![vec!["a".into(), "😂".into()], vec!["".into()]],
+            },
+        ],
+        &()
+    )
+}
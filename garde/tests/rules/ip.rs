@@ -1,3 +1,5 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
 use super::util;
 
 #[derive(Debug, garde::Validate)]
@@ -229,3 +231,127 @@ fn ip_v6_invalid() {
         &()
     )
 }
+
+#[derive(Debug, garde::Validate)]
+struct TestIpInSubnet<'a> {
+    #[garde(ip_in_subnet("192.168.0.0/24"))]
+    field: &'a str,
+    #[garde(ip_in_subnet("fd00::/8"))]
+    v6: &'a str,
+    #[garde(inner(ip_in_subnet("10.0.0.0/8")))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn ip_in_subnet_valid() {
+    util::check_ok(
+        &[
+            TestIpInSubnet {
+                field: "192.168.0.0",
+                v6: "fd00::1",
+                inner: &["10.0.0.1", "10.255.255.255"],
+            },
+            TestIpInSubnet {
+                field: "192.168.0.255",
+                v6: "fdff:ffff:ffff:ffff:ffff:ffff:ffff:ffff",
+                inner: &[],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn ip_in_subnet_invalid() {
+    util::check_fail!(
+        &[
+            TestIpInSubnet {
+                field: "192.168.1.0",
+                v6: "fe80::1",
+                inner: &["11.0.0.0"],
+            },
+            TestIpInSubnet {
+                field: "not an ip",
+                v6: "192.168.0.1",
+                inner: &["::ffff:10.0.0.1"],
+            },
+        ],
+        &()
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+struct TestIpAddr {
+    #[garde(ip)]
+    any: IpAddr,
+    #[garde(ipv4)]
+    v4: IpAddr,
+    #[garde(ipv6)]
+    v6: IpAddr,
+    #[garde(ipv4)]
+    parsed_v4: Ipv4Addr,
+    #[garde(ipv6)]
+    parsed_v6: Option<Ipv6Addr>,
+}
+
+#[test]
+fn ip_addr_valid() {
+    util::check_ok(
+        &[TestIpAddr {
+            any: IpAddr::V6(Ipv6Addr::LOCALHOST),
+            v4: IpAddr::V4(Ipv4Addr::LOCALHOST),
+            v6: IpAddr::V6(Ipv6Addr::LOCALHOST),
+            parsed_v4: Ipv4Addr::LOCALHOST,
+            parsed_v6: None,
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn ip_addr_invalid() {
+    util::check_fail!(
+        &[TestIpAddr {
+            any: IpAddr::V4(Ipv4Addr::LOCALHOST),
+            v4: IpAddr::V6(Ipv6Addr::LOCALHOST),
+            v6: IpAddr::V4(Ipv4Addr::LOCALHOST),
+            parsed_v4: Ipv4Addr::LOCALHOST,
+            parsed_v6: Some(Ipv6Addr::LOCALHOST),
+        }],
+        &()
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+struct TestIpAddrInSubnet {
+    #[garde(ip_in_subnet("192.168.0.0/24"))]
+    field: IpAddr,
+    #[garde(ip_in_subnet("fd00::/8"))]
+    v6: Ipv6Addr,
+    #[garde(inner(ip_in_subnet("10.0.0.0/8")))]
+    inner: Vec<Ipv4Addr>,
+}
+
+#[test]
+fn ip_addr_in_subnet_valid() {
+    util::check_ok(
+        &[TestIpAddrInSubnet {
+            field: IpAddr::V4(Ipv4Addr::new(192, 168, 0, 1)),
+            v6: Ipv6Addr::new(0xfd00, 0, 0, 0, 0, 0, 0, 1),
+            inner: vec![Ipv4Addr::new(10, 0, 0, 1)],
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn ip_addr_in_subnet_invalid() {
+    util::check_fail!(
+        &[TestIpAddrInSubnet {
+            field: IpAddr::V6(Ipv6Addr::LOCALHOST),
+            v6: Ipv6Addr::LOCALHOST,
+            inner: vec![Ipv4Addr::new(11, 0, 0, 1)],
+        }],
+        &()
+    )
+}
@@ -0,0 +1,72 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(isbn)]
+    field: &'a str,
+
+    #[garde(isbn(format = "isbn10"))]
+    isbn10: &'a str,
+
+    #[garde(isbn(format = "isbn13"))]
+    isbn13: &'a str,
+
+    #[garde(isbn(version = 13))]
+    version13: &'a str,
+
+    #[garde(inner(isbn))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn isbn_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: "978-3-16-148410-0",
+                isbn10: "0-306-40615-2",
+                isbn13: "9783161484100",
+                version13: "979-10-90636-07-1",
+                inner: &["080442957X", "978-0-306-40615-7"],
+            },
+            Test {
+                field: "0306406152",
+                isbn10: "080442957x",
+                isbn13: "978-0-306-40615-7",
+                version13: "9791090636071",
+                inner: &[],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn isbn_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                field: "978-3-16-148410-1",
+                isbn10: "9783161484100",
+                isbn13: "0306406152",
+                version13: "0-306-40615-2",
+                inner: &["not an isbn"],
+            },
+            Test {
+                field: "-9783161484100",
+                isbn10: "0-306-40615-3",
+                isbn13: "978316148410X",
+                version13: "1234567890128",
+                inner: &["X804429570"],
+            },
+            Test {
+                field: "97-83161484100",
+                isbn10: "0-30640615-2",
+                isbn13: "978-316-148410-0",
+                version13: "97830-6-40615-7",
+                inner: &["978-0-306-4061-57", "0-306-406152"],
+            },
+        ],
+        &()
+    )
+}
@@ -0,0 +1,165 @@
+#![allow(dead_code)]
+
+use garde::schema::{json_schema, JsonSchema};
+use serde_json::json;
+
+#[derive(garde::Validate, garde::JsonSchema)]
+#[garde(context(usize as ctx))]
+struct User<'a> {
+    #[garde(length(min = 3, max = 25))]
+    username: &'a str,
+    #[garde(email)]
+    email: String,
+    #[garde(range(min = 0, max = 100))]
+    score: u8,
+    #[garde(range(exclusive_min = 0.0, exclusive_max = 1.0))]
+    ratio: f64,
+    #[garde(length(min = 1), inner(length(max = 10), pattern(r"^[a-z]+$")))]
+    tags: Vec<String>,
+    #[garde(url)]
+    website: Option<String>,
+    #[garde(required, length(chars, max = *ctx))]
+    bio: Option<String>,
+    #[garde(rename("isAdmin"), skip)]
+    is_admin: bool,
+    #[garde(dive)]
+    address: Address,
+}
+
+#[derive(garde::Validate, garde::JsonSchema)]
+#[garde(context(usize))]
+struct Address {
+    #[garde(positive, multiple_of(10))]
+    number: i32,
+    #[garde(one_of(["home", "work"]))]
+    kind: String,
+}
+
+#[test]
+fn json_schema_struct() {
+    assert_eq!(
+        json_schema::<User<'static>>(),
+        json!({
+            "$schema": "http://json-schema.org/draft-07/schema#",
+            "type": "object",
+            "properties": {
+                "username": { "type": "string", "minLength": 3, "maxLength": 25 },
+                "email": { "type": "string", "format": "email" },
+                "score": { "type": "integer", "minimum": 0, "maximum": 100 },
+                "ratio": { "type": "number", "exclusiveMinimum": 0.0, "exclusiveMaximum": 1.0 },
+                "tags": {
+                    "type": "array",
+                    "minItems": 1,
+                    "items": { "type": "string", "maxLength": 10, "pattern": "^[a-z]+$" },
+                },
+                "website": { "type": ["string", "null"], "format": "uri" },
+                "bio": { "type": ["string", "null"] },
+                "isAdmin": {},
+                "address": {
+                    "type": "object",
+                    "properties": {
+                        "number": { "type": "integer", "exclusiveMinimum": 0, "multipleOf": 10 },
+                        "kind": { "type": "string", "enum": ["home", "work"] },
+                    },
+                    "required": ["kind", "number"],
+                },
+            },
+            "required": ["address", "bio", "email", "isAdmin", "ratio", "score", "tags", "username"],
+        })
+    );
+}
+
+#[derive(garde::Validate, garde::JsonSchema)]
+#[garde(transparent)]
+struct Username(#[garde(length(min = 3))] String);
+
+#[derive(garde::Validate, garde::JsonSchema)]
+struct Point(
+    #[garde(range(min = -10))] i32,
+    #[garde(range(max = 10))] i32,
+);
+
+#[test]
+fn json_schema_tuple() {
+    assert_eq!(
+        Username::json_schema(),
+        json!({ "type": "string", "minLength": 3 })
+    );
+    assert_eq!(
+        Point::json_schema(),
+        json!({
+            "type": "array",
+            "items": [
+                { "type": "integer", "minimum": -10 },
+                { "type": "integer", "maximum": 10 },
+            ],
+            "minItems": 2,
+            "maxItems": 2,
+        })
+    );
+}
+
+#[derive(garde::Validate, garde::JsonSchema)]
+enum Shape {
+    Empty,
+    Circle(#[garde(range(min = 0.0))] f64),
+    Rect {
+        #[garde(range(min = 0.0))]
+        w: f64,
+        #[garde(range(min = 0.0))]
+        h: f64,
+    },
+}
+
+#[test]
+fn json_schema_enum() {
+    assert_eq!(
+        Shape::json_schema(),
+        json!({
+            "oneOf": [
+                { "const": "Empty" },
+                {
+                    "type": "object",
+                    "properties": { "Circle": { "type": "number", "minimum": 0.0 } },
+                    "required": ["Circle"],
+                    "additionalProperties": false,
+                },
+                {
+                    "type": "object",
+                    "properties": {
+                        "Rect": {
+                            "type": "object",
+                            "properties": {
+                                "h": { "type": "number", "minimum": 0.0 },
+                                "w": { "type": "number", "minimum": 0.0 },
+                            },
+                            "required": ["h", "w"],
+                        },
+                    },
+                    "required": ["Rect"],
+                    "additionalProperties": false,
+                },
+            ],
+        })
+    );
+}
+
+#[derive(garde::Validate, garde::JsonSchema)]
+struct Generic<T> {
+    #[garde(length(max = 3))]
+    items: Vec<T>,
+}
+
+#[test]
+fn json_schema_generic() {
+    assert_eq!(
+        Generic::<bool>::json_schema(),
+        json!({
+            "type": "object",
+            "properties": {
+                "items": { "type": "array", "items": { "type": "boolean" }, "maxItems": 3 },
+            },
+            "required": ["items"],
+        })
+    );
+}
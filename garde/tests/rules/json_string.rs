@@ -0,0 +1,58 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(json_string)]
+    field: &'a str,
+
+    #[garde(json_string(schema_type = "object"))]
+    object: &'a str,
+
+    #[garde(json_string(schema_type = "array"))]
+    array: Option<&'a str>,
+
+    #[garde(inner(json_string))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn json_string_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: r#"{"a": [1, 2.5, "b", null, true]}"#,
+                object: "{}",
+                array: Some("[]"),
+                inner: &["1", r#""string""#, "null"],
+            },
+            Test {
+                field: " false ",
+                object: r#"{"nested": {"a": 1}}"#,
+                array: None,
+                inner: &[],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn json_string_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                field: "",
+                object: "[1]",
+                array: Some("{}"),
+                inner: &["{", "'a'"],
+            },
+            Test {
+                field: r#"{"a": 1,}"#,
+                object: "null",
+                array: Some("[1, 2"),
+                inner: &["undefined"],
+            },
+        ],
+        &()
+    )
+}
@@ -0,0 +1,70 @@
+use garde::Validate;
+
+use super::util;
+
+fn not_equal_to_password(value: &str, _: &()) -> garde::Result {
+    match value == "password" {
+        true => Err(garde::Error::new("must not be `password`")),
+        false => Ok(()),
+    }
+}
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(
+        length(min = 8),
+        custom(not_equal_to_password),
+        label("Password Confirmation")
+    )]
+    password_confirmation: &'a str,
+    #[garde(inner(ascii), label("Tags"))]
+    tags: &'a [&'a str],
+    #[garde(length(min = 1))]
+    name: &'a str,
+}
+
+#[test]
+fn label_valid() {
+    util::check_ok(
+        &[Test {
+            password_confirmation: "hunter22",
+            tags: &["a"],
+            name: "a",
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn label_invalid() {
+    util::check_fail!(
+        &[Test {
+            password_confirmation: "a",
+            tags: &["😂"],
+            name: "",
+        }],
+        &()
+    )
+}
+
+#[test]
+fn label_error() {
+    let value = Test {
+        password_confirmation: "password",
+        tags: &[],
+        name: "a",
+    };
+    let report = value.validate().unwrap_err();
+    let labels = report
+        .iter()
+        .map(|(path, error)| (path.to_string(), error.label(), error.message()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        labels,
+        [(
+            "password_confirmation".to_string(),
+            Some("Password Confirmation"),
+            "must not be `password`"
+        )]
+    );
+}
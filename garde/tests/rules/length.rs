@@ -89,6 +89,8 @@ struct MinMaxEqual<'a> {
     min_max: &'a str,
     #[garde(length(equal = 2))]
     equal: &'a str,
+    #[garde(length(exact = 2))]
+    exact: &'a str,
 }
 
 #[test]
@@ -98,6 +100,7 @@ fn min_max_equal_length_valid() {
             // 'b' * 2
             min_max: "bb",
             equal: "bb",
+            exact: "bb",
         }],
         &(),
     )
@@ -109,17 +112,20 @@ fn min_max_equal_length_invalid() {
         &[
             MinMaxEqual {
                 min_max: "",
-                equal: ""
+                equal: "",
+                exact: ""
             },
             MinMaxEqual {
                 // 'b' * 1
                 min_max: "b",
-                equal: "b"
+                equal: "b",
+                exact: "b"
             },
             MinMaxEqual {
                 // 'b' * 3
                 min_max: "bbb",
-                equal: "bbb"
+                equal: "bbb",
+                exact: "bbb"
             },
         ],
         &()
@@ -174,3 +180,177 @@ fn char_length_invalid() {
         &()
     )
 }
+
+#[derive(Debug, garde::Validate)]
+struct Collections {
+    #[garde(length(min = 1, max = 2))]
+    hash_map: std::collections::HashMap<u32, u32>,
+    #[garde(length(min = 1, max = 2))]
+    btree_map: std::collections::BTreeMap<u32, u32>,
+    #[garde(length(min = 1, max = 2))]
+    hash_set: std::collections::HashSet<u32>,
+    #[garde(length(min = 1, max = 2))]
+    btree_set: std::collections::BTreeSet<u32>,
+    #[garde(length(min = 1, max = 2))]
+    vec_deque: std::collections::VecDeque<u32>,
+}
+
+#[derive(Debug, garde::Validate)]
+struct CowSlice<'a> {
+    #[garde(length(min = 1, max = 2))]
+    field: std::borrow::Cow<'a, [u32]>,
+}
+
+#[test]
+fn cow_slice_length_valid() {
+    util::check_ok(
+        &[
+            CowSlice {
+                field: std::borrow::Cow::Borrowed(&[0]),
+            },
+            CowSlice {
+                field: std::borrow::Cow::Owned(vec![0, 1]),
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn cow_slice_length_invalid() {
+    util::check_fail!(
+        &[
+            CowSlice {
+                field: std::borrow::Cow::Borrowed(&[]),
+            },
+            CowSlice {
+                field: std::borrow::Cow::Owned(vec![0, 1, 2]),
+            },
+        ],
+        &()
+    )
+}
+
+#[test]
+fn collection_length_valid() {
+    util::check_ok(
+        &[Collections {
+            hash_map: [(0, 0)].into(),
+            btree_map: [(0, 0), (1, 1)].into(),
+            hash_set: [0].into(),
+            btree_set: [0, 1].into(),
+            vec_deque: [0].into(),
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn collection_length_invalid() {
+    util::check_fail!(
+        &[Collections {
+            hash_map: [].into(),
+            btree_map: [(0, 0), (1, 1), (2, 2)].into(),
+            hash_set: [].into(),
+            btree_set: [0, 1, 2].into(),
+            vec_deque: [].into(),
+        }],
+        &()
+    )
+}
+
+#[derive(Clone, Debug)]
+struct Items(std::vec::IntoIter<u32>);
+
+impl Iterator for Items {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl ExactSizeIterator for Items {}
+
+impl garde::rules::length::ExactSizeLength for Items {}
+
+#[derive(Debug, garde::Validate)]
+struct ExactSizeIter {
+    #[garde(length(min = 1, max = 2))]
+    field: Items,
+}
+
+#[test]
+fn exact_size_iter_length_valid() {
+    util::check_ok(
+        &[
+            ExactSizeIter {
+                field: Items(vec![0].into_iter()),
+            },
+            ExactSizeIter {
+                field: Items(vec![0, 1].into_iter()),
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn exact_size_iter_length_invalid() {
+    util::check_fail!(
+        &[
+            ExactSizeIter {
+                field: Items(vec![].into_iter()),
+            },
+            ExactSizeIter {
+                field: Items(vec![0, 1, 2].into_iter()),
+            },
+        ],
+        &()
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+struct External {
+    #[garde(length(min = 1, max = 2))]
+    compact: garde::external::compact_str::CompactString,
+    #[garde(length(chars, min = 1, max = 2))]
+    compact_chars: garde::external::compact_str::CompactString,
+    #[garde(length(min = 1, max = 2), inner(length(min = 1)))]
+    small_vec: garde::external::smallvec::SmallVec<[&'static str; 2]>,
+}
+
+#[test]
+fn external_length_valid() {
+    util::check_ok(
+        &[External {
+            compact: "ab".into(),
+            compact_chars: "éé".into(),
+            small_vec: garde::external::smallvec::smallvec!["a", "b"],
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn external_length_invalid() {
+    util::check_fail!(
+        &[
+            External {
+                compact: "".into(),
+                compact_chars: "".into(),
+                small_vec: garde::external::smallvec::smallvec![],
+            },
+            External {
+                compact: "éé".into(),
+                compact_chars: "ééé".into(),
+                small_vec: garde::external::smallvec::smallvec!["a", "", "c"],
+            },
+        ],
+        &()
+    )
+}
@@ -0,0 +1,51 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(luhn)]
+    field: &'a str,
+    #[garde(luhn)]
+    optional: Option<&'a str>,
+    #[garde(inner(luhn))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn luhn_valid() {
+    util::check_ok(
+        &[
+            Test {
+                // IMEI
+                field: "490154203237518",
+                optional: None,
+                inner: &["79927398713"],
+            },
+            Test {
+                // Canadian SIN
+                field: "046454286",
+                optional: Some("4111111111111111"),
+                inner: &["00", "18"],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn luhn_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                field: "490154203237517",
+                optional: Some("79927398710"),
+                inner: &["4111 1111 1111 1111"],
+            },
+            Test {
+                field: "0",
+                optional: Some(""),
+                inner: &["12a"],
+            },
+        ],
+        &()
+    )
+}
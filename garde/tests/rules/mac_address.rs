@@ -0,0 +1,58 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(mac_address)]
+    field: &'a str,
+
+    #[garde(mac_address(format = "colon"))]
+    colon: &'a str,
+
+    #[garde(mac_address(format = "hyphen"))]
+    hyphen: &'a str,
+
+    #[garde(inner(mac_address))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn mac_address_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: "00:1A:2B:3C:4D:5E",
+                colon: "00:1a:2b:3c:4d:5e",
+                hyphen: "00-1A-2B-3C-4D-5E",
+                inner: &["ff-ff-ff-ff-ff-ff"],
+            },
+            Test {
+                field: "00-1a-2b-3c-4d-5e",
+                colon: "FF:FF:FF:FF:FF:FF",
+                hyphen: "00-00-00-00-00-00",
+                inner: &["00:00:00:00:00:00"],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn mac_address_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                field: "00:1A:2B-3C:4D:5E",
+                colon: "00-1A-2B-3C-4D-5E",
+                hyphen: "00:1A:2B:3C:4D:5E",
+                inner: &["001A2B3C4D5E"],
+            },
+            Test {
+                field: "00:1A:2B:3C:4D",
+                colon: "00:1A:2B:3C:4D:5G",
+                hyphen: "0-1A-2B-3C-4D-5E-",
+                inner: &["00.1A.2B.3C.4D.5E"],
+            },
+        ],
+        &()
+    )
+}
@@ -0,0 +1,69 @@
+use super::util;
+
+fn custom_message(value: &str, _: &()) -> String {
+    format!("`{value}` is not allowed")
+}
+
+fn reserved(value: &str, _: &()) -> garde::Result {
+    match value {
+        "admin" => Err(garde::Error::new("is reserved")),
+        _ => Ok(()),
+    }
+}
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(ascii, message("must be ascii"))]
+    fixed: &'a str,
+    #[garde(
+        length(min = 2, max = 4),
+        message("length must be between {min} and {max}, got \"{value}\"")
+    )]
+    length: &'a str,
+    #[garde(range(min = 10), message("must be at least {min}: {error}"))]
+    range: u32,
+    #[garde(alphanumeric, message(custom_message))]
+    func: &'a str,
+    #[garde(length(equal = 3), message(|value: &str, _: &()| format!("{value:?} has {} characters", value.len())))]
+    closure: &'a str,
+    #[garde(ascii, length(min = 3), message("must be at least 3 ascii characters"))]
+    multiple: &'a str,
+    #[garde(custom(reserved), message("{value} is not available: {error}"))]
+    custom: &'a str,
+    #[garde(inner(length(min = 1)), message("must not contain empty tags"))]
+    inner: Vec<&'a str>,
+}
+
+#[test]
+fn message_valid() {
+    util::check_ok(
+        &[Test {
+            fixed: "abc",
+            length: "abc",
+            range: 10,
+            func: "abc",
+            closure: "abc",
+            multiple: "abc",
+            custom: "user",
+            inner: vec!["a", "b"],
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn message_invalid() {
+    util::check_fail!(
+        &[Test {
+            fixed: "💩",
+            length: "a",
+            range: 9,
+            func: "a b",
+            closure: "ab",
+            multiple: "ü",
+            custom: "admin",
+            inner: vec!["", "a", ""],
+        }],
+        &()
+    )
+}
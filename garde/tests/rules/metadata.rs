@@ -0,0 +1,51 @@
+use garde::Validate;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(length(min = 2, max = 4))]
+    length: &'a str,
+    #[garde(range(max = 10))]
+    range: u32,
+    #[garde(length(max = 2), message("{value} is too long"))]
+    message: &'a str,
+    #[garde(ascii)]
+    ascii: &'a str,
+}
+
+#[test]
+fn metadata_invalid() {
+    let value = Test {
+        length: "a",
+        range: 11,
+        message: "abc",
+        ascii: "💩",
+    };
+    let report = value.validate().unwrap_err();
+    let metadata = report
+        .iter()
+        .map(|(path, error)| {
+            let metadata = error
+                .metadata()
+                .iter()
+                .map(|(k, v)| format!("{k}={v}"))
+                .collect::<Vec<_>>()
+                .join(",");
+            (path.to_string(), metadata)
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        metadata,
+        [
+            ("ascii".to_string(), "".to_string()),
+            ("length".to_string(), "actual_length=1,min=2".to_string()),
+            ("message".to_string(), "actual_length=3,max=2".to_string()),
+            ("range".to_string(), "max=10".to_string()),
+        ]
+    );
+
+    let (_, error) = report
+        .iter()
+        .find(|(path, _)| path.to_string() == "message")
+        .unwrap();
+    assert_eq!(error.message(), "abc is too long");
+}
@@ -0,0 +1,61 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(mime_type)]
+    field: &'a str,
+    #[garde(mime_type(allowed = ["image/jpeg", "image/png"]))]
+    allowed: String,
+    #[garde(mime_type)]
+    optional: Option<String>,
+    #[garde(inner(mime_type))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn mime_type_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: "image/jpeg",
+                allowed: "image/png".into(),
+                optional: None,
+                inner: &["text/plain; charset=utf-8", "application/vnd.api+json"],
+            },
+            Test {
+                field: "multipart/form-data; boundary=\"a b;c\"",
+                allowed: "Image/JPEG; q=0.9".into(),
+                optional: Some("application/octet-stream".into()),
+                inner: &[],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn mime_type_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                field: "image",
+                allowed: "image/gif".into(),
+                optional: Some("".into()),
+                inner: &["/plain"],
+            },
+            Test {
+                field: "image/",
+                allowed: "text/plain".into(),
+                optional: Some("text/plain; charset".into()),
+                inner: &["text/plain", "text/plain; charset=\"utf-8"],
+            },
+            Test {
+                field: "image/jpeg png",
+                allowed: "image/jpeg/png".into(),
+                optional: Some("text/plain;; a=b".into()),
+                inner: &["-text/plain"],
+            },
+        ],
+        &()
+    )
+}
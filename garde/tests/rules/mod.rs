@@ -1,27 +1,87 @@
 mod adapt;
 mod allow_unvalidated;
+mod alphabetic;
 mod alphanumeric;
 mod ascii;
+mod async_custom;
+mod base64;
+mod byte_length;
+mod case;
+mod cidr;
+mod code;
 mod contains;
+mod contains_all;
+mod contains_any;
+mod context_fn;
+mod country_code;
 mod credit_card;
 mod custom;
+mod date;
+mod datetime;
 mod dive;
+mod dive_with;
 mod dive_with_rules;
+mod domain;
 mod email;
+#[cfg(not(target_arch = "wasm32"))]
+mod email_mx;
+mod finite;
+mod flatten;
+mod geolocation;
+mod group;
+mod hex;
+mod iban;
 mod inner;
 mod ip;
+mod isbn;
+mod json_schema;
+mod json_string;
+mod label;
 mod length;
+mod luhn;
+mod mac_address;
 mod matches;
+mod message;
+mod metadata;
+mod mime_type;
 mod multi_rule;
+mod multiple_of;
 mod newtype;
+mod non_default;
+mod normalize;
+mod not;
+mod not_common_password;
+mod not_contains;
+mod not_prefix;
+mod not_suffix;
+mod numeric_string;
+mod one_of;
 mod option;
+mod password;
 mod pattern;
 mod phone_number;
+mod port;
+mod precision;
 mod prefix;
 mod range;
+mod rename;
+mod rule;
 mod select;
+mod semver;
+mod sign;
 mod skip;
+mod skip_if;
+mod slug;
+mod sorted;
 mod suffix;
+mod time;
+mod timezone;
+mod unique;
+mod unique_by;
 mod url;
+mod uuid;
+mod warn;
+mod when;
+mod whitespace;
 
 mod util;
@@ -0,0 +1,61 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(range(min = 6), multiple_of(6))]
+    field: u32,
+    #[garde(multiple_of(-3))]
+    signed: i64,
+    #[garde(multiple_of(0.1))]
+    float_field: f64,
+    #[garde(multiple_of(2))]
+    optional: Option<u8>,
+    #[garde(inner(multiple_of(5)))]
+    inner: &'a [usize],
+}
+
+#[test]
+fn multiple_of_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: 6,
+                signed: 0,
+                float_field: 0.3,
+                optional: None,
+                inner: &[0, 5, 10],
+            },
+            Test {
+                field: 36,
+                signed: -9,
+                float_field: 12.5,
+                optional: Some(4),
+                inner: &[],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn multiple_of_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                field: 0,
+                signed: 1,
+                float_field: 0.35,
+                optional: Some(3),
+                inner: &[1],
+            },
+            Test {
+                field: 13,
+                signed: -10,
+                float_field: 1.01,
+                optional: Some(255),
+                inner: &[5, 12],
+            },
+        ],
+        &()
+    )
+}
@@ -63,3 +63,79 @@ fn newtype_fields_invalid() {
         &()
     );
 }
+
+struct Context {
+    max_len: usize,
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(transparent)]
+#[garde(context(Context as ctx))]
+struct Bounded<'a>(#[garde(length(min = 1, max = ctx.max_len))] &'a str);
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(Context))]
+struct WithContext<'a> {
+    #[garde(dive)]
+    field: Bounded<'a>,
+}
+
+#[test]
+fn newtype_context_valid() {
+    util::check_ok(
+        &[WithContext {
+            field: Bounded("test"),
+        }],
+        &Context { max_len: 4 },
+    );
+}
+
+#[test]
+fn newtype_context_invalid() {
+    util::check_fail!(
+        &[
+            WithContext { field: Bounded("") },
+            WithContext {
+                field: Bounded("tests"),
+            }
+        ],
+        &Context { max_len: 4 }
+    );
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(transparent)]
+struct Nested<'a>(#[garde(dive)] NonEmptyStr_Tuple<'a>);
+
+#[derive(Debug, garde::Validate)]
+struct WithNested<'a> {
+    #[garde(dive)]
+    field: Nested<'a>,
+    #[garde(dive)]
+    list: Vec<Nested<'a>>,
+}
+
+#[test]
+fn newtype_nested_valid() {
+    util::check_ok(
+        &[WithNested {
+            field: Nested(NonEmptyStr_Tuple("test")),
+            list: vec![Nested(NonEmptyStr_Tuple("test"))],
+        }],
+        &(),
+    );
+}
+
+#[test]
+fn newtype_nested_invalid() {
+    util::check_fail!(
+        &[WithNested {
+            field: Nested(NonEmptyStr_Tuple("")),
+            list: vec![
+                Nested(NonEmptyStr_Tuple("test")),
+                Nested(NonEmptyStr_Tuple("")),
+            ],
+        }],
+        &()
+    );
+}
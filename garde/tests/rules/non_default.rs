@@ -0,0 +1,64 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(non_default)]
+    id: u64,
+    #[garde(non_default)]
+    name: &'a str,
+    #[garde(non_default)]
+    items: Vec<u8>,
+    #[garde(non_default)]
+    optional: Option<i32>,
+    #[garde(inner(non_default))]
+    inner: Option<i32>,
+}
+
+#[test]
+fn non_default_valid() {
+    util::check_ok(
+        &[
+            Test {
+                id: 1,
+                name: "a",
+                items: vec![0],
+                optional: Some(0),
+                inner: None,
+            },
+            Test {
+                id: u64::MAX,
+                name: " ",
+                items: vec![1, 2, 3],
+                optional: Some(-1),
+                inner: Some(1),
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn non_default_invalid() {
+    util::check_fail!(
+        &[Test {
+            id: 0,
+            name: "",
+            items: vec![],
+            optional: None,
+            inner: Some(0),
+        }],
+        &()
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+struct Negated {
+    #[garde(not(non_default))]
+    v: u32,
+}
+
+#[test]
+fn non_default_negated() {
+    util::check_ok(&[Negated { v: 0 }], &());
+    util::check_fail!(&[Negated { v: 1 }], &());
+}
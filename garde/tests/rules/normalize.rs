@@ -0,0 +1,43 @@
+use super::util;
+
+fn collapse_whitespace(value: &str) -> String {
+    value.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(normalize(trim), length(min = 3))]
+    trim: &'a str,
+    #[garde(normalize(lowercase), pattern("^[a-z]+$"))]
+    lowercase: String,
+    #[garde(normalize(uppercase), prefix("ABC"))]
+    uppercase: &'a str,
+    #[garde(normalize(collapse_whitespace), length(max = 5))]
+    func: &'a str,
+}
+
+#[test]
+fn normalize_valid() {
+    util::check_ok(
+        &[Test {
+            trim: "  abc  ",
+            lowercase: "ABC".into(),
+            uppercase: "abcd",
+            func: "a    b   c",
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn normalize_invalid() {
+    util::check_fail!(
+        &[Test {
+            trim: "  ab  ",
+            lowercase: "ABC1".into(),
+            uppercase: "xyz",
+            func: "ab   cd   ef",
+        }],
+        &()
+    )
+}
@@ -0,0 +1,57 @@
+use garde::Validate;
+
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(ascii, not(pattern("^(admin|root|system)$")))]
+    username: &'a str,
+    #[garde(not(alphanumeric), length(min = 1))]
+    symbols: &'a str,
+    #[garde(inner(not(range(min = 10, max = 20))))]
+    numbers: &'a [u32],
+    #[garde(not(contains("password")), message("contains a forbidden word"))]
+    bio: &'a str,
+}
+
+#[test]
+fn not_valid() {
+    util::check_ok(
+        &[Test {
+            username: "user",
+            symbols: "!?",
+            numbers: &[1, 9, 21],
+            bio: "hello",
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn not_invalid() {
+    util::check_fail!(
+        &[Test {
+            username: "admin",
+            symbols: "abc",
+            numbers: &[1, 10, 20],
+            bio: "my password is hunter2",
+        }],
+        &()
+    )
+}
+
+#[test]
+fn not_code() {
+    let value = Test {
+        username: "root",
+        symbols: "!",
+        numbers: &[],
+        bio: "",
+    };
+    let report = value.validate().unwrap_err();
+    let codes = report
+        .iter()
+        .map(|(path, error)| (path.to_string(), error.code()))
+        .collect::<Vec<_>>();
+    assert_eq!(codes, [("username".to_string(), Some("GARDE_NOT_PATTERN"))]);
+}
@@ -0,0 +1,56 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(not_common_password)]
+    field: &'a str,
+    #[garde(not_common_password(case_insensitive))]
+    case_insensitive: &'a str,
+    #[garde(not_common_password)]
+    optional: Option<&'a str>,
+    #[garde(inner(not_common_password))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn not_common_password_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: "correct horse battery staple",
+                case_insensitive: "Tr0ub4dor&3-extended",
+                optional: None,
+                inner: &["kP9#vLq2!mZx"],
+            },
+            Test {
+                // matching is case-sensitive by default
+                field: "PASSWORD",
+                case_insensitive: "not-in-the-list",
+                optional: Some("also not in the list"),
+                inner: &[],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn not_common_password_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                field: "password",
+                case_insensitive: "PassWord",
+                optional: Some("123456"),
+                inner: &["qwerty"],
+            },
+            Test {
+                field: "letmein",
+                case_insensitive: "ILOVEYOU",
+                optional: Some("admin"),
+                inner: &["monkey", "dragon"],
+            },
+        ],
+        &()
+    )
+}
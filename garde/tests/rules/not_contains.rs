@@ -0,0 +1,51 @@
+use super::util;
+
+const TEST: &str = "admin";
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(not_contains(TEST))]
+    field: &'a str,
+    #[garde(not_contains("admin"))]
+    optional: Option<&'a str>,
+    #[garde(inner(not_contains("admin")))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn not_contains_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: "user",
+                optional: None,
+                inner: &["user"],
+            },
+            Test {
+                field: "adm_in",
+                optional: Some("adm_in"),
+                inner: &["adm_in"],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn not_contains_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                field: "admin",
+                optional: Some("admin"),
+                inner: &["admin"]
+            },
+            Test {
+                field: "super_admin_1",
+                optional: Some("super_admin_1"),
+                inner: &["super_admin_1"]
+            }
+        ],
+        &()
+    )
+}
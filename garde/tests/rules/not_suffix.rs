@@ -0,0 +1,51 @@
+use super::util;
+
+const TEST: &str = "admin";
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(not_suffix(TEST))]
+    field: &'a str,
+    #[garde(not_suffix("admin"))]
+    optional: Option<&'a str>,
+    #[garde(inner(not_suffix("admin")))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn not_suffix_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: "user",
+                optional: None,
+                inner: &["user"],
+            },
+            Test {
+                field: "admin_1",
+                optional: Some("admin_1"),
+                inner: &["admin_1"],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn not_suffix_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                field: "admin",
+                optional: Some("admin"),
+                inner: &["admin"]
+            },
+            Test {
+                field: "my_admin",
+                optional: Some("my_admin"),
+                inner: &["my_admin"]
+            }
+        ],
+        &()
+    )
+}
@@ -0,0 +1,68 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(numeric(u64))]
+    unsigned: &'a str,
+    #[garde(numeric(i32, negative))]
+    negative: String,
+    #[garde(numeric(f64, finite, positive))]
+    float: &'a str,
+    #[garde(numeric(u8, non_negative))]
+    optional: Option<String>,
+    #[garde(inner(numeric(f32)))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn numeric_string_valid() {
+    util::check_ok(
+        &[
+            Test {
+                unsigned: "0",
+                negative: "-1".into(),
+                float: "0.5",
+                optional: None,
+                inner: &["1", "-1.5", "inf"],
+            },
+            Test {
+                unsigned: "18446744073709551615",
+                negative: "-2147483648".into(),
+                float: "1e10",
+                optional: Some("255".into()),
+                inner: &[],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn numeric_string_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                unsigned: "-1",
+                negative: "0".into(),
+                float: "inf",
+                optional: Some("256".into()),
+                inner: &["one"],
+            },
+            Test {
+                unsigned: "",
+                negative: "1.0".into(),
+                float: "-0.5",
+                optional: Some("".into()),
+                inner: &["1", "1.0.0"],
+            },
+            Test {
+                unsigned: "18446744073709551616",
+                negative: "a".into(),
+                float: "NaN",
+                optional: Some(" 1".into()),
+                inner: &[""],
+            },
+        ],
+        &()
+    )
+}
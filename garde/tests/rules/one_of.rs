@@ -0,0 +1,63 @@
+use super::util;
+
+const RESERVED: &[&str] = &["admin", "root"];
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(one_of(["production", "staging", "development"]))]
+    field: &'a str,
+    #[garde(one_of([1, 2, 4, 8]))]
+    int_field: u32,
+    #[garde(one_of(['a', 'b']))]
+    optional: Option<char>,
+    #[garde(not_one_of(RESERVED))]
+    not_field: String,
+    #[garde(inner(not_one_of([0, 13])))]
+    inner: &'a [i64],
+}
+
+#[test]
+fn one_of_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: "production",
+                int_field: 1,
+                optional: None,
+                not_field: "user".into(),
+                inner: &[1, 2, 3],
+            },
+            Test {
+                field: "development",
+                int_field: 8,
+                optional: Some('b'),
+                not_field: "Admin".into(),
+                inner: &[],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn one_of_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                field: "Production",
+                int_field: 3,
+                optional: Some('c'),
+                not_field: "admin".into(),
+                inner: &[0],
+            },
+            Test {
+                field: "",
+                int_field: 0,
+                optional: Some('A'),
+                not_field: "root".into(),
+                inner: &[12, 13],
+            },
+        ],
+        &()
+    )
+}
@@ -0,0 +1,55 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(password(require_uppercase, require_lowercase, require_digit, require_special))]
+    categories: &'a str,
+    #[garde(password(min_complexity_score = 3))]
+    complexity: &'a str,
+    #[garde(password(min_entropy_bits = 40.0))]
+    entropy: &'a str,
+    #[garde(inner(password(require_digit)))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn password_valid() {
+    util::check_ok(
+        &[
+            Test {
+                categories: "Aa1!",
+                complexity: "Aa1",
+                entropy: "correcthorse",
+                inner: &["1"],
+            },
+            Test {
+                categories: "Ünïcödé 2 ✓",
+                complexity: "a1!",
+                entropy: "Tr0ub4dor&3",
+                inner: &[],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn password_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                categories: "",
+                complexity: "",
+                entropy: "",
+                inner: &["a"],
+            },
+            Test {
+                categories: "Aa1",
+                complexity: "aaaaaaaa",
+                entropy: "password",
+                inner: &["1", ""],
+            },
+        ],
+        &()
+    )
+}
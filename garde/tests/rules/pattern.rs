@@ -81,3 +81,103 @@ fn pattern_invalid() {
         &()
     )
 }
+
+fn is_http(v: &str) -> bool {
+    v.starts_with("http")
+}
+
+#[derive(Debug, garde::Validate)]
+struct FnTest<'a> {
+    #[garde(pattern(|v: &str| v.starts_with("http")))]
+    field_closure: &'a str,
+
+    #[garde(pattern(|v| v.starts_with("http")))]
+    field_closure_inferred: &'a str,
+
+    #[garde(pattern(garde::rules::pattern::FnMatcher::new(is_http)))]
+    field_fn: &'a str,
+
+    #[garde(inner(pattern(|v: &str| v.starts_with("http"))))]
+    inner: &'a [&'a str],
+}
+
+#[cfg_attr(not(all(target_arch = "wasm32", target_os = "unknown")), test)]
+#[cfg_attr(
+    all(target_arch = "wasm32", target_os = "unknown"),
+    wasm_bindgen_test::wasm_bindgen_test
+)]
+fn pattern_fn_valid() {
+    util::check_ok(
+        &[FnTest {
+            field_closure: "https://example.com",
+            field_closure_inferred: "https://example.com",
+            field_fn: "http://example.com",
+            inner: &["https://example.com"],
+        }],
+        &(),
+    )
+}
+
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+#[test]
+fn pattern_fn_invalid() {
+    util::check_fail!(
+        &[FnTest {
+            field_closure: "ftp://example.com",
+            field_closure_inferred: "ftp://example.com",
+            field_fn: "ftp://example.com",
+            inner: &["ftp://example.com"]
+        }],
+        &()
+    )
+}
+
+#[cfg(not(all(feature = "js-sys", target_arch = "wasm32", target_os = "unknown")))]
+mod groups {
+    use garde::rules::pattern::PatternWithGroups;
+    use once_cell::sync::Lazy;
+
+    use super::util;
+
+    static DATE: Lazy<PatternWithGroups> = Lazy::new(|| {
+        PatternWithGroups::new(r"^(?P<year>\d{4})-(?P<month>\d{2})-(?P<day>\d{2})$").unwrap()
+    });
+
+    #[derive(Debug, garde::Validate)]
+    struct GroupsTest<'a> {
+        #[garde(pattern(DATE))]
+        field: &'a str,
+    }
+
+    #[test]
+    fn pattern_groups_valid() {
+        util::check_ok(
+            &[GroupsTest {
+                field: "2024-01-31",
+            }],
+            &(),
+        )
+    }
+
+    #[test]
+    fn pattern_groups_invalid() {
+        util::check_fail!(
+            &[
+                GroupsTest { field: "24-01-31" },
+                GroupsTest { field: "2024-1-31" },
+                GroupsTest { field: "2024-01-" },
+                GroupsTest {
+                    field: "2024-01-31T00:00"
+                },
+            ],
+            &()
+        )
+    }
+
+    #[test]
+    fn pattern_groups_metadata() {
+        let report = garde::Validate::validate(&GroupsTest { field: "2024-1-31" }).unwrap_err();
+        let (_, error) = report.iter().next().unwrap();
+        assert_eq!(error.metadata().get("group"), Some("month"));
+    }
+}
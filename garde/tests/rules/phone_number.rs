@@ -57,3 +57,51 @@ fn phone_number_invalid() {
         &()
     )
 }
+
+#[derive(Debug, garde::Validate)]
+struct Region<'a> {
+    #[garde(phone_number(region = "US"))]
+    us: &'a str,
+    #[garde(phone_number(region = "FR"))]
+    fr: Option<&'a str>,
+    #[garde(phone_number(region = "XX"))]
+    unknown: Option<&'a str>,
+}
+
+#[test]
+fn phone_number_region_valid() {
+    util::check_ok(
+        &[
+            Region {
+                us: "(415) 237-0800",
+                fr: Some("06 42 92 68 29"),
+                unknown: None,
+            },
+            Region {
+                us: "+33642926829",
+                fr: Some("+1 (415) 237-0800"),
+                unknown: None,
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn phone_number_region_invalid() {
+    util::check_fail!(
+        &[
+            Region {
+                us: "0642926829",
+                fr: Some("(415) 237-0800"),
+                unknown: Some("+14152370800"),
+            },
+            Region {
+                us: "A012",
+                fr: Some("0000"),
+                unknown: None,
+            },
+        ],
+        &()
+    )
+}
@@ -0,0 +1,55 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(port)]
+    field: u16,
+    #[garde(port(unprivileged))]
+    unprivileged: u32,
+    #[garde(port)]
+    optional: Option<u64>,
+    #[garde(inner(port))]
+    inner: &'a [u32],
+}
+
+#[test]
+fn port_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: 1,
+                unprivileged: 1024,
+                optional: None,
+                inner: &[80, 443],
+            },
+            Test {
+                field: 65535,
+                unprivileged: 65535,
+                optional: Some(8080),
+                inner: &[],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn port_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                field: 0,
+                unprivileged: 80,
+                optional: Some(0),
+                inner: &[65536],
+            },
+            Test {
+                field: 0,
+                unprivileged: 65536,
+                optional: Some(u64::MAX),
+                inner: &[1, 0],
+            },
+        ],
+        &()
+    )
+}
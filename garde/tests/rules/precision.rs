@@ -0,0 +1,69 @@
+use rust_decimal::Decimal;
+
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(precision(max_decimal_places = 2))]
+    field: &'a str,
+    #[garde(precision(max_decimal_places = 0))]
+    integer: String,
+    #[garde(precision(max_decimal_places = 2))]
+    float_field: f64,
+    #[garde(precision(max_decimal_places = 3))]
+    decimal: Decimal,
+    #[garde(precision(max_decimal_places = 2))]
+    optional: Option<f32>,
+    #[garde(inner(precision(max_decimal_places = 1)))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn precision_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: "10.12",
+                integer: "-42".into(),
+                float_field: 10.5,
+                decimal: Decimal::new(1_234, 3),
+                optional: None,
+                inner: &["1", "+1.5", ".5"],
+            },
+            Test {
+                field: "10.1200",
+                integer: "7.000".into(),
+                float_field: -0.01,
+                decimal: Decimal::new(10_000, 4),
+                optional: Some(1.25),
+                inner: &[],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn precision_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                field: "10.123",
+                integer: "1.5".into(),
+                float_field: 0.1 + 0.2,
+                decimal: Decimal::new(12_345, 4),
+                optional: Some(1.125),
+                inner: &["1.25"],
+            },
+            Test {
+                field: "1e3",
+                integer: "".into(),
+                float_field: f64::NAN,
+                decimal: Decimal::new(1, 28),
+                optional: Some(f32::INFINITY),
+                inner: &["1.0.0", "-", "."],
+            },
+        ],
+        &()
+    )
+}
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use super::util;
 
 #[derive(Debug, garde::Validate)]
@@ -126,3 +128,151 @@ fn min_max_equal_length_invalid() {
         &()
     )
 }
+
+#[derive(Debug, garde::Validate)]
+struct Exclusive {
+    #[garde(range(exclusive_min = 0.0, max = 1.0))]
+    rate: f64,
+    #[garde(range(min = 0, exclusive_max = 10))]
+    digit: u8,
+    #[garde(range(exclusive_min = -1, exclusive_max = 1))]
+    both: Option<i32>,
+}
+
+#[test]
+fn exclusive_range_valid() {
+    util::check_ok(
+        &[
+            Exclusive {
+                rate: f64::EPSILON,
+                digit: 0,
+                both: None,
+            },
+            Exclusive {
+                rate: 1.0,
+                digit: 9,
+                both: Some(0),
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn exclusive_range_invalid() {
+    util::check_fail!(
+        &[
+            Exclusive {
+                rate: 0.0,
+                digit: 10,
+                both: Some(-1),
+            },
+            Exclusive {
+                rate: 1.5,
+                digit: 11,
+                both: Some(1),
+            },
+        ],
+        &()
+    )
+}
+
+// Only implements `validate_bounds`, so exclusive bounds use the default `validate_exclusive_bounds`.
+#[derive(Debug, PartialEq, PartialOrd)]
+struct Percent(u8);
+
+impl garde::rules::range::Bounds for Percent {
+    type Size = u8;
+
+    const MIN: Self::Size = 0;
+    const MAX: Self::Size = 100;
+
+    fn validate_bounds(
+        &self,
+        lower_bound: Self::Size,
+        upper_bound: Self::Size,
+    ) -> Result<(), garde::rules::range::OutOfBounds> {
+        if self.0 < lower_bound {
+            Err(garde::rules::range::OutOfBounds::Lower)
+        } else if self.0 > upper_bound {
+            Err(garde::rules::range::OutOfBounds::Upper)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[derive(Debug, garde::Validate)]
+struct CustomExclusive {
+    #[garde(range(exclusive_min = 0, exclusive_max = 100))]
+    value: Percent,
+}
+
+#[test]
+fn custom_exclusive_range_valid() {
+    util::check_ok(
+        &[
+            CustomExclusive { value: Percent(1) },
+            CustomExclusive { value: Percent(99) },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn custom_exclusive_range_invalid() {
+    util::check_fail!(
+        &[
+            CustomExclusive { value: Percent(0) },
+            CustomExclusive {
+                value: Percent(100)
+            },
+            CustomExclusive {
+                value: Percent(101)
+            },
+        ],
+        &()
+    )
+}
+
+#[derive(Debug, garde::Validate)]
+struct Timeouts {
+    #[garde(range(min = Duration::from_secs(1), max = Duration::from_secs(3600)))]
+    timeout: Duration,
+    #[garde(range(exclusive_min = Duration::ZERO))]
+    interval: Option<Duration>,
+}
+
+#[test]
+fn duration_range_valid() {
+    util::check_ok(
+        &[
+            Timeouts {
+                timeout: Duration::from_secs(1),
+                interval: None,
+            },
+            Timeouts {
+                timeout: Duration::from_secs(3600),
+                interval: Some(Duration::from_millis(1)),
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn duration_range_invalid() {
+    util::check_fail!(
+        &[
+            Timeouts {
+                timeout: Duration::from_millis(500),
+                interval: Some(Duration::ZERO),
+            },
+            Timeouts {
+                timeout: Duration::from_secs(3601),
+                interval: Some(Duration::ZERO),
+            },
+        ],
+        &()
+    )
+}
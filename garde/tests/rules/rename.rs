@@ -0,0 +1,74 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(rename("userName"), ascii)]
+    user_name: &'a str,
+    #[garde(alias("mail"), ascii)]
+    email_address: &'a str,
+    #[garde(dive)]
+    nested: Nested<'a>,
+    #[garde(dive)]
+    tuple: Tuple<'a>,
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(rename_all("camelCase"))]
+struct Nested<'a> {
+    #[garde(ascii)]
+    first_name: &'a str,
+    #[garde(rename("LAST"), ascii)]
+    last_name: &'a str,
+}
+
+#[derive(Debug, garde::Validate)]
+struct Tuple<'a>(
+    #[garde(rename("first"), ascii)] &'a str,
+    #[garde(ascii)] &'a str,
+);
+
+#[derive(Debug, garde::Validate)]
+#[garde(rename_all("SCREAMING-KEBAB-CASE"))]
+enum Enum<'a> {
+    Struct {
+        #[garde(ascii)]
+        some_field: &'a str,
+    },
+}
+
+#[test]
+fn rename_valid() {
+    util::check_ok(
+        &[Test {
+            user_name: "a",
+            email_address: "a",
+            nested: Nested {
+                first_name: "a",
+                last_name: "a",
+            },
+            tuple: Tuple("a", "a"),
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn rename_invalid() {
+    util::check_fail!(
+        &[Test {
+            user_name: "😂",
+            email_address: "😂",
+            nested: Nested {
+                first_name: "😂",
+                last_name: "😂",
+            },
+            tuple: Tuple("😂", "😂"),
+        }],
+        &()
+    )
+}
+
+#[test]
+fn rename_enum_invalid() {
+    util::check_fail!(&[Enum::Struct { some_field: "😂" }], &())
+}
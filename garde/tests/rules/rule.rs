@@ -0,0 +1,100 @@
+use garde::rules::{All, Any, Rule};
+
+use super::util;
+
+struct MinLen(usize);
+
+impl Rule<str> for MinLen {
+    fn validate(&self, value: &str, _: &()) -> garde::Result {
+        match value.len() < self.0 {
+            true => Err(garde::Error::new(format!("shorter than {}", self.0))),
+            false => Ok(()),
+        }
+    }
+}
+
+impl<'a> Rule<&'a str> for MinLen {
+    fn validate(&self, value: &&'a str, ctx: &()) -> garde::Result {
+        <Self as Rule<str>>::validate(self, value, ctx)
+    }
+}
+
+struct Prefix(&'static str);
+
+impl<'a> Rule<&'a str> for Prefix {
+    fn validate(&self, value: &&'a str, _: &()) -> garde::Result {
+        match value.starts_with(self.0) {
+            true => Ok(()),
+            false => Err(garde::Error::new(format!("not prefixed by `{}`", self.0))),
+        }
+    }
+}
+
+const PREFIXED_ID: All<Prefix, MinLen> = All(Prefix("id_"), MinLen(6));
+const ANY_PREFIX: Any<Prefix, Prefix> = Any(Prefix("http://"), Prefix("https://"));
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(rule(PREFIXED_ID))]
+    id: &'a str,
+    #[garde(rule(ANY_PREFIX), length(max = 20))]
+    link: &'a str,
+    #[garde(inner(rule(MinLen(2))))]
+    tags: &'a [&'a str],
+}
+
+#[test]
+fn rule_valid() {
+    util::check_ok(
+        &[Test {
+            id: "id_123",
+            link: "https://a.com",
+            tags: &["ab"],
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn rule_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                id: "123456",
+                link: "ftp://a.com",
+                tags: &["a"],
+            },
+            Test {
+                id: "id_1",
+                link: "http://a.com",
+                tags: &[],
+            }
+        ],
+        &()
+    )
+}
+
+struct Context {
+    max: usize,
+}
+
+fn max_len(value: &&str, ctx: &Context) -> garde::Result {
+    match value.len() > ctx.max {
+        true => Err(garde::Error::new(format!("longer than {}", ctx.max))),
+        false => Ok(()),
+    }
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(Context))]
+struct WithContext<'a> {
+    #[garde(rule(max_len))]
+    field: &'a str,
+}
+
+#[test]
+fn rule_context() {
+    let ctx = Context { max: 3 };
+    util::check_ok(&[WithContext { field: "abc" }], &ctx);
+    util::check_fail!(&[WithContext { field: "abcd" }], &ctx)
+}
@@ -0,0 +1,56 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(semver)]
+    field: &'a str,
+
+    #[garde(semver(req = ">=1.0.0, <2.0.0"))]
+    req: &'a str,
+
+    #[garde(inner(semver))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn semver_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: "0.1.0",
+                req: "1.0.0",
+                inner: &["0.1.0"],
+            },
+            Test {
+                field: "1.2.3-alpha.1+build.5",
+                req: "1.99.99",
+                inner: &["1.2.3-alpha.1+build.5"],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn semver_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                field: "",
+                req: "",
+                inner: &[""],
+            },
+            Test {
+                field: "1.0",
+                req: "0.9.0",
+                inner: &["1.0"],
+            },
+            Test {
+                field: "v1.0.0",
+                req: "2.0.0",
+                inner: &["v1.0.0"],
+            },
+        ],
+        &()
+    )
+}
@@ -0,0 +1,61 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(positive)]
+    positive: u32,
+    #[garde(non_negative)]
+    non_negative: i64,
+    #[garde(negative)]
+    negative: f64,
+    #[garde(non_positive)]
+    non_positive: Option<i8>,
+    #[garde(inner(positive))]
+    inner: &'a [f32],
+}
+
+#[test]
+fn sign_valid() {
+    util::check_ok(
+        &[
+            Test {
+                positive: 1,
+                non_negative: 0,
+                negative: -0.5,
+                non_positive: None,
+                inner: &[f32::MIN_POSITIVE],
+            },
+            Test {
+                positive: u32::MAX,
+                non_negative: 10,
+                negative: f64::NEG_INFINITY,
+                non_positive: Some(0),
+                inner: &[],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn sign_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                positive: 0,
+                non_negative: -1,
+                negative: 0.0,
+                non_positive: Some(1),
+                inner: &[-1.0],
+            },
+            Test {
+                positive: 0,
+                non_negative: i64::MIN,
+                negative: f64::NAN,
+                non_positive: Some(i8::MAX),
+                inner: &[1.0, 0.0],
+            },
+        ],
+        &()
+    )
+}
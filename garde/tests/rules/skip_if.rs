@@ -0,0 +1,64 @@
+use super::util;
+
+fn is_not_us(address: &Address<'_>, _: &()) -> bool {
+    address.country != "US"
+}
+
+#[derive(Debug, garde::Validate)]
+struct Address<'a> {
+    #[garde(length(equal = 2))]
+    country: &'a str,
+    #[garde(skip_if(is_not_us), required, length(equal = 2))]
+    state: Option<&'a str>,
+    #[garde(skip_if(|s: &Self, _| s.country == "XX"), dive)]
+    inner: Inner<'a>,
+}
+
+#[derive(Debug, garde::Validate)]
+struct Inner<'a> {
+    #[garde(ascii)]
+    field: &'a str,
+}
+
+#[test]
+fn skip_if_valid() {
+    util::check_ok(
+        &[
+            Address {
+                country: "US",
+                state: Some("CA"),
+                inner: Inner { field: "a" },
+            },
+            Address {
+                country: "DE",
+                state: None,
+                inner: Inner { field: "a" },
+            },
+            Address {
+                country: "XX",
+                state: Some("invalid"),
+                inner: Inner { field: "😂" },
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn skip_if_invalid() {
+    util::check_fail!(
+        &[
+            Address {
+                country: "US",
+                state: None,
+                inner: Inner { field: "😂" },
+            },
+            Address {
+                country: "US",
+                state: Some("California"),
+                inner: Inner { field: "a" },
+            },
+        ],
+        &()
+    )
+}
@@ -0,0 +1,55 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(slug)]
+    field: &'a str,
+    #[garde(slug(allow_uppercase))]
+    uppercase: &'a str,
+    #[garde(slug(allow_dots))]
+    dots: &'a str,
+    #[garde(inner(slug))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn slug_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: "my-first-post",
+                uppercase: "My-First-Post",
+                dots: "release-1.0",
+                inner: &["a", "2024", "a-1-b"],
+            },
+            Test {
+                field: "post",
+                uppercase: "post",
+                dots: "a.b-c",
+                inner: &[],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn slug_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                field: "My-Post",
+                uppercase: "-post",
+                dots: "release-.1",
+                inner: &["", "a--b", "a_b"],
+            },
+            Test {
+                field: "post-",
+                uppercase: "my post",
+                dots: "release.",
+                inner: &["a.b", "ü"],
+            },
+        ],
+        &()
+    )
+}
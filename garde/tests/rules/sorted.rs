@@ -0,0 +1,61 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(sorted)]
+    field: Vec<u32>,
+    #[garde(sorted(strict))]
+    strict: &'a [&'a str],
+    #[garde(sorted(descending))]
+    descending: [f64; 3],
+    #[garde(sorted(descending, strict))]
+    optional: Option<Vec<i64>>,
+    #[garde(inner(sorted))]
+    inner: &'a [Vec<u8>],
+}
+
+#[test]
+fn sorted_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: vec![1, 1, 2, 3],
+                strict: &["a", "b", "c"],
+                descending: [3.0, 2.0, 2.0],
+                optional: None,
+                inner: &[vec![], vec![1], vec![1, 2]],
+            },
+            Test {
+                field: vec![],
+                strict: &["a"],
+                descending: [0.0, -1.0, f64::NEG_INFINITY],
+                optional: Some(vec![3, 2, 1]),
+                inner: &[],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn sorted_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                field: vec![1, 3, 2],
+                strict: &["a", "a"],
+                descending: [1.0, 2.0, 3.0],
+                optional: Some(vec![3, 3]),
+                inner: &[vec![2, 1]],
+            },
+            Test {
+                field: vec![2, 1],
+                strict: &["b", "a"],
+                descending: [1.0, f64::NAN, 0.0],
+                optional: Some(vec![1, 2]),
+                inner: &[vec![1], vec![1, 0]],
+            },
+        ],
+        &()
+    )
+}
@@ -0,0 +1,55 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(time)]
+    field: &'a str,
+    #[garde(time(format = "%H:%M", after = "08:00", before = "18:00"))]
+    bounded: String,
+    #[garde(time)]
+    optional: Option<String>,
+    #[garde(inner(time(format = "%I:%M %p")))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn time_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: "00:00:00",
+                bounded: "08:01".into(),
+                optional: None,
+                inner: &["12:30 PM", "01:00 am"],
+            },
+            Test {
+                field: "23:59:59",
+                bounded: "17:59".into(),
+                optional: Some("12:00:00".into()),
+                inner: &[],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn time_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                field: "24:00:00",
+                bounded: "08:00".into(),
+                optional: Some("12:00".into()),
+                inner: &["13:00 PM"],
+            },
+            Test {
+                field: "12:60:00",
+                bounded: "18:00".into(),
+                optional: Some("noon".into()),
+                inner: &["12:00 PM", "12:00"],
+            },
+        ],
+        &()
+    )
+}
@@ -0,0 +1,67 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(timezone)]
+    field: &'a str,
+    #[garde(timezone(utc_offset_only))]
+    offset: String,
+    #[garde(timezone)]
+    optional: Option<String>,
+    #[garde(inner(timezone))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn timezone_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: "America/New_York",
+                offset: "+05:30".into(),
+                optional: None,
+                inner: &["Europe/Prague", "Asia/Tokyo"],
+            },
+            Test {
+                field: "UTC",
+                offset: "-23:59".into(),
+                optional: Some("Etc/GMT+5".into()),
+                inner: &[],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn timezone_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                field: "America/Atlantis",
+                offset: "05:30".into(),
+                optional: Some("".into()),
+                inner: &["+01:00"],
+            },
+            Test {
+                field: "america/new_york",
+                offset: "+24:00".into(),
+                optional: Some("Europe/Prague ".into()),
+                inner: &["UTC", "Mars/Olympus_Mons"],
+            },
+            Test {
+                field: "Europe/Prague",
+                offset: "+01:60".into(),
+                optional: None,
+                inner: &[],
+            },
+            Test {
+                field: "UTC",
+                offset: "Europe/Prague".into(),
+                optional: None,
+                inner: &[],
+            },
+        ],
+        &()
+    )
+}
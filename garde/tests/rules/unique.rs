@@ -0,0 +1,64 @@
+use std::collections::BTreeSet;
+
+use super::util;
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+enum Role {
+    Admin,
+    User,
+}
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(unique, length(min = 1))]
+    field: Vec<&'a str>,
+
+    #[garde(unique)]
+    slice: &'a [u32],
+
+    #[garde(unique)]
+    set: BTreeSet<&'a str>,
+
+    #[garde(inner(unique))]
+    inner: &'a [Vec<&'a str>],
+
+    #[garde(unique)]
+    roles: Vec<Role>,
+}
+
+#[test]
+fn unique_valid() {
+    util::check_ok(
+        &[Test {
+            field: vec!["a", "b", "c"],
+            slice: &[1, 2, 3],
+            set: BTreeSet::from(["a", "b"]),
+            inner: &[vec!["a", "b"], vec!["a", "b"]],
+            roles: vec![Role::Admin, Role::User],
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn unique_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                field: vec!["foo", "bar", "foo"],
+                slice: &[1, 2, 3, 2, 1],
+                set: BTreeSet::new(),
+                inner: &[vec!["a", "a"]],
+                roles: vec![Role::User, Role::Admin, Role::User],
+            },
+            Test {
+                field: vec![],
+                slice: &[0, 0],
+                set: BTreeSet::new(),
+                inner: &[vec![], vec!["b", "c", "b"]],
+                roles: vec![],
+            },
+        ],
+        &()
+    )
+}
@@ -0,0 +1,96 @@
+use super::util;
+
+#[derive(Debug)]
+struct User<'a> {
+    email: &'a str,
+    id: u32,
+}
+
+fn user_id(user: &User) -> u32 {
+    user.id
+}
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(unique_by(|user: &User| user.email.to_lowercase()))]
+    users: Vec<User<'a>>,
+
+    #[garde(unique_by(user_id))]
+    slice: &'a [User<'a>],
+
+    #[garde(unique_by(|user: &User| &user.email))]
+    optional: Option<Vec<User<'a>>>,
+}
+
+#[test]
+fn unique_by_valid() {
+    util::check_ok(
+        &[Test {
+            users: vec![
+                User {
+                    email: "a@example.com",
+                    id: 0,
+                },
+                User {
+                    email: "b@example.com",
+                    id: 0,
+                },
+            ],
+            slice: &[
+                User {
+                    email: "a@example.com",
+                    id: 0,
+                },
+                User {
+                    email: "a@example.com",
+                    id: 1,
+                },
+            ],
+            optional: None,
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn unique_by_invalid() {
+    util::check_fail!(
+        &[Test {
+            users: vec![
+                User {
+                    email: "a@example.com",
+                    id: 0
+                },
+                User {
+                    email: "b@example.com",
+                    id: 1
+                },
+                User {
+                    email: "A@example.com",
+                    id: 2
+                },
+            ],
+            slice: &[
+                User {
+                    email: "a@example.com",
+                    id: 0
+                },
+                User {
+                    email: "b@example.com",
+                    id: 0
+                },
+            ],
+            optional: Some(vec![
+                User {
+                    email: "a@example.com",
+                    id: 0
+                },
+                User {
+                    email: "a@example.com",
+                    id: 1
+                },
+            ]),
+        }],
+        &()
+    )
+}
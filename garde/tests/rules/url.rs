@@ -109,3 +109,92 @@ fn url_valid_wrapper() {
     };
     println!("{:?}", value.validate().unwrap_err());
 }
+
+#[derive(Debug, Validate)]
+struct Schemes<'a> {
+    #[garde(url(schemes = ["https"]))]
+    https: &'a str,
+    #[garde(url(schemes = ["https", "http"]))]
+    http: Option<&'a str>,
+    #[garde(inner(url(schemes = ["wss"])))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn url_schemes_valid() {
+    util::check_ok(
+        &[
+            Schemes {
+                https: "https://www.youtube.com/watch?v=dQw4w9WgXcQ",
+                http: Some("http://info.cern.ch/hypertext/WWW/TheProject.html"),
+                inner: &["wss://example.com/socket"],
+            },
+            Schemes {
+                https: "HTTPS://example.com",
+                http: None,
+                inner: &[],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn url_schemes_invalid() {
+    util::check_fail!(
+        &[
+            Schemes {
+                https: "http://example.com",
+                http: Some("ftp://example.com/file.txt"),
+                inner: &["ws://example.com/socket"],
+            },
+            Schemes {
+                https: "asdf",
+                http: Some("file:///etc/passwd"),
+                inner: &["wss ://example.com"],
+            },
+        ],
+        &()
+    )
+}
+
+#[derive(Debug, Validate)]
+struct Require<'a> {
+    #[garde(url(require_https))]
+    https: &'a str,
+    #[garde(url(require_tld))]
+    tld: &'a str,
+    #[garde(url(require_https, require_tld))]
+    both: &'a str,
+}
+
+#[test]
+fn url_require_valid() {
+    util::check_ok(
+        &[Require {
+            https: "https://localhost:8080",
+            tld: "http://example.com.",
+            both: "https://www.example.co.uk/path",
+        }],
+        &(),
+    )
+}
+
+#[test]
+fn url_require_invalid() {
+    util::check_fail!(
+        &[
+            Require {
+                https: "http://example.com",
+                tld: "http://localhost:8080",
+                both: "http://example.com",
+            },
+            Require {
+                https: "wss://example.com",
+                tld: "http://127.0.0.1",
+                both: "https://localhost",
+            },
+        ],
+        &()
+    )
+}
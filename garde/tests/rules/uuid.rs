@@ -0,0 +1,52 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(uuid)]
+    field: &'a str,
+
+    #[garde(inner(uuid))]
+    inner: &'a [&'a str],
+}
+
+#[test]
+fn uuid_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: "67e55044-10b1-426f-9247-bb680e5fe0c8",
+                inner: &["67e55044-10b1-426f-9247-bb680e5fe0c8"],
+            },
+            Test {
+                field: "67e5504410b1426f9247bb680e5fe0c8",
+                inner: &["67e5504410b1426f9247bb680e5fe0c8"],
+            },
+            Test {
+                field: "urn:uuid:F9168C5E-CEB2-4FAA-B6BF-329BF39FA1E4",
+                inner: &["urn:uuid:F9168C5E-CEB2-4FAA-B6BF-329BF39FA1E4"],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn uuid_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                field: "",
+                inner: &[""],
+            },
+            Test {
+                field: "67e55044-10b1-426f-9247-bb680e5fe0c",
+                inner: &["67e55044-10b1-426f-9247-bb680e5fe0c"],
+            },
+            Test {
+                field: "67e55044-10b1-426f-9247-bb680e5fe0cz",
+                inner: &["67e55044-10b1-426f-9247-bb680e5fe0cz"],
+            },
+        ],
+        &()
+    )
+}
@@ -0,0 +1,63 @@
+use garde::Validate;
+
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(length(min = 8), warn(length(min = 12), not(contains("password"))))]
+    password: &'a str,
+    #[garde(inner(warn(ascii)))]
+    tags: &'a [&'a str],
+}
+
+#[test]
+fn warn_valid() {
+    util::check_ok(
+        &[
+            Test {
+                password: "correct horse battery staple",
+                tags: &["a"],
+            },
+            Test {
+                password: "password",
+                tags: &["😂"],
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn warn_invalid() {
+    util::check_fail!(
+        &[Test {
+            password: "pass",
+            tags: &["😂"],
+        }],
+        &()
+    )
+}
+
+#[test]
+fn warn_report() {
+    let value = Test {
+        password: "password",
+        tags: &["a", "😂"],
+    };
+    let mut report = garde::Report::new();
+    value.validate_into(&(), &mut garde::Path::empty, &mut report);
+
+    assert!(!report.has_errors());
+    let warnings = report
+        .warnings()
+        .map(|(path, error)| format!("{path}: {}", error.code().unwrap()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        warnings,
+        [
+            "password: GARDE_LENGTH",
+            "password: GARDE_NOT_CONTAINS",
+            "tags[1]: GARDE_ASCII",
+        ]
+    );
+}
@@ -0,0 +1,78 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(skip)]
+    billing_enabled: bool,
+    #[garde(when(|s, _| s.billing_enabled, required, length(min = 1)))]
+    billing_address: Option<&'a str>,
+    #[garde(ascii, when(|s: &Self, _| s.billing_enabled, alphanumeric))]
+    name: &'a str,
+}
+
+struct Context {
+    strict: bool,
+}
+
+fn is_strict(_: &TestCtx<'_>, ctx: &Context) -> bool {
+    ctx.strict
+}
+
+#[derive(Debug, garde::Validate)]
+#[garde(context(Context))]
+struct TestCtx<'a> {
+    #[garde(ascii, when(is_strict, alphanumeric, length(max = 5)))]
+    name: &'a str,
+}
+
+#[test]
+fn when_valid() {
+    util::check_ok(
+        &[
+            Test {
+                billing_enabled: false,
+                billing_address: None,
+                name: "not strict",
+            },
+            Test {
+                billing_enabled: true,
+                billing_address: Some("street"),
+                name: "",
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn when_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                billing_enabled: true,
+                billing_address: None,
+                name: "😂",
+            },
+            Test {
+                billing_enabled: true,
+                billing_address: Some(""),
+                name: "",
+            },
+        ],
+        &()
+    )
+}
+
+#[test]
+fn when_context_valid() {
+    util::check_ok(
+        &[TestCtx { name: "not strict" }],
+        &Context { strict: false },
+    );
+    util::check_ok(&[TestCtx { name: "abc" }], &Context { strict: true });
+}
+
+#[test]
+fn when_context_invalid() {
+    util::check_fail!(&[TestCtx { name: "not strict" }], &Context { strict: true })
+}
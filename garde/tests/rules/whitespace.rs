@@ -0,0 +1,56 @@
+use super::util;
+
+#[derive(Debug, garde::Validate)]
+struct Test<'a> {
+    #[garde(no_whitespace)]
+    field: &'a str,
+
+    #[garde(inner(no_whitespace))]
+    inner: &'a [&'a str],
+
+    #[garde(no_leading_whitespace, no_trailing_whitespace)]
+    trimmed: &'a str,
+}
+
+#[test]
+fn whitespace_valid() {
+    util::check_ok(
+        &[
+            Test {
+                field: "user-name_1",
+                inner: &["user-name_1"],
+                trimmed: "display name",
+            },
+            Test {
+                field: "",
+                inner: &[""],
+                trimmed: "",
+            },
+        ],
+        &(),
+    )
+}
+
+#[test]
+fn whitespace_invalid() {
+    util::check_fail!(
+        &[
+            Test {
+                field: "user name",
+                inner: &["user\tname"],
+                trimmed: " display name",
+            },
+            Test {
+                field: "user\u{3000}name",
+                inner: &["\nusername"],
+                trimmed: "display name\n",
+            },
+            Test {
+                field: " ",
+                inner: &[" "],
+                trimmed: " ",
+            },
+        ],
+        &()
+    )
+}
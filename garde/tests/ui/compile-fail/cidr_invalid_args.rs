@@ -0,0 +1,11 @@
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(cidr(version = "v4"))]
+    version: &'a str,
+    #[garde(cidr(version = "ipv4", version = "ipv6"))]
+    duplicate: &'a str,
+    #[garde(cidr(prefix = 24))]
+    unknown: &'a str,
+}
+
+fn main() {}
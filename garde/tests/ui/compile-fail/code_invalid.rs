@@ -0,0 +1,11 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(custom(|_, _| Ok(()), code = "CUSTOM"))]
+    custom: &'a str,
+    #[garde(skip(code = "SKIP"))]
+    skip: &'a str,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+struct Context {
+    inner: (),
+}
+
+#[derive(garde::Validate)]
+#[garde(context(Context))]
+enum Test {
+    #[garde(context_fn(|ctx: &Context| &ctx.inner))]
+    Empty,
+}
+
+fn main() {}
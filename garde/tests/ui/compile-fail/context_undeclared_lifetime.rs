@@ -0,0 +1,19 @@
+struct Context<'a> {
+    value: &'a str,
+}
+
+#[derive(garde::Validate)]
+#[garde(context(Context<'_>))]
+struct Elided {
+    #[garde(length(min = 1))]
+    field: String,
+}
+
+#[derive(garde::Validate)]
+#[garde(context(Context<'a>))]
+struct Undeclared {
+    #[garde(length(min = 1))]
+    field: String,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(credit_card(networks = ["visa", "paypal"]))]
+    unknown: &'a str,
+    #[garde(credit_card(networks = []))]
+    empty: &'a str,
+    #[garde(credit_card(networks = "visa"))]
+    not_array: &'a str,
+}
+
+fn main() {}
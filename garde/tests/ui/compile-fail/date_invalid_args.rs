@@ -0,0 +1,9 @@
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(date(format = "%Y", format = "%m"))]
+    duplicate: &'a str,
+    #[garde(date(since = "2000-01-01"))]
+    unknown: &'a str,
+}
+
+fn main() {}
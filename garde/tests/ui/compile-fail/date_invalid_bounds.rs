@@ -0,0 +1,13 @@
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(date(format = "%Y-%m-%Q"))]
+    format: &'a str,
+    #[garde(date(after = "2000-13-01"))]
+    bound: &'a str,
+    #[garde(time(after = "18:00:00", before = "08:00:00"))]
+    reversed: &'a str,
+    #[garde(datetime(after = "2000-01-01"))]
+    datetime: &'a str,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test {
+    #[garde(dive)]
+    field: Box<dyn garde::Validate<Context = ()>>,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(email(check_mx, check_mx))]
+    duplicate: &'a str,
+    #[garde(inner(email(check_mx)))]
+    inner: &'a [&'a str],
+}
+
+fn main() {}
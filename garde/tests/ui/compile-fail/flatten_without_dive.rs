@@ -0,0 +1,13 @@
+#[derive(garde::Validate)]
+struct Inner<'a> {
+    #[garde(length(min = 1))]
+    field: &'a str,
+}
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(flatten)]
+    inner: Inner<'a>,
+}
+
+fn main() {}
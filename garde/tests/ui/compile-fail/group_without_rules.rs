@@ -0,0 +1,11 @@
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(group("a"))]
+    field: &'a str,
+    #[garde(group())]
+    empty: &'a str,
+    #[garde(skip, group("a"))]
+    skipped: &'a str,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use std::collections::HashMap;
+
+#[derive(garde::Validate)]
+struct Test {
+    #[garde(key(ascii))]
+    key_outside_inner: HashMap<String, String>,
+    #[garde(inner(key(ascii), ascii))]
+    key_with_other_rules: HashMap<String, String>,
+    #[garde(inner(key(ascii), key(length(min = 1))))]
+    duplicate_key: HashMap<String, String>,
+}
+
+fn main() {}
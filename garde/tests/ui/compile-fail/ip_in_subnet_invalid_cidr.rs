@@ -0,0 +1,9 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(ip_in_subnet("192.168.0.0/33"))]
+    field: &'a str,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(isbn(version = 12))]
+    version: &'a str,
+    #[garde(isbn(version = "13"))]
+    string: &'a str,
+    #[garde(isbn(format = "isbn10", version = 13))]
+    duplicate: &'a str,
+}
+
+fn main() {}
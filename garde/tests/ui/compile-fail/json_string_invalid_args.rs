@@ -0,0 +1,11 @@
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(json_string(schema_type = "Object"))]
+    capitalized: &'a str,
+    #[garde(json_string(schema_type = "integer"))]
+    unknown_type: &'a str,
+    #[garde(json_string(schema = "object"))]
+    unknown_argument: &'a str,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(ascii, message("{min}"))]
+    field: &'a str,
+    #[garde(ascii, message("{foo}"))]
+    unknown: &'a str,
+}
+
+fn main() {}
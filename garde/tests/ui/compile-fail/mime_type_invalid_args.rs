@@ -0,0 +1,15 @@
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(mime_type(allowed = []))]
+    empty: &'a str,
+    #[garde(mime_type(allowed = ["image"]))]
+    no_subtype: &'a str,
+    #[garde(mime_type(allowed = ["text/plain; charset=utf-8"]))]
+    parameters: &'a str,
+    #[garde(mime_type(allowed = ["text/plain"], allowed = ["text/html"]))]
+    duplicate: &'a str,
+    #[garde(mime_type(types = ["text/plain"]))]
+    unknown: &'a str,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(normalize(trim))]
+    field: &'a str,
+    #[garde(normalize(trim), inner(length(min = 1)))]
+    inner: &'a [&'a str],
+    #[garde(length(min = 1), inner(normalize(trim), length(min = 1)))]
+    nested: &'a [&'a str],
+}
+
+fn main() {}
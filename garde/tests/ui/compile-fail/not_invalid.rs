@@ -0,0 +1,15 @@
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(not(not_contains("a")))]
+    double_negation: &'a str,
+    #[garde(not(not_common_password))]
+    common_password: &'a str,
+    #[garde(not(custom(|_, _| Ok(()))))]
+    custom: &'a str,
+    #[garde(not(skip))]
+    skip: &'a str,
+    #[garde(not(ascii), not(ascii))]
+    duplicate: &'a str,
+}
+
+fn main() {}
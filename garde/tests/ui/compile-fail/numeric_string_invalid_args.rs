@@ -0,0 +1,11 @@
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(numeric(u64, finite, finite))]
+    duplicate: &'a str,
+    #[garde(numeric(i64, positive, negative))]
+    conflicting: &'a str,
+    #[garde(numeric(u64, even))]
+    unknown: &'a str,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(password())]
+    empty: &'a str,
+    #[garde(password(min_complexity_score = 5))]
+    complexity: &'a str,
+    #[garde(password(min_entropy_bits = 0.0))]
+    entropy: &'a str,
+    #[garde(password(require_digit, require_digit))]
+    duplicate: &'a str,
+    #[garde(password(require_emoji))]
+    unknown: &'a str,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(precision())]
+    empty: &'a str,
+    #[garde(precision(max_decimal_places = -1))]
+    negative: &'a str,
+    #[garde(precision(max_decimal_places = 2, max_decimal_places = 3))]
+    duplicate: &'a str,
+    #[garde(precision(max_digits = 2))]
+    unknown: &'a str,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+#[derive(garde::Validate)]
+struct Test {
+    #[garde(range(min = 0, exclusive_min = 0))]
+    min: u32,
+    #[garde(range(max = 10, exclusive_max = 10))]
+    max: u32,
+    #[garde(range(equal = 5, exclusive_min = 0))]
+    equal: u32,
+}
+
+fn main() {}
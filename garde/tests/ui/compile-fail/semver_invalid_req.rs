@@ -0,0 +1,9 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(semver(req = ">=1.0.0, <"))]
+    field: &'a str,
+}
+
+fn main() {}
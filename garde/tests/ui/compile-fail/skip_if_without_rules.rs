@@ -0,0 +1,11 @@
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(skip)]
+    enabled: bool,
+    #[garde(skip_if(|s, _| !s.enabled))]
+    field: &'a str,
+    #[garde(skip, skip_if(|s, _| !s.enabled))]
+    skipped: &'a str,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+#[derive(garde::Validate)]
+struct Test {
+    #[garde(sorted(strict, strict))]
+    duplicate: Vec<u32>,
+    #[garde(sorted(ascending))]
+    unknown: Vec<u32>,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+#![deny(deprecated)]
+
+#[derive(garde::Validate)]
+enum Test {
+    #[garde(skip)]
+    Struct {
+        #[garde(ascii)]
+        field: String,
+    },
+    #[garde(context_fn(|ctx: &()| ctx))]
+    Tuple(#[garde(ascii)] String),
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(warn(inner(ascii)))]
+    inner: &'a [&'a str],
+    #[garde(not(warn(ascii)))]
+    not: &'a str,
+    #[garde(warn(warn(ascii)))]
+    nested: &'a str,
+    #[garde(warn(dive))]
+    dive: &'a str,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(skip)]
+    enabled: bool,
+    #[garde(when(|s, _| s.enabled, dive))]
+    dive: &'a str,
+    #[garde(when(|s, _| s.enabled, inner(ascii)))]
+    inner: &'a [&'a str],
+    #[garde(inner(when(|s, _| s.enabled, ascii)))]
+    nested: &'a [&'a str],
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(skip)]
+    enabled: bool,
+    #[garde(when(|s, _| s.enabled))]
+    field: &'a str,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(alphabetic)]
+    field: &'a str,
+    #[garde(alphabetic(ascii))]
+    unicode: &'a str,
+    #[garde(inner(alphabetic))]
+    inner: &'a [&'a str],
+}
+
+fn main() {}
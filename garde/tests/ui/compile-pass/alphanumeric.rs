@@ -4,6 +4,8 @@
 struct Test<'a> {
     #[garde(alphanumeric)]
     field: &'a str,
+    #[garde(alphanumeric(ascii))]
+    unicode: &'a str,
     #[garde(inner(alphanumeric))]
     inner: &'a [&'a str],
 }
@@ -0,0 +1,18 @@
+#![allow(dead_code)]
+
+async fn is_available(_: &str, _: &()) -> garde::Result {
+    Ok(())
+}
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(ascii, async_custom(is_available))]
+    field: &'a str,
+    #[garde(dive)]
+    nested: Nested,
+}
+
+#[derive(garde::Validate)]
+struct Nested(#[garde(async_custom(is_available))] String);
+
+fn main() {}
@@ -0,0 +1,13 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(base64)]
+    field: &'a str,
+    #[garde(base64(url_safe, padding = "optional"))]
+    url_safe: &'a str,
+    #[garde(inner(base64(standard, padding = "none")))]
+    inner: &'a [&'a str],
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(lowercase)]
+    lower: &'a str,
+    #[garde(uppercase)]
+    upper: &'a str,
+    #[garde(inner(lowercase, uppercase))]
+    inner: &'a [&'a str],
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(cidr)]
+    field: &'a str,
+    #[garde(cidr(version = "ipv6"))]
+    v6: String,
+    #[garde(inner(cidr(version = "ipv4")))]
+    allowed_cidrs: Vec<String>,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(length(min = 1, max = 10), code("INVALID_FIELD"))]
+    field: &'a str,
+    #[garde(inner(ascii), code("INVALID_ITEM"))]
+    items: &'a [&'a str],
+    #[garde(length(min = 3, code = "TOO_SHORT"), ascii(code = "NOT_ASCII"), not(contains("+"), code = "ALIAS"))]
+    username: &'a str,
+}
+
+fn main() {}
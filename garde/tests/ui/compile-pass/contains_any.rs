@@ -0,0 +1,15 @@
+#![allow(dead_code)]
+
+const TERMS: &[&str] = &["spam", "scam"];
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(contains_any(["spam", "scam", "phishing"]))]
+    field: &'a str,
+    #[garde(contains_all(["foo", "bar"]))]
+    all: &'a str,
+    #[garde(inner(contains_any(TERMS)))]
+    inner: &'a [&'a str],
+}
+
+fn main() {}
@@ -0,0 +1,37 @@
+#![allow(dead_code)]
+
+struct Pool;
+
+struct Context<'a> {
+    pool: &'a Pool,
+}
+
+#[derive(garde::Validate)]
+#[garde(context(Context<'_>))]
+struct Elided<'a> {
+    #[garde(length(min = 1))]
+    field: &'a str,
+}
+
+#[derive(garde::Validate)]
+#[garde(context(&Pool))]
+struct ElidedReference<'a> {
+    #[garde(length(min = 1))]
+    field: &'a str,
+}
+
+#[derive(garde::Validate)]
+#[garde(context(Box<dyn for<'x> Fn(&'x str) -> bool>))]
+struct HigherRanked {
+    #[garde(length(min = 1))]
+    field: String,
+}
+
+#[derive(garde::Validate)]
+#[garde(context(fn(&str) -> bool))]
+struct FnPointer {
+    #[garde(length(min = 1))]
+    field: String,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(country_code)]
+    field: &'a str,
+    #[garde(country_code(alpha = 3, case_insensitive))]
+    alpha3: &'a str,
+    #[garde(inner(country_code))]
+    inner: &'a [&'a str],
+}
+
+fn main() {}
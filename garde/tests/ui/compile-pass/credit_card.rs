@@ -4,6 +4,8 @@
 struct Test<'a> {
     #[garde(credit_card)]
     field: &'a str,
+    #[garde(credit_card(networks = ["visa", "mastercard"]))]
+    networks: &'a str,
     #[garde(inner(credit_card))]
     inner: &'a [&'a str],
 }
@@ -0,0 +1,15 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(date)]
+    date: &'a str,
+    #[garde(date(format = "%d.%m.%Y", after = "01.01.2000", before = "01.01.2100"))]
+    bounded_date: String,
+    #[garde(time(format = "%H:%M"))]
+    time: String,
+    #[garde(datetime, length(max = 64))]
+    datetime: Option<String>,
+}
+
+fn main() {}
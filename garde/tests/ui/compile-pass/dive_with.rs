@@ -0,0 +1,20 @@
+#![allow(dead_code)]
+
+struct Outer {
+    inner: (),
+}
+
+#[derive(garde::Validate)]
+struct Inner {
+    #[garde(ascii)]
+    field: String,
+}
+
+#[derive(garde::Validate)]
+#[garde(context(Outer))]
+struct Test {
+    #[garde(dive_with(|ctx: &Outer| &ctx.inner))]
+    inner: Inner,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(domain)]
+    field: &'a str,
+    #[garde(domain(require_tld))]
+    require_tld: &'a str,
+    #[garde(inner(domain))]
+    inner: &'a [&'a str],
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(email(check_mx))]
+    field: &'a str,
+    #[garde(email(check_mx))]
+    optional: Option<&'a str>,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(finite)]
+    field: f64,
+    #[garde(finite, range(min = 0.0, max = 100.0))]
+    bounded: f32,
+    #[garde(inner(finite))]
+    inner: &'a [f32],
+}
+
+fn main() {}
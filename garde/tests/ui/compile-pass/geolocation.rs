@@ -0,0 +1,15 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(latitude)]
+    lat: f64,
+    #[garde(longitude)]
+    lon: f32,
+    #[garde(coordinate_pair)]
+    position: Option<(f32, f32)>,
+    #[garde(inner(coordinate_pair))]
+    inner: &'a [(f64, f64)],
+}
+
+fn main() {}
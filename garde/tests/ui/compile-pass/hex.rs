@@ -0,0 +1,11 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(hex)]
+    field: &'a str,
+    #[garde(inner(hex))]
+    inner: &'a [&'a str],
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(iban)]
+    field: &'a str,
+    #[garde(iban(country = "DE"))]
+    german: &'a str,
+    #[garde(inner(iban))]
+    inner: &'a [&'a str],
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(ip_in_subnet("192.168.0.0/24"))]
+    field: &'a str,
+    #[garde(ip_in_subnet("2001:db8::/32"))]
+    v6: &'a str,
+    #[garde(inner(ip_in_subnet("10.0.0.0/8")))]
+    inner: &'a [&'a str],
+}
+
+fn main() {}
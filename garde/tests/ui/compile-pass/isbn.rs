@@ -0,0 +1,17 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(isbn)]
+    field: &'a str,
+    #[garde(isbn(format = "isbn10"))]
+    isbn10: &'a str,
+    #[garde(isbn(format = "isbn13"))]
+    isbn13: &'a str,
+    #[garde(isbn(version = 13))]
+    version: &'a str,
+    #[garde(inner(isbn))]
+    inner: &'a [&'a str],
+}
+
+fn main() {}
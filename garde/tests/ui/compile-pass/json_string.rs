@@ -0,0 +1,13 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(json_string)]
+    field: &'a str,
+    #[garde(json_string(schema_type = "object"))]
+    object: &'a str,
+    #[garde(inner(json_string))]
+    inner: &'a [&'a str],
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(luhn)]
+    field: &'a str,
+    #[garde(inner(luhn))]
+    inner: &'a [&'a str],
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(mac_address)]
+    field: &'a str,
+    #[garde(mac_address(format = "colon"))]
+    colon: &'a str,
+    #[garde(mac_address(format = "hyphen"))]
+    hyphen: &'a str,
+    #[garde(inner(mac_address))]
+    inner: &'a [&'a str],
+}
+
+fn main() {}
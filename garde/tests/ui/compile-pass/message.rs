@@ -0,0 +1,11 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(length(min = 1, max = 10), message("expected {min} to {max} characters"))]
+    field: &'a str,
+    #[garde(ascii, message(|_: &str, _: &()| "not ascii"))]
+    func: &'a str,
+}
+
+fn main() {}
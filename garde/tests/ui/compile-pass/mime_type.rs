@@ -0,0 +1,13 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(mime_type)]
+    field: &'a str,
+    #[garde(mime_type(allowed = ["image/jpeg", "image/png"]))]
+    allowed: String,
+    #[garde(inner(mime_type(allowed = ["application/json"])))]
+    inner: &'a [String],
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(range(min = 6), multiple_of(6))]
+    field: u32,
+    #[garde(multiple_of(0.5))]
+    float_field: f32,
+    #[garde(inner(multiple_of(5)))]
+    inner: &'a [usize],
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(not_common_password)]
+    field: &'a str,
+    #[garde(not_common_password(case_insensitive))]
+    case_insensitive: &'a str,
+    #[garde(inner(not_common_password))]
+    inner: &'a [&'a str],
+}
+
+fn main() {}
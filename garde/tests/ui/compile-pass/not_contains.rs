@@ -0,0 +1,11 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(not_contains("admin"))]
+    field: &'a str,
+    #[garde(inner(not_contains("admin")))]
+    inner: &'a [&'a str],
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(not_prefix("admin"))]
+    field: &'a str,
+    #[garde(inner(not_prefix("admin")))]
+    inner: &'a [&'a str],
+}
+
+fn main() {}
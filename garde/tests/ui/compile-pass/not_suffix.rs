@@ -0,0 +1,11 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(not_suffix("admin"))]
+    field: &'a str,
+    #[garde(inner(not_suffix("admin")))]
+    inner: &'a [&'a str],
+}
+
+fn main() {}
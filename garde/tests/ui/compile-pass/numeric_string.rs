@@ -0,0 +1,13 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(numeric(u64))]
+    unsigned: &'a str,
+    #[garde(numeric(f64, finite, non_negative))]
+    float: String,
+    #[garde(numeric(i128, non_positive))]
+    optional: Option<String>,
+}
+
+fn main() {}
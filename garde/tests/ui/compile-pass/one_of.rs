@@ -0,0 +1,15 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(one_of(["a", "b", "c"]))]
+    field: &'a str,
+    #[garde(one_of([1, 2, 3]))]
+    int_field: u8,
+    #[garde(not_one_of(["admin", "root"]))]
+    not_field: String,
+    #[garde(inner(one_of(["a", "b"])))]
+    inner: &'a [&'a str],
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(password(require_uppercase, require_lowercase, require_digit, require_special))]
+    field: &'a str,
+    #[garde(password(min_entropy_bits = 40.0, min_complexity_score = 3))]
+    score: &'a str,
+    #[garde(inner(password(require_digit)))]
+    inner: &'a [&'a str],
+}
+
+fn main() {}
@@ -4,6 +4,8 @@
 struct Test<'a> {
     #[garde(pattern(r"a|b"))]
     field: &'a str,
+    #[garde(pattern(|v: &str| v.starts_with("http")))]
+    closure: &'a str,
     #[garde(inner(pattern(r"a|b")))]
     inner: &'a [&'a str],
 }
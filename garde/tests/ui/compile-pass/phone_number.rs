@@ -4,6 +4,8 @@
 struct Test<'a> {
     #[garde(phone_number)]
     field: &'a str,
+    #[garde(phone_number(region = "US"))]
+    region: &'a str,
     #[garde(inner(phone_number))]
     inner: &'a [&'a str],
 }
@@ -0,0 +1,13 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(port)]
+    field: u16,
+    #[garde(port(unprivileged))]
+    unprivileged: u32,
+    #[garde(inner(port))]
+    inner: &'a [u64],
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(precision(max_decimal_places = 2))]
+    field: &'a str,
+    #[garde(precision(max_decimal_places = 2))]
+    float_field: f64,
+    #[garde(inner(precision(max_decimal_places = 1)))]
+    inner: &'a [f32],
+}
+
+fn main() {}
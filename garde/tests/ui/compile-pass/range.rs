@@ -4,6 +4,8 @@
 struct Test<'a> {
     #[garde(range(min = 10, max = 100))]
     field: u64,
+    #[garde(range(exclusive_min = 0.0, max = 1.0))]
+    exclusive: f64,
     #[garde(inner(range(min = 10, max = 100)))]
     inner: &'a [u64],
 }
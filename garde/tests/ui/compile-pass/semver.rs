@@ -0,0 +1,17 @@
+#![allow(dead_code)]
+
+const REQ: &str = "^1.2";
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(semver)]
+    field: &'a str,
+    #[garde(semver(req = ">=1.0.0"))]
+    req: &'a str,
+    #[garde(semver(req = REQ))]
+    req_const: &'a str,
+    #[garde(inner(semver))]
+    inner: &'a [&'a str],
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(positive)]
+    positive: u32,
+    #[garde(non_negative)]
+    non_negative: i64,
+    #[garde(negative)]
+    negative: f64,
+    #[garde(non_positive)]
+    non_positive: f32,
+    #[garde(inner(positive))]
+    inner: &'a [u8],
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(skip)]
+    enabled: bool,
+    #[garde(skip_if(|s, _| !s.enabled), ascii)]
+    field: &'a str,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(slug)]
+    field: &'a str,
+    #[garde(slug(allow_uppercase, allow_dots))]
+    relaxed: &'a str,
+    #[garde(inner(slug))]
+    inner: &'a [&'a str],
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(sorted)]
+    field: Vec<u32>,
+    #[garde(sorted(descending, strict))]
+    strict: &'a [f32],
+    #[garde(inner(sorted))]
+    inner: &'a [Vec<&'a str>],
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(timezone)]
+    field: &'a str,
+    #[garde(timezone(utc_offset_only))]
+    offset: String,
+    #[garde(inner(timezone))]
+    inner: &'a [String],
+}
+
+fn main() {}
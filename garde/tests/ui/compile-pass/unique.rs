@@ -0,0 +1,11 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(unique)]
+    field: Vec<&'a str>,
+    #[garde(inner(unique))]
+    inner: &'a [Vec<&'a str>],
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+#![allow(dead_code)]
+
+struct User {
+    email: String,
+}
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(unique_by(|user: &User| &user.email))]
+    field: Vec<User>,
+    #[garde(inner(unique_by(|user: &User| user.email.len())))]
+    inner: &'a [Vec<User>],
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(url)]
+    field: &'a str,
+    #[garde(url(schemes = ["https", "http"]))]
+    schemes: &'a str,
+    #[garde(inner(url(schemes = ["https"])))]
+    inner: &'a [&'a str],
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(uuid)]
+    field: &'a str,
+    #[garde(inner(uuid))]
+    inner: &'a [&'a str],
+}
+
+fn main() {}
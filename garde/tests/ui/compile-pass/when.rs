@@ -0,0 +1,11 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(skip)]
+    enabled: bool,
+    #[garde(when(|s, _| s.enabled, required, length(min = 1)))]
+    field: Option<&'a str>,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+#![allow(dead_code)]
+
+#[derive(garde::Validate)]
+struct Test<'a> {
+    #[garde(no_whitespace)]
+    field: &'a str,
+    #[garde(no_leading_whitespace, no_trailing_whitespace)]
+    trimmed: &'a str,
+    #[garde(inner(no_whitespace))]
+    inner: &'a [&'a str],
+}
+
+fn main() {}
@@ -1,6 +1,7 @@
 use std::collections::BTreeSet;
 
 use proc_macro2::Span;
+use syn::ext::IdentExt as _;
 use syn::parse_quote;
 use syn::spanned::Spanned;
 
@@ -14,6 +15,7 @@ pub fn check(input: model::Input) -> syn::Result<model::Validate> {
         generics,
         attrs,
         kind,
+        ignored_variant_attrs,
     } = input;
 
     let mut error = None;
@@ -22,7 +24,7 @@ pub fn check(input: model::Input) -> syn::Result<model::Validate> {
         error.maybe_fold(e);
     }
 
-    let context = match get_context(&attrs) {
+    let mut context = match get_context(&attrs) {
         Ok(v) => v,
         Err(e) => {
             error.maybe_fold(e);
@@ -30,6 +32,14 @@ pub fn check(input: model::Input) -> syn::Result<model::Validate> {
         }
     };
 
+    if let Err(e) = check_context_lifetimes(&mut context.0, &generics) {
+        error.maybe_fold(e);
+    }
+
+    if let Err(e) = check_async_attr(&attrs) {
+        error.maybe_fold(e);
+    }
+
     let transparent = get_transparent_attr(&attrs);
 
     let options = get_options(&attrs);
@@ -48,13 +58,17 @@ pub fn check(input: model::Input) -> syn::Result<model::Validate> {
         model::InputKind::Enum(list) => {
             let mut inner_error = None;
             let mut variants = Vec::new();
-            for (ident, variant) in list {
+            for (ident, context_fn, variant) in list {
                 match variant {
                     Some(variant) => match check_variant(variant, &options) {
-                        Ok(v) => variants.push((ident, Some(v))),
+                        Ok(v) => variants.push((ident, context_fn, Some(v))),
                         Err(e) => inner_error.maybe_fold(e),
                     },
-                    None => variants.push((ident, None)),
+                    None if context_fn.is_some() => inner_error.maybe_fold(syn::Error::new(
+                        ident.span(),
+                        "`context_fn` may not be used on unit variants",
+                    )),
+                    None => variants.push((ident, None, None)),
                 }
             }
             if let Some(inner_error) = inner_error {
@@ -83,6 +97,7 @@ pub fn check(input: model::Input) -> syn::Result<model::Validate> {
         context,
         is_transparent: transparent.is_some(),
         kind,
+        ignored_variant_attrs,
         options,
     })
 }
@@ -131,6 +146,114 @@ fn get_context(attrs: &[(Span, model::Attr)]) -> syn::Result<(syn::Type, syn::Id
     }
 }
 
+/// `Validate::Context` is an associated type, so any lifetime it uses must be
+/// constrained by the type itself. An elided lifetime, such as in `Context<'_>` or
+/// `&Context`, is replaced by the lifetime parameter of the type, if it has exactly one.
+/// Any other lifetime is reported up front instead of letting rustc fail with an
+/// unconstrained or undeclared lifetime in the generated impl.
+fn check_context_lifetimes(ty: &mut syn::Type, generics: &syn::Generics) -> syn::Result<()> {
+    use syn::visit_mut::{self, VisitMut};
+
+    struct Lifetimes<'a> {
+        generics: &'a syn::Generics,
+        /// Lifetimes introduced by `for<...>`.
+        bound: Vec<syn::Lifetime>,
+        /// Elided lifetimes in the arguments of `Fn(&str)` or `fn(&str)` are
+        /// not part of the context type, so they are left as they are.
+        in_fn: usize,
+        error: Option<syn::Error>,
+    }
+
+    impl Lifetimes<'_> {
+        fn elided(&mut self, span: Span) -> Option<syn::Lifetime> {
+            let mut params = self.generics.lifetimes();
+            match (params.next(), params.next()) {
+                (Some(param), None) => Some(param.lifetime.clone()),
+                _ => {
+                    self.error.maybe_fold(syn::Error::new(
+                        span,
+                        "elided context lifetime requires the type to have exactly one lifetime parameter, e.g. `struct User<'a>` with `#[garde(context(Context<'_>))]`",
+                    ));
+                    None
+                }
+            }
+        }
+    }
+
+    impl VisitMut for Lifetimes<'_> {
+        fn visit_lifetime_mut(&mut self, lifetime: &mut syn::Lifetime) {
+            if lifetime.ident == "_" {
+                if self.in_fn == 0 {
+                    if let Some(param) = self.elided(lifetime.span()) {
+                        *lifetime = syn::Lifetime::new(&param.to_string(), lifetime.span());
+                    }
+                }
+                return;
+            }
+            if lifetime.ident == "static"
+                || self.bound.contains(lifetime)
+                || self
+                    .generics
+                    .lifetimes()
+                    .any(|param| param.lifetime.ident == lifetime.ident)
+            {
+                return;
+            }
+            self.error.maybe_fold(syn::Error::new(
+                lifetime.span(),
+                format!(
+                    "context lifetime `{lifetime}` must be a lifetime parameter of the type, e.g. `struct User<'a>` with `#[garde(context(Context<'a>))]`"
+                ),
+            ));
+        }
+
+        fn visit_type_reference_mut(&mut self, ty: &mut syn::TypeReference) {
+            if ty.lifetime.is_none() && self.in_fn == 0 {
+                ty.lifetime = self
+                    .elided(ty.and_token.span)
+                    .map(|param| syn::Lifetime::new(&param.to_string(), ty.and_token.span));
+            }
+            visit_mut::visit_type_reference_mut(self, ty);
+        }
+
+        fn visit_bound_lifetimes_mut(&mut self, bound: &mut syn::BoundLifetimes) {
+            self.bound
+                .extend(bound.lifetimes.iter().filter_map(|param| match param {
+                    syn::GenericParam::Lifetime(param) => Some(param.lifetime.clone()),
+                    _ => None,
+                }));
+        }
+
+        fn visit_parenthesized_generic_arguments_mut(
+            &mut self,
+            args: &mut syn::ParenthesizedGenericArguments,
+        ) {
+            self.in_fn += 1;
+            visit_mut::visit_parenthesized_generic_arguments_mut(self, args);
+            self.in_fn -= 1;
+        }
+
+        fn visit_type_bare_fn_mut(&mut self, ty: &mut syn::TypeBareFn) {
+            self.in_fn += 1;
+            visit_mut::visit_type_bare_fn_mut(self, ty);
+            self.in_fn -= 1;
+        }
+    }
+
+    let mut visitor = Lifetimes {
+        generics,
+        bound: Vec::new(),
+        in_fn: 0,
+        error: None,
+    };
+    visitor.visit_type_mut(ty);
+
+    match visitor.error {
+        Some(error) => Err(error),
+        None => Ok(()),
+    }
+}
+
 fn get_transparent_attr(attrs: &[(Span, model::Attr)]) -> Option<Span> {
     for (span, attr) in attrs {
         if let model::Attr::Transparent = attr {
@@ -141,6 +264,25 @@ fn get_transparent_attr(attrs: &[(Span, model::Attr)]) -> Option<Span> {
     None
 }
 
+#[cfg(feature = "async")]
+fn check_async_attr(_: &[(Span, model::Attr)]) -> syn::Result<()> {
+    Ok(())
+}
+
+#[cfg(not(feature = "async"))]
+fn check_async_attr(attrs: &[(Span, model::Attr)]) -> syn::Result<()> {
+    for (span, attr) in attrs {
+        if let model::Attr::Async = attr {
+            return Err(syn::Error::new(
+                *span,
+                "async feature must be enabled to use `async`",
+            ));
+        }
+    }
+
+    Ok(())
+}
+
 fn is_unary_struct(k: &model::ValidateKind) -> bool {
     match k {
         model::ValidateKind::Struct(model::ValidateVariant::Tuple(fields)) => {
@@ -160,12 +302,18 @@ fn is_unary_struct(k: &model::ValidateKind) -> bool {
 fn get_options(attrs: &[(Span, model::Attr)]) -> model::Options {
     let mut options = model::Options {
         allow_unvalidated: false,
+        rename_all: None,
+        is_async: false,
+        bound: None,
     };
 
     for (_, attr) in attrs {
         match attr {
             model::Attr::Context(..) => {}
             model::Attr::AllowUnvalidated => options.allow_unvalidated = true,
+            model::Attr::RenameAll(rule) => options.rename_all = Some(*rule),
+            model::Attr::Async => options.is_async = true,
+            model::Attr::Bound(predicates) => options.bound = Some(predicates.clone()),
             _ => {}
         }
     }
@@ -183,13 +331,16 @@ fn check_variant(
         model::Variant::Struct(map) => {
             let mut fields = Vec::new();
             for (ident, field) in map {
-                let field = match check_field(field, options) {
+                let mut field = match check_field(field, options) {
                     Ok(v) => v,
                     Err(e) => {
                         error.maybe_fold(e);
                         continue;
                     }
                 };
+                if let (None, Some(rule)) = (&field.alias, options.rename_all) {
+                    field.alias = Some(rename(rule, &ident.unraw().to_string()));
+                }
                 fields.push((ident, field))
             }
             model::ValidateVariant::Struct(fields)
@@ -217,6 +368,32 @@ fn check_variant(
     Ok(variant)
 }
 
+fn rename(rule: model::RenameRule, name: &str) -> String {
+    let words = name.split('_').filter(|word| !word.is_empty());
+    let capitalize = |word: &str| {
+        let mut chars = word.chars();
+        match chars.next() {
+            Some(first) => first.to_uppercase().chain(chars).collect::<String>(),
+            None => String::new(),
+        }
+    };
+    match rule {
+        model::RenameRule::Lower => name.to_lowercase(),
+        model::RenameRule::Snake => name.to_string(),
+        model::RenameRule::Upper | model::RenameRule::ScreamingSnake => name.to_uppercase(),
+        model::RenameRule::Pascal => words.map(capitalize).collect(),
+        model::RenameRule::Camel => words
+            .enumerate()
+            .map(|(i, word)| match i {
+                0 => word.to_string(),
+                _ => capitalize(word),
+            })
+            .collect(),
+        model::RenameRule::Kebab => name.replace('_', "-"),
+        model::RenameRule::ScreamingKebab => name.replace('_', "-").to_uppercase(),
+    }
+}
+
 fn check_field(field: model::Field, options: &model::Options) -> syn::Result<model::ValidateField> {
     let mut error = None;
 
@@ -229,10 +406,16 @@ fn check_field(field: model::Field, options: &model::Options) -> syn::Result<mod
         ty,
         adapter: None,
         skip: None,
+        skip_if: None,
+        groups: None,
         alias: None,
-        // message: None,
+        message: None,
         code: None,
+        label: None,
+        normalize: None,
         dive: None,
+        dive_context: None,
+        flatten: None,
         rule_set: model::RuleSet::empty(),
     };
 
@@ -256,7 +439,7 @@ fn check_field(field: model::Field, options: &model::Options) -> syn::Result<mod
     };
 
     if let Some(span) = field.skip {
-        if !field.is_empty() {
+        if !field.is_empty() || field.skip_if.is_some() || field.groups.is_some() {
             error.maybe_fold(syn::Error::new(
                 span,
                 "`skip` may not be combined with other rules",
@@ -264,6 +447,29 @@ fn check_field(field: model::Field, options: &model::Options) -> syn::Result<mod
         }
     }
 
+    if let Some(condition) = &field.skip_if {
+        if field.skip.is_none() && field.is_empty() {
+            error.maybe_fold(syn::Error::new(
+                condition.span(),
+                "`skip_if` requires at least one other rule",
+            ))
+        }
+    }
+
+    if field.groups.is_some() && field.skip.is_none() && field.is_empty() {
+        error.maybe_fold(syn::Error::new(
+            field.ty.span(),
+            "`group` requires at least one other rule",
+        ))
+    }
+
+    if field.normalize.is_some() && !field.has_top_level_rules() {
+        error.maybe_fold(syn::Error::new(
+            field.ty.span(),
+            "`normalize` requires at least one rule which is not in `inner` or `dive`",
+        ))
+    }
+
     if let Some(span) = field.dive {
         if field.rule_set.inner.is_some() {
             error.maybe_fold(syn::Error::new(
@@ -273,6 +479,21 @@ fn check_field(field: model::Field, options: &model::Options) -> syn::Result<mod
         }
     }
 
+    if let Some(span) = field.flatten {
+        if field.dive.is_none() {
+            error.maybe_fold(syn::Error::new(
+                span,
+                "`flatten` requires `dive` or `dive_with`",
+            ))
+        }
+    }
+
+    if let Some(message) = &field.message {
+        if let Err(e) = check_message_bounds(message, &field.rule_set) {
+            error.maybe_fold(e);
+        }
+    }
+
     if let Some(error) = error {
         return Err(error);
     }
@@ -280,6 +501,111 @@ fn check_field(field: model::Field, options: &model::Options) -> syn::Result<mod
     Ok(field)
 }
 
+fn check_message(message: model::Message) -> syn::Result<model::ValidateMessage> {
+    match message {
+        model::Message::Fmt(fmt) => {
+            let placeholders = parse_placeholders(&fmt)?;
+            Ok(model::ValidateMessage::Fmt(fmt, placeholders))
+        }
+        model::Message::Func(func) => Ok(model::ValidateMessage::Func(func)),
+    }
+}
+
+const PLACEHOLDERS: &[&str] = &["value", "error", "min", "max", "equal"];
+
+fn parse_placeholders(fmt: &model::Str) -> syn::Result<Vec<String>> {
+    let mut placeholders = BTreeSet::new();
+    let mut chars = fmt.value.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+            }
+            '{' => {
+                let mut placeholder = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => placeholder.push(c),
+                        None => return Err(syn::Error::new(fmt.span, "unclosed `{` in message")),
+                    }
+                }
+                let name = match placeholder.split_once(':') {
+                    Some((name, _)) => name,
+                    None => &placeholder,
+                };
+                if !PLACEHOLDERS.contains(&name) {
+                    return Err(syn::Error::new(
+                        fmt.span,
+                        format!(
+                            "unknown placeholder `{{{name}}}`, expected one of {}",
+                            PLACEHOLDERS
+                                .iter()
+                                .map(|p| format!("`{{{p}}}`"))
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        ),
+                    ));
+                }
+                placeholders.insert(name.to_string());
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+            }
+            '}' => return Err(syn::Error::new(fmt.span, "unmatched `}` in message")),
+            _ => {}
+        }
+    }
+    Ok(placeholders.into_iter().collect())
+}
+
+fn check_message_bounds(
+    message: &model::ValidateMessage,
+    rule_set: &model::RuleSet,
+) -> syn::Result<()> {
+    let (span, placeholders) = match message {
+        model::ValidateMessage::Fmt(fmt, placeholders) => (fmt.span, placeholders),
+        model::ValidateMessage::Func(func) => (func.span(), &Vec::new()),
+    };
+
+    if rule_set.is_empty() {
+        return Err(syn::Error::new(
+            span,
+            "`message` requires at least one rule on the same field",
+        ));
+    }
+
+    let bounds = rule_set.bounds();
+    for name in placeholders
+        .iter()
+        .filter(|name| matches!(name.as_str(), "min" | "max" | "equal"))
+    {
+        match bounds.as_slice() {
+            [bounds] if bounds.get(name).is_some() => {}
+            [_] => {
+                return Err(syn::Error::new(
+                    span,
+                    format!("placeholder `{{{name}}}` is not set by the `length` or `range` rule"),
+                ))
+            }
+            [] => {
+                return Err(syn::Error::new(
+                    span,
+                    format!("placeholder `{{{name}}}` requires a `length` or `range` rule"),
+                ))
+            }
+            _ => {
+                return Err(syn::Error::new(
+                    span,
+                    format!("placeholder `{{{name}}}` is ambiguous, field has more than one `length` or `range` rule"),
+                ))
+            }
+        }
+    }
+
+    Ok(())
+}
+
 fn check_rules(
     field: &mut model::ValidateField,
     raw_rules: Vec<model::RawRule>,
@@ -287,7 +613,7 @@ fn check_rules(
     let mut error = None;
     let mut rule_set = model::RuleSet::empty();
     for raw_rule in raw_rules {
-        if let Err(e) = check_rule(field, raw_rule, &mut rule_set, false) {
+        if let Err(e) = check_rule(field, raw_rule, &mut rule_set, None) {
             error.maybe_fold(e);
         };
     }
@@ -301,14 +627,17 @@ fn check_rule(
     field: &mut model::ValidateField,
     raw_rule: model::RawRule,
     rule_set: &mut model::RuleSet,
-    is_inner: bool,
+    scope: Option<&'static str>,
 ) -> syn::Result<()> {
+    let model::RawRule { span, kind, code } = raw_rule;
+    let mut code = code;
+
     macro_rules! apply {
         ($name:ident = $value:expr, $span:expr) => {{
-            if is_inner {
+            if let Some(scope) = scope {
                 return Err(syn::Error::new(
                     $span,
-                    concat!("rule `", stringify!($name), "` may not be used in `inner`")
+                    format!(concat!("rule `", stringify!($name), "` may not be used in `{}`"), scope),
                 ));
             }
             match field.$name {
@@ -328,29 +657,129 @@ fn check_rule(
             if !rule_set.rules.insert(rule) {
                 return Err(syn::Error::new($span, format!("duplicate rule `{name}`")));
             }
+            if let Some(code) = code.take() {
+                rule_set.codes.insert(name, code.value);
+            }
         }};
     }
 
-    let span = raw_rule.span;
     use model::RawRuleKind::*;
-    match raw_rule.kind {
+    match kind {
         Skip => apply!(skip = span, span),
+        SkipIf(condition) => apply!(skip_if = condition, span),
+        Group(groups) => {
+            if groups.contents.is_empty() {
+                return Err(syn::Error::new(
+                    span,
+                    "`group` requires at least one group name",
+                ));
+            }
+            apply!(
+                groups = groups.contents.into_iter().map(|v| v.value).collect(),
+                span
+            )
+        }
         Adapt(path) => apply!(adapter = path, span),
         Rename(alias) => apply!(alias = alias.value, span),
-        // Message(message) => apply!(message = message, span),
+        Message(message) => apply!(message = check_message(message)?, span),
         Code(code) => apply!(code = code.value, span),
+        Label(label) => apply!(label = label.value, span),
+        Normalize(normalize) => apply!(normalize = normalize, span),
         Dive => apply!(dive = span, span),
+        DiveWith(context) => {
+            apply!(dive = span, span);
+            field.dive_context = Some(context);
+        }
+        Flatten => apply!(flatten = span, span),
         Custom(custom) => rule_set.custom_rules.push(custom),
+        Rule(rule) => rule_set.rule_values.push(rule),
+        AsyncCustom(custom) => {
+            if let Some(scope) = scope {
+                return Err(syn::Error::new(
+                    span,
+                    format!("rule `async_custom` may not be used in `{scope}`"),
+                ));
+            }
+            rule_set
+                .async_custom_rules
+                .push(check_async_custom(custom)?)
+        }
         Required => apply!(Required(), span),
+        NonDefault => apply!(NonDefault(), span),
         Ascii => apply!(Ascii(), span),
-        Alphanumeric => apply!(Alphanumeric(), span),
-        Email => apply!(Email(), span),
-        Url => apply!(Url(), span),
+        Alphanumeric(v) => apply!(Alphanumeric(v.is_some_and(|v| v.ascii)), span),
+        Alphabetic(v) => apply!(Alphabetic(v.is_some_and(|v| v.ascii)), span),
+        Lowercase => apply!(Lowercase(), span),
+        Uppercase => apply!(Uppercase(), span),
+        NoWhitespace => apply!(NoWhitespace(), span),
+        NoLeadingWhitespace => apply!(NoLeadingWhitespace(), span),
+        NoTrailingWhitespace => apply!(NoTrailingWhitespace(), span),
+        Hex => apply!(Hex(), span),
+        Email(v) => {
+            apply!(Email(), span);
+            if let Some(v) = v {
+                check_email_mx(v, rule_set, scope)?;
+            }
+        }
+        Url(v) => {
+            let url = v.map(|v| model::Url {
+                schemes: v.schemes.into_iter().map(|v| v.value).collect(),
+                require_https: v.require_https,
+                require_tld: v.require_tld,
+            });
+            apply!(Url(url.unwrap_or_default()), span)
+        }
+        Domain(v) => apply!(Domain(v.is_some_and(|v| v.require_tld)), span),
+        Slug(v) => apply!(Slug(v.unwrap_or_default()), span),
+        MimeType(v) => {
+            let allowed = v.map(|v| v.allowed.into_iter().map(|v| v.value).collect());
+            apply!(MimeType(allowed.unwrap_or_default()), span)
+        }
         Ip => apply!(Ip(), span),
         IpV4 => apply!(IpV4(), span),
         IpV6 => apply!(IpV6(), span),
-        CreditCard => apply!(CreditCard(), span),
-        PhoneNumber => apply!(PhoneNumber(), span),
+        #[cfg(feature = "ipnet")]
+        IpInSubnet(v) => apply!(IpInSubnet(check_subnet(v)?), span),
+        #[cfg(not(feature = "ipnet"))]
+        IpInSubnet(v) => {
+            return Err(syn::Error::new(
+                v.span,
+                "ipnet feature must be enabled to use `ip_in_subnet`",
+            ))
+        }
+        Cidr(v) => apply!(Cidr(v.map(|v| v.version).unwrap_or_default()), span),
+        MacAddress(v) => apply!(MacAddress(v.map(|v| v.format).unwrap_or_default()), span),
+        JsonString(v) => apply!(JsonString(v.and_then(|v| v.schema_type)), span),
+        CreditCard(v) => apply!(CreditCard(v.map(|v| v.networks).unwrap_or_default()), span),
+        Luhn => apply!(Luhn(), span),
+        PhoneNumber(v) => apply!(PhoneNumber(v.and_then(|v| v.region)), span),
+        Uuid => apply!(Uuid(), span),
+        Iban(v) => apply!(Iban(v.and_then(|v| v.country)), span),
+        Isbn(v) => apply!(Isbn(v.map(|v| v.format).unwrap_or_default()), span),
+        CountryCode(v) => apply!(CountryCode(v.unwrap_or_default()), span),
+        Semver(v) => apply!(Semver(check_semver_req(v.and_then(|v| v.req))?), span),
+        Date(v) => apply!(
+            Date(check_datetime(model::DateTimeKind::Date, v, span)?),
+            span
+        ),
+        Time(v) => apply!(
+            Time(check_datetime(model::DateTimeKind::Time, v, span)?),
+            span
+        ),
+        DateTime(v) => apply!(
+            DateTime(check_datetime(model::DateTimeKind::DateTime, v, span)?),
+            span
+        ),
+        Password(v) => apply!(Password(v), span),
+        NotCommonPassword(v) => apply!(
+            NotCommonPassword(v.is_some_and(|v| v.case_insensitive)),
+            span
+        ),
+        Unique => apply!(Unique(), span),
+        UniqueBy(v) => apply!(UniqueBy(v), span),
+        Sorted(v) => apply!(Sorted(v.unwrap_or_default()), span),
+        Numeric(v) => apply!(Numeric(v), span),
+        Base64(v) => apply!(Base64(v.unwrap_or_default()), span),
         Length(v) => {
             let range = check_range_generic(v.range)?;
             match v.mode {
@@ -361,21 +790,145 @@ fn check_rule(
                 LengthMode::Utf16 => apply!(LengthUtf16(range), span),
             }
         }
+        ByteLength(v) => apply!(LengthBytes(check_range_generic(v)?), span),
         Matches(path) => apply!(Matches(path), span),
-        Range(v) => apply!(Range(check_range_not_ord(v)?), span),
+        Range(v) => apply!(Range(check_range_bounds(v)?), span),
+        Port(v) => apply!(Port(v.is_some_and(|v| v.unprivileged)), span),
+        Timezone(v) => apply!(Timezone(v.is_some_and(|v| v.utc_offset_only)), span),
+        Positive => apply!(Positive(), span),
+        NonNegative => apply!(NonNegative(), span),
+        Negative => apply!(Negative(), span),
+        NonPositive => apply!(NonPositive(), span),
+        MultipleOf(v) => apply!(MultipleOf(v), span),
+        Precision(v) => apply!(Precision(v.max_decimal_places), span),
+        Finite => apply!(Finite(), span),
+        Latitude => apply!(Latitude(), span),
+        Longitude => apply!(Longitude(), span),
+        CoordinatePair => apply!(CoordinatePair(), span),
+        OneOf(v) => apply!(OneOf(v), span),
+        NotOneOf(v) => apply!(NotOneOf(v), span),
         Contains(v) => apply!(Contains(v), span),
+        NotContains(v) => apply!(NotContains(v), span),
+        ContainsAny(v) => apply!(ContainsAny(v), span),
+        ContainsAll(v) => apply!(ContainsAll(v), span),
         Prefix(v) => apply!(Prefix(v), span),
+        NotPrefix(v) => apply!(NotPrefix(v), span),
         Suffix(v) => apply!(Suffix(v), span),
+        NotSuffix(v) => apply!(NotSuffix(v), span),
         Pattern(v) => apply!(Pattern(check_regex(v)?), span),
         Inner(v) => {
+            if let Some(scope @ ("when" | "warn")) = scope {
+                return Err(syn::Error::new(
+                    span,
+                    format!("rule `inner` may not be used in `{scope}`"),
+                ));
+            }
             if rule_set.inner.is_none() {
                 rule_set.inner = Some(Box::new(model::RuleSet::empty()));
             }
 
             let mut error = None;
             for raw_rule in v.contents {
-                if let Err(e) = check_rule(field, raw_rule, rule_set.inner.as_mut().unwrap(), true)
-                {
+                if let Err(e) = check_rule(
+                    field,
+                    raw_rule,
+                    rule_set.inner.as_mut().unwrap(),
+                    Some("inner"),
+                ) {
+                    error.maybe_fold(e);
+                }
+            }
+            if let Some(error) = error {
+                return Err(error);
+            }
+
+            let inner = rule_set.inner.as_ref().unwrap();
+            if inner.has_map_rules() && (inner.has_top_level_rules() || inner.inner.is_some()) {
+                return Err(syn::Error::new(
+                    span,
+                    "`key` and `value` may not be combined with other rules in `inner`",
+                ));
+            }
+        }
+        Not(v) => {
+            let mut negated = model::RuleSet::empty();
+            check_rule(field, *v, &mut negated, Some("not"))?;
+            let rule = match negated.rules.pop_first() {
+                Some(rule) if negated.is_empty() => rule,
+                _ => {
+                    return Err(syn::Error::new(
+                        span,
+                        "`not` only supports a single built-in rule",
+                    ))
+                }
+            };
+            if let model::ValidateRule::NotCommonPassword(_) = rule {
+                return Err(syn::Error::new(
+                    span,
+                    "rule `not_common_password` may not be negated",
+                ));
+            }
+            if rule.negated_message().is_none() {
+                let name = rule.name().replace("::not", "");
+                return Err(syn::Error::new(
+                    span,
+                    format!("rule `not_{name}` may not be negated, use `{name}` instead"),
+                ));
+            }
+            let name = rule.name();
+            if !rule_set.negated.insert(rule) {
+                return Err(syn::Error::new(
+                    span,
+                    format!("duplicate rule `not({name})`"),
+                ));
+            }
+            if let Some(code) = code.take().map(|code| code.value) {
+                rule_set.negated_codes.insert(name, code);
+            } else if let Some(code) = negated.codes.remove(name) {
+                rule_set.negated_codes.insert(name, code);
+            }
+        }
+        Key(v) => check_map_rule(field, "key", v, &mut rule_set.key, scope, span)?,
+        Value(v) => check_map_rule(field, "value", v, &mut rule_set.value, scope, span)?,
+        When(when) => {
+            if let Some(scope) = scope {
+                return Err(syn::Error::new(
+                    span,
+                    format!("rule `when` may not be used in `{scope}`"),
+                ));
+            }
+
+            let mut conditional = model::RuleSet::empty();
+            let mut error = None;
+            for raw_rule in when.rules {
+                if let Err(e) = check_rule(field, raw_rule, &mut conditional, Some("when")) {
+                    error.maybe_fold(e);
+                }
+            }
+            if let Some(error) = error {
+                return Err(error);
+            }
+            rule_set.conditional.push((when.condition, conditional));
+        }
+        Warn(v) => {
+            if let Some(scope @ ("when" | "not" | "warn")) = scope {
+                return Err(syn::Error::new(
+                    span,
+                    format!("rule `warn` may not be used in `{scope}`"),
+                ));
+            }
+            if rule_set.warnings.is_none() {
+                rule_set.warnings = Some(Box::new(model::RuleSet::empty()));
+            }
+
+            let mut error = None;
+            for raw_rule in v.contents {
+                if let Err(e) = check_rule(
+                    field,
+                    raw_rule,
+                    rule_set.warnings.as_mut().unwrap(),
+                    Some("warn"),
+                ) {
                     error.maybe_fold(e);
                 }
             }
@@ -385,9 +938,47 @@ fn check_rule(
         }
     };
 
+    if let Some(code) = code {
+        return Err(syn::Error::new(
+            code.span,
+            "`code` may only be used in built-in rules",
+        ));
+    }
+
     Ok(())
 }
 
+fn check_map_rule(
+    field: &mut model::ValidateField,
+    name: &'static str,
+    raw_rules: model::List<model::RawRule>,
+    set: &mut Option<Box<model::RuleSet>>,
+    scope: Option<&'static str>,
+    span: Span,
+) -> syn::Result<()> {
+    if scope != Some("inner") {
+        return Err(syn::Error::new(
+            span,
+            format!("rule `{name}` may only be used in `inner`"),
+        ));
+    }
+    if set.is_some() {
+        return Err(syn::Error::new(span, format!("duplicate rule `{name}`")));
+    }
+    let set = set.insert(Box::new(model::RuleSet::empty()));
+
+    let mut error = None;
+    for raw_rule in raw_rules.contents {
+        if let Err(e) = check_rule(field, raw_rule, set, Some(name)) {
+            error.maybe_fold(e);
+        }
+    }
+    match error {
+        Some(error) => Err(error),
+        None => Ok(()),
+    }
+}
+
 fn check_range_generic<L, R>(
     range: model::Range<model::Either<L, R>>,
 ) -> syn::Result<model::ValidateRange<model::Either<L, R>>>
@@ -405,10 +996,12 @@ where
                     model::ValidateRange::Between($wrapper(v1), $wrapper(v2))
                 }
                 model::ValidateRange::Equal(v) => model::ValidateRange::Equal($wrapper(v)),
+                model::ValidateRange::Exact(v) => model::ValidateRange::Exact($wrapper(v)),
             }
         }};
     }
 
+    let exact = range.exact;
     let range = match (range.span, range.min, range.max, range.equal) {
         (span, Some(model::Either::Left(min)), Some(model::Either::Left(max)), None) => {
             map_validate_range!(
@@ -417,6 +1010,7 @@ where
                     min: Some(min),
                     max: Some(max),
                     equal: None,
+                    exact: false,
                 })?,
                 model::Either::Left
             )
@@ -428,6 +1022,7 @@ where
                     min: Some(min),
                     max: None,
                     equal: None,
+                    exact: false,
                 })?,
                 model::Either::Left
             )
@@ -439,6 +1034,7 @@ where
                     min: None,
                     max: Some(max),
                     equal: None,
+                    exact: false,
                 })?,
                 model::Either::Left
             )
@@ -450,6 +1046,7 @@ where
                     min: None,
                     max: None,
                     equal: Some(equal),
+                    exact: false,
                 })?,
                 model::Either::Left
             )
@@ -459,10 +1056,14 @@ where
             min,
             max,
             equal,
+            exact: false,
         })?,
     };
 
-    Ok(range)
+    match range {
+        model::ValidateRange::Equal(v) if exact => Ok(model::ValidateRange::Exact(v)),
+        range => Ok(range),
+    }
 }
 
 fn check_range<T>(range: model::Range<T>) -> syn::Result<model::ValidateRange<T>>
@@ -518,6 +1119,217 @@ fn check_range_not_ord<T>(range: model::Range<T>) -> syn::Result<model::Validate
     }
 }
 
+fn check_range_bounds(
+    raw: Box<model::RawRange>,
+) -> syn::Result<model::ValidateRange<model::RangeBound>> {
+    let model::RawRange {
+        range,
+        exclusive_min,
+        exclusive_max,
+    } = *raw;
+    let bound = |value, exclusive| model::RangeBound { value, exclusive };
+    let pick = |inclusive: Option<syn::Expr>, exclusive: Option<syn::Expr>, name: &str| match (
+        inclusive, exclusive,
+    ) {
+        (Some(_), Some(_)) => Err(syn::Error::new(
+            range.span,
+            format!("`{name}` and `exclusive_{name}` may not be used together"),
+        )),
+        (Some(v), None) => Ok(Some(bound(v, false))),
+        (None, Some(v)) => Ok(Some(bound(v, true))),
+        (None, None) => Ok(None),
+    };
+    let min = pick(range.min, exclusive_min, "min")?;
+    let max = pick(range.max, exclusive_max, "max")?;
+    check_range_not_ord(model::Range {
+        span: range.span,
+        min,
+        max,
+        equal: range.equal.map(|v| bound(v, false)),
+        exact: false,
+    })
+}
+
+fn check_async_custom(custom: syn::Expr) -> syn::Result<syn::Expr> {
+    #[cfg(feature = "async")]
+    {
+        Ok(custom)
+    }
+    #[cfg(not(feature = "async"))]
+    Err(syn::Error::new(
+        custom.span(),
+        "async feature must be enabled to use `async_custom`",
+    ))
+}
+
+fn check_email_mx(
+    email: model::RawEmail,
+    rule_set: &mut model::RuleSet,
+    scope: Option<&'static str>,
+) -> syn::Result<()> {
+    let Some(span) = email.check_mx else {
+        return Ok(());
+    };
+    if let Some(scope) = scope {
+        return Err(syn::Error::new(
+            span,
+            format!("`check_mx` may not be used in `{scope}`"),
+        ));
+    }
+    rule_set.async_custom_rules.push(check_mx_rule(span)?);
+    Ok(())
+}
+
+fn check_mx_rule(span: Span) -> syn::Result<syn::Expr> {
+    #[cfg(all(feature = "email-dns", feature = "async"))]
+    {
+        Ok(syn::parse_quote_spanned!(span=> ::garde::rules::email::mx::check_mx))
+    }
+    #[cfg(all(feature = "email-dns", not(feature = "async")))]
+    {
+        Err(syn::Error::new(
+            span,
+            "async feature must be enabled to use `check_mx`",
+        ))
+    }
+    #[cfg(not(feature = "email-dns"))]
+    {
+        Err(syn::Error::new(
+            span,
+            "email-dns feature must be enabled to use `check_mx`",
+        ))
+    }
+}
+
+#[cfg(feature = "ipnet")]
+fn check_subnet(value: model::Str) -> syn::Result<model::Subnet> {
+    match value.value.parse::<ipnet::IpNet>() {
+        Ok(ipnet::IpNet::V4(net)) => Ok(model::Subnet::V4(net.addr().octets(), net.prefix_len())),
+        Ok(ipnet::IpNet::V6(net)) => Ok(model::Subnet::V6(net.addr().segments(), net.prefix_len())),
+        Err(e) => Err(syn::Error::new(value.span, format!("invalid subnet: {e}"))),
+    }
+}
+
+#[cfg_attr(not(feature = "date"), allow(unused_variables))]
+fn check_datetime(
+    kind: model::DateTimeKind,
+    value: Option<model::RawDateTime>,
+    span: Span,
+) -> syn::Result<model::DateTime> {
+    #[cfg(feature = "date")]
+    {
+        let Some(value) = value else {
+            return Ok(model::DateTime {
+                format: None,
+                after: None,
+                before: None,
+            });
+        };
+
+        if let Some(format) = &value.format {
+            if chrono::format::StrftimeItems::new(&format.value)
+                .any(|item| item == chrono::format::Item::Error)
+            {
+                return Err(syn::Error::new(format.span, "invalid format string"));
+            }
+        }
+
+        // bounds are parsed with the same format as the value
+        let format = value.format.as_ref().map(|v| v.value.as_str());
+        let parse_bound = |bound: &Option<model::Str>| -> syn::Result<_> {
+            let Some(bound) = bound else {
+                return Ok(None);
+            };
+            let parsed = match kind {
+                model::DateTimeKind::Date => {
+                    chrono::NaiveDate::parse_from_str(&bound.value, format.unwrap_or("%Y-%m-%d"))
+                        .map(|v| v.and_time(chrono::NaiveTime::MIN))
+                }
+                model::DateTimeKind::Time => {
+                    chrono::NaiveTime::parse_from_str(&bound.value, format.unwrap_or("%H:%M:%S"))
+                        .map(|v| chrono::NaiveDate::default().and_time(v))
+                }
+                model::DateTimeKind::DateTime => parse_datetime(&bound.value, format),
+            };
+            match parsed {
+                Ok(v) => Ok(Some(v)),
+                Err(e) => Err(syn::Error::new(
+                    bound.span,
+                    format!("invalid {}: {e}", kind.name()),
+                )),
+            }
+        };
+
+        let mut error = None;
+        let after = parse_bound(&value.after).unwrap_or_else(|e| {
+            error.maybe_fold(e);
+            None
+        });
+        let before = parse_bound(&value.before).unwrap_or_else(|e| {
+            error.maybe_fold(e);
+            None
+        });
+        if let Some(error) = error {
+            return Err(error);
+        }
+        if let (Some(after), Some(before)) = (after, before) {
+            if after >= before {
+                return Err(syn::Error::new(
+                    span,
+                    "`after` must be earlier than `before`",
+                ));
+            }
+        }
+
+        Ok(model::DateTime {
+            format: value.format.map(|v| v.value),
+            after: value.after.map(|v| v.value),
+            before: value.before.map(|v| v.value),
+        })
+    }
+    #[cfg(not(feature = "date"))]
+    Err(syn::Error::new(
+        span,
+        format!("date feature must be enabled to use `{}`", kind.name()),
+    ))
+}
+
+/// Parses a date and time in UTC, using RFC 3339 if there is no `format`.
+#[cfg(feature = "date")]
+fn parse_datetime(value: &str, format: Option<&str>) -> chrono::ParseResult<chrono::NaiveDateTime> {
+    let Some(format) = format else {
+        return chrono::DateTime::parse_from_rfc3339(value).map(|v| v.naive_utc());
+    };
+    let mut parsed = chrono::format::Parsed::new();
+    chrono::format::parse(
+        &mut parsed,
+        value,
+        chrono::format::StrftimeItems::new(format),
+    )?;
+    match parsed.offset() {
+        Some(_) => parsed.to_datetime().map(|v| v.naive_utc()),
+        None => parsed.to_naive_datetime_with_offset(0),
+    }
+}
+
+/// A string literal `req` is parsed up front, so that an invalid requirement is a compile error.
+fn check_semver_req(req: Option<syn::Expr>) -> syn::Result<Option<syn::Expr>> {
+    #[cfg(feature = "semver")]
+    if let Some(syn::Expr::Lit(syn::ExprLit {
+        lit: syn::Lit::Str(lit),
+        ..
+    })) = &req
+    {
+        if let Err(e) = semver::VersionReq::parse(&lit.value()) {
+            return Err(syn::Error::new(
+                lit.span(),
+                format!("invalid version requirement: {e}"),
+            ));
+        }
+    }
+    Ok(req)
+}
+
 fn check_regex(value: model::Pattern) -> syn::Result<model::ValidatePattern> {
     match value {
         model::Pattern::Lit(lit) => {
@@ -15,10 +15,39 @@ impl ToTokens for model::Validate {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
         let ident = &self.ident;
         let (context_ty, context_ident) = &self.context;
-        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let generics = with_dive_bounds(
+            &self.generics,
+            context_ty,
+            &self.kind,
+            self.options.bound.as_deref(),
+        );
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
         let ty = Type {
             is_transparent: self.is_transparent,
             kind: &self.kind,
+            context_ident,
+        };
+
+        let (group, validate_group_into) = match has_groups(&self.kind) {
+            true => (
+                Some(quote!(let __garde_group: ::std::option::Option<&str> = None;)),
+                Some(quote! {
+                    #[allow(clippy::needless_borrow)]
+                    fn validate_group_into(
+                        &self,
+                        __garde_group: &str,
+                        #context_ident: &Self::Context,
+                        mut __garde_path: &mut dyn FnMut() -> ::garde::Path,
+                        __garde_report: &mut ::garde::error::Report,
+                    ) {
+                        let __garde_user_ctx = &#context_ident;
+                        let __garde_group = Some(__garde_group);
+
+                        #ty
+                    }
+                }),
+            ),
+            false => (None, None),
         };
 
         quote! {
@@ -34,8 +63,397 @@ impl ToTokens for model::Validate {
                 ) {
                     let __garde_user_ctx = &#context_ident;
 
+                    #group
                     #ty
                 }
+
+                #validate_group_into
+            }
+        }
+        .to_tokens(tokens);
+
+        for span in self.ignored_variant_attrs.iter() {
+            ignored_variant_attr_warning(*span).to_tokens(tokens);
+        }
+
+        if self.options.is_async || has_async_rules(&self.kind) {
+            let ty = AsyncType {
+                is_transparent: self.is_transparent,
+                kind: &self.kind,
+                context_ident,
+            };
+
+            quote! {
+                #[::garde::external::async_trait::async_trait]
+                impl #impl_generics ::garde::validate::AsyncValidate for #ident #ty_generics #where_clause {
+                    #[allow(clippy::needless_borrow)]
+                    async fn validate_into_async(
+                        &self,
+                        #context_ident: &Self::Context,
+                        __garde_path: ::garde::Path,
+                        __garde_report: &mut ::garde::error::Report,
+                    ) {
+                        let __garde_user_ctx = &#context_ident;
+
+                        #ty
+                    }
+                }
+            }
+            .to_tokens(tokens)
+        }
+    }
+}
+
+fn has_groups(kind: &model::ValidateKind) -> bool {
+    fn variant_has_groups(variant: &model::ValidateVariant) -> bool {
+        match variant {
+            model::ValidateVariant::Struct(fields) => {
+                fields.iter().any(|(_, field)| field.groups.is_some())
+            }
+            model::ValidateVariant::Tuple(fields) => {
+                fields.iter().any(|field| field.groups.is_some())
+            }
+        }
+    }
+
+    match kind {
+        model::ValidateKind::Struct(variant) => variant_has_groups(variant),
+        model::ValidateKind::Enum(variants) => variants
+            .iter()
+            .filter_map(|(_, _, variant)| variant.as_ref())
+            .any(variant_has_groups),
+    }
+}
+
+/// Adds a `T: Validate<Context = C>` bound for every type parameter `T`
+/// which is used in the type of a field marked with `dive`.
+///
+/// Fields using `dive_with` and variants using `context_fn` are skipped, as their context is different from `C`.
+///
+/// If the type has a `bound` attribute, its predicates are added instead.
+fn with_dive_bounds(
+    generics: &syn::Generics,
+    context_ty: &syn::Type,
+    kind: &model::ValidateKind,
+    bound: Option<&[syn::WherePredicate]>,
+) -> syn::Generics {
+    if let Some(bound) = bound {
+        let mut generics = generics.clone();
+        generics
+            .make_where_clause()
+            .predicates
+            .extend(bound.iter().cloned());
+        return generics;
+    }
+
+    struct UsesParam<'a> {
+        param: &'a Ident,
+        found: bool,
+    }
+
+    impl<'ast> syn::visit::Visit<'ast> for UsesParam<'_> {
+        fn visit_type_path(&mut self, ty: &'ast syn::TypePath) {
+            if ty.qself.is_none() && ty.path.is_ident(self.param) {
+                self.found = true;
+            }
+            syn::visit::visit_type_path(self, ty);
+        }
+    }
+
+    fn dive_fields(variant: &model::ValidateVariant) -> Vec<&model::ValidateField> {
+        let fields: Vec<&model::ValidateField> = match variant {
+            model::ValidateVariant::Struct(fields) => fields.iter().map(|(_, f)| f).collect(),
+            model::ValidateVariant::Tuple(fields) => fields.iter().collect(),
+        };
+        fields
+            .into_iter()
+            .filter(|field| field.skip.is_none())
+            .filter(|field| field.dive.is_some() && field.dive_context.is_none())
+            .collect()
+    }
+
+    let fields = match kind {
+        model::ValidateKind::Struct(variant) => dive_fields(variant),
+        model::ValidateKind::Enum(variants) => variants
+            .iter()
+            .filter(|(_, context_fn, _)| context_fn.is_none())
+            .filter_map(|(_, _, variant)| variant.as_ref())
+            .flat_map(dive_fields)
+            .collect(),
+    };
+
+    let mut generics = generics.clone();
+    let params: Vec<Ident> = generics.type_params().map(|p| p.ident.clone()).collect();
+    for param in params {
+        let used = fields.iter().any(|field| {
+            let mut visitor = UsesParam {
+                param: &param,
+                found: false,
+            };
+            syn::visit::Visit::visit_type(&mut visitor, &field.ty);
+            visitor.found
+        });
+        if used {
+            generics
+                .make_where_clause()
+                .predicates
+                .push(syn::parse_quote!(#param: ::garde::Validate<Context = #context_ty>));
+        }
+    }
+    generics
+}
+
+fn has_async_rules(kind: &model::ValidateKind) -> bool {
+    fn variant_has_async_rules(variant: &model::ValidateVariant) -> bool {
+        match variant {
+            model::ValidateVariant::Struct(fields) => fields
+                .iter()
+                .any(|(_, field)| !field.rule_set.async_custom_rules.is_empty()),
+            model::ValidateVariant::Tuple(fields) => fields
+                .iter()
+                .any(|field| !field.rule_set.async_custom_rules.is_empty()),
+        }
+    }
+
+    match kind {
+        model::ValidateKind::Struct(variant) => variant_has_async_rules(variant),
+        model::ValidateKind::Enum(variants) => variants
+            .iter()
+            .filter_map(|(_, _, variant)| variant.as_ref())
+            .any(variant_has_async_rules),
+    }
+}
+
+/// Whether a field is validated by `AsyncValidate`, because it has async rules or is marked with `dive`.
+fn is_async_field(field: &model::ValidateField) -> bool {
+    field.skip.is_none() && (!field.rule_set.async_custom_rules.is_empty() || field.dive.is_some())
+}
+
+struct AsyncType<'a> {
+    is_transparent: bool,
+    kind: &'a model::ValidateKind,
+    context_ident: &'a Ident,
+}
+
+impl<'a> ToTokens for AsyncType<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let is_transparent = self.is_transparent;
+        match &self.kind {
+            model::ValidateKind::Struct(variant) => {
+                let bindings = AsyncBindings(variant);
+                let validation = AsyncVariant {
+                    is_transparent,
+                    variant,
+                };
+
+                quote! {{
+                    let Self #bindings = self;
+                    #validation
+                }}
+            }
+            model::ValidateKind::Enum(variants) => {
+                let variants = variants.iter().map(|(name, context_fn, variant)| {
+                    if let Some(variant) = variant {
+                        let bindings = AsyncBindings(variant);
+                        let validation = AsyncVariant {
+                            is_transparent,
+                            variant,
+                        };
+                        let context = context_fn
+                            .as_ref()
+                            .map(|context_fn| variant_context(self.context_ident, context_fn));
+
+                        quote!(Self::#name #bindings => { #context #validation })
+                    } else {
+                        quote!(Self::#name => {})
+                    }
+                });
+
+                quote! {{
+                    match self {
+                        #(#variants,)*
+                    }
+                }}
+            }
+        }
+        .to_tokens(tokens)
+    }
+}
+
+/// Proc macros can't emit warnings, so this uses a deprecated item at `span` instead.
+fn ignored_variant_attr_warning(span: proc_macro2::Span) -> TokenStream2 {
+    quote_spanned! {span=>
+        const _: () = {
+            #[deprecated(
+                note = "`#[garde(...)]` attributes on enum variants are ignored, except for `context_fn`; they will be rejected in a future release"
+            )]
+            #[allow(non_upper_case_globals)]
+            const garde_variant_attr: () = ();
+            garde_variant_attr
+        };
+    }
+}
+
+/// Rebinds the context to the one returned by the `context_fn` of a variant,
+/// so that every rule in the variant sees the same context.
+fn variant_context(context_ident: &Ident, context_fn: &syn::Expr) -> TokenStream2 {
+    quote! {
+        let #context_ident: &_ = ::garde::util::__dive_context(*__garde_user_ctx, #context_fn);
+        let __garde_user_ctx = &#context_ident;
+    }
+}
+
+struct AsyncVariant<'a> {
+    is_transparent: bool,
+    variant: &'a model::ValidateVariant,
+}
+
+impl<'a> ToTokens for AsyncVariant<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let fields: Vec<(Binding, &model::ValidateField, TokenStream2)> = match &self.variant {
+            model::ValidateVariant::Struct(fields) => fields
+                .iter()
+                .map(|(key, field)| {
+                    let name = field.alias.clone().unwrap_or_else(|| key.to_string());
+                    (Binding::Ident(key), field, quote!(#name))
+                })
+                .collect(),
+            model::ValidateVariant::Tuple(fields) => fields
+                .iter()
+                .enumerate()
+                .map(|(index, field)| {
+                    let key = match &field.alias {
+                        Some(alias) => quote!(#alias),
+                        None => quote!(#index),
+                    };
+                    (Binding::Index(index), field, key)
+                })
+                .collect(),
+        };
+
+        let fields = fields
+            .into_iter()
+            .filter(|(_, field, _)| is_async_field(field))
+            .map(|(binding, field, key)| {
+                let path = match self.is_transparent || field.flatten.is_some() {
+                    true => quote!(__garde_path.clone()),
+                    false => quote!(__garde_path.join(#key)),
+                };
+                let label = field.label.as_ref().map(|label| quote!(.with_label(#label)));
+                let dive = field.dive.as_ref().map(|_| {
+                    let (dive_ctx, ctx) = match &field.dive_context {
+                        Some(context) => (
+                            Some(quote! {
+                                let __garde_dive_ctx = ::garde::util::__dive_context(*__garde_user_ctx, #context);
+                            }),
+                            quote!(::std::borrow::Borrow::borrow(&__garde_dive_ctx)),
+                        ),
+                        None => (None, quote!(__garde_user_ctx)),
+                    };
+                    quote! {{
+                        #[allow(unused_imports)]
+                        use ::garde::util::{__AsyncDiveFallback as _, __AsyncDiveValidate as _};
+                        #dive_ctx
+                        if let Some(__garde_future) = (&::garde::util::__AsyncDive(__garde_binding))
+                            .__garde_validate_async(#ctx, #path, __garde_report)
+                        {
+                            __garde_future.await;
+                        }
+                    }}
+                });
+                let rules = field.rule_set.async_custom_rules.iter().map(|custom_rule| {
+                    let append = match field.message {
+                        Some(_) => quote! {
+                            if __garde_message_error.is_none() {
+                                __garde_message_error = Some(__garde_error #label);
+                            }
+                        },
+                        None => quote!(__garde_report.append(#path, __garde_error #label);),
+                    };
+                    quote! {
+                        if let Err(__garde_error) = (#custom_rule)(&*__garde_binding, &__garde_user_ctx).await {
+                            #append
+                        }
+                    }
+                });
+                // The sync rules of the field have already been validated,
+                // so the `message` is only reported if they did not fail.
+                let (message_error, message) = match &field.message {
+                    Some(message) if !field.rule_set.async_custom_rules.is_empty() => {
+                        let error = with_message(message, &field.rule_set);
+                        (
+                            Some(quote! {
+                                let mut __garde_message_error = None::<::garde::error::Error>;
+                            }),
+                            Some(quote! {
+                                if let Some(__garde_error) = __garde_message_error {
+                                    let __garde_binding = &*#binding;
+                                    let __garde_error_path = #path;
+                                    if !__garde_report.errors().any(|(__garde_path, _)| *__garde_path == __garde_error_path) {
+                                        __garde_report.append(__garde_error_path, #error);
+                                    }
+                                }
+                            }),
+                        )
+                    }
+                    _ => (None, None),
+                };
+
+                let normalize = field.normalize.as_ref().map(|normalize| quote! {
+                    let __garde_normalized = #normalize;
+                    let __garde_binding = &__garde_normalized;
+                });
+                let value = quote! {
+                    let __garde_binding = &*#binding;
+                    #message_error
+                    #normalize
+                    #dive
+                    #(#rules)*
+                    #message
+                };
+                match &field.skip_if {
+                    Some(condition) => quote! {
+                        if !::garde::util::__when(self, *__garde_user_ctx, #condition) {
+                            #value
+                        }
+                    },
+                    None => quote! {{ #value }},
+                }
+            });
+
+        quote! {{
+            #(#fields)*
+        }}
+        .to_tokens(tokens)
+    }
+}
+
+struct AsyncBindings<'a>(&'a model::ValidateVariant);
+
+impl<'a> ToTokens for AsyncBindings<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let is_bound = is_async_field;
+        match &self.0 {
+            model::ValidateVariant::Struct(fields) => {
+                let names = fields
+                    .iter()
+                    .filter(|(_, field)| is_bound(field))
+                    .map(|(name, _)| name);
+
+                quote!( { #(#names,)* .. } )
+            }
+            model::ValidateVariant::Tuple(fields) => {
+                let last = fields.iter().rposition(is_bound).map_or(0, |i| i + 1);
+                let indices =
+                    fields[..last]
+                        .iter()
+                        .enumerate()
+                        .map(|(i, field)| match is_bound(field) {
+                            true => IndexBinding(i).to_token_stream(),
+                            false => quote!(_),
+                        });
+
+                quote!( ( #(#indices,)* .. ) )
             }
         }
         .to_tokens(tokens)
@@ -45,6 +463,7 @@ impl ToTokens for model::Validate {
 struct Type<'a> {
     is_transparent: bool,
     kind: &'a model::ValidateKind,
+    context_ident: &'a Ident,
 }
 
 impl<'a> ToTokens for Type<'a> {
@@ -64,15 +483,18 @@ impl<'a> ToTokens for Type<'a> {
                 }}
             }
             model::ValidateKind::Enum(variants) => {
-                let variants = variants.iter().map(|(name, variant)| {
+                let variants = variants.iter().map(|(name, context_fn, variant)| {
                     if let Some(variant) = variant {
                         let bindings = Bindings(variant);
                         let validation = Variant {
                             is_transparent,
                             variant,
                         };
+                        let context = context_fn
+                            .as_ref()
+                            .map(|context_fn| variant_context(self.context_ident, context_fn));
 
-                        quote!(Self::#name #bindings => #validation)
+                        quote!(Self::#name #bindings => { #context #validation })
                     } else {
                         quote!(Self::#name => {})
                     }
@@ -125,10 +547,11 @@ struct Struct<'a> {
 impl<'a> ToTokens for Struct<'a> {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
         Fields::new(
-            self.fields
-                .iter()
-                .map(|(key, field)| (Binding::Ident(key), field, key.to_string())),
-            |key, value| match self.is_transparent {
+            self.fields.iter().map(|(key, field)| {
+                let name = field.alias.clone().unwrap_or_else(|| key.to_string());
+                (Binding::Ident(key), field, (name, field.flatten.is_some()))
+            }),
+            |(key, flatten), value| match self.is_transparent || flatten {
                 true => quote! {{
                     #value
                 }},
@@ -150,11 +573,14 @@ struct Tuple<'a> {
 impl<'a> ToTokens for Tuple<'a> {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
         Fields::new(
-            self.fields
-                .iter()
-                .enumerate()
-                .map(|(index, field)| (Binding::Index(index), field, index)),
-            |index, value| match self.is_transparent {
+            self.fields.iter().enumerate().map(|(index, field)| {
+                let key = match &field.alias {
+                    Some(alias) => quote!(#alias),
+                    None => quote!(#index),
+                };
+                (Binding::Index(index), field, (key, field.flatten.is_some()))
+            }),
+            |(index, flatten), value| match self.is_transparent || flatten {
                 true => quote! {{
                     #value
                 }},
@@ -168,16 +594,39 @@ impl<'a> ToTokens for Tuple<'a> {
     }
 }
 
+impl ToTokens for model::Normalize {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        match self {
+            model::Normalize::Trim => quote!(::garde::util::__normalize_trim(&*__garde_binding)),
+            model::Normalize::Lowercase => {
+                quote!(::garde::util::__normalize_lowercase(&*__garde_binding))
+            }
+            model::Normalize::Uppercase => {
+                quote!(::garde::util::__normalize_uppercase(&*__garde_binding))
+            }
+            model::Normalize::Func(func) => quote!((#func)(&*__garde_binding)),
+        }
+        .to_tokens(tokens)
+    }
+}
+
 struct Inner<'a> {
     rules_mod: &'a TokenStream2,
     rule_set: &'a model::RuleSet,
+    message: bool,
+    code: Option<&'a str>,
+    label: Option<&'a str>,
 }
 
-impl<'a> ToTokens for Inner<'a> {
-    fn to_tokens(&self, tokens: &mut TokenStream2) {
+impl<'a> Inner<'a> {
+    /// Validation of a single item bound to `__garde_binding`.
+    fn item(&self, rule_set: &'a model::RuleSet) -> Option<TokenStream2> {
         let Inner {
             rules_mod,
-            rule_set,
+            message,
+            code,
+            label,
+            ..
         } = self;
 
         let outer = match rule_set.has_top_level_rules() {
@@ -185,6 +634,10 @@ impl<'a> ToTokens for Inner<'a> {
                 let rules = Rules {
                     rules_mod,
                     rule_set,
+                    message: *message,
+                    code: *code,
+                    label: *label,
+                    warn: false,
                 };
                 Some(quote! {#rules})
             }
@@ -193,18 +646,70 @@ impl<'a> ToTokens for Inner<'a> {
         let inner = rule_set.inner.as_deref().map(|rule_set| Inner {
             rules_mod,
             rule_set,
+            message: *message,
+            code: *code,
+            label: *label,
         });
 
-        let value = match (outer, inner) {
-            (Some(outer), Some(inner)) => quote! {
+        match (outer, inner) {
+            (Some(outer), Some(inner)) => Some(quote! {
                 #outer
                 #inner
-            },
-            (None, Some(inner)) => quote! {
+            }),
+            (None, Some(inner)) => Some(quote! {
                 #inner
-            },
-            (Some(outer), None) => outer,
-            (None, None) => return,
+            }),
+            (Some(outer), None) => Some(outer),
+            (None, None) => None,
+        }
+    }
+}
+
+impl<'a> ToTokens for Inner<'a> {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let Inner {
+            rules_mod,
+            rule_set,
+            ..
+        } = self;
+
+        if rule_set.has_map_rules() {
+            let key = rule_set.key.as_deref().and_then(|set| self.item(set)).map(|key| {
+                quote! {{
+                    let __garde_binding = __garde_key;
+                    let __garde_map_key = ::garde::error::MapKey(__garde_key);
+                    let mut __garde_path = ::garde::util::nested_path!(__garde_path, __garde_map_key);
+                    #key
+                }}
+            });
+            let value = rule_set.value.as_deref().and_then(|set| self.item(set)).map(|value| {
+                quote! {{
+                    let __garde_binding = __garde_value;
+                    let __garde_map_key = ::garde::error::MapValue(__garde_key);
+                    let mut __garde_path = ::garde::util::nested_path!(__garde_path, __garde_map_key);
+                    #value
+                }}
+            });
+            if key.is_none() && value.is_none() {
+                return;
+            }
+
+            quote! {
+                #rules_mod::inner::apply_map(
+                    &*__garde_binding,
+                    |__garde_key, __garde_value| {
+                        #key
+                        #value
+                    }
+                );
+            }
+            .to_tokens(tokens);
+            return;
+        }
+
+        let value = match self.item(rule_set) {
+            Some(value) => value,
+            None => return,
         };
 
         quote! {
@@ -220,9 +725,44 @@ impl<'a> ToTokens for Inner<'a> {
     }
 }
 
+/// Replaces the message of `__garde_error` with the `message` of a field.
+///
+/// `{min}`, `{max}` and `{equal}` refer to the `length` or `range` rule in `rule_set`.
+fn with_message(message: &model::ValidateMessage, rule_set: &model::RuleSet) -> TokenStream2 {
+    match message {
+        model::ValidateMessage::Fmt(fmt, placeholders) => {
+            let bounds = rule_set.bounds();
+            let args = placeholders.iter().map(|name| {
+                let value = match name.as_str() {
+                    "value" => quote!(&*__garde_binding),
+                    "error" => quote!(__garde_error),
+                    // checked to be unambiguous in `check`
+                    _ => bounds[0].get(name).unwrap(),
+                };
+                let name = format_ident!("{name}");
+                quote!(#name = #value)
+            });
+            let fmt = syn::LitStr::new(&fmt.value, fmt.span);
+            quote!({
+                let __garde_message = ::std::format!(#fmt, #(#args),*);
+                __garde_error.__with_message(__garde_message)
+            })
+        }
+        model::ValidateMessage::Func(func) => quote! {
+            __garde_error.__with_message((#func)(&*__garde_binding, &__garde_user_ctx))
+        },
+    }
+}
+
 struct Rules<'a> {
     rules_mod: &'a TokenStream2,
     rule_set: &'a model::RuleSet,
+    /// Whether the field has a `message`, in which case only the first error
+    /// is kept, to be reported once the whole field is validated.
+    message: bool,
+    code: Option<&'a str>,
+    label: Option<&'a str>,
+    warn: bool,
 }
 
 #[derive(Clone, Copy)]
@@ -245,24 +785,133 @@ impl<'a> ToTokens for Rules<'a> {
         let Rules {
             rules_mod,
             rule_set,
+            message,
+            code,
+            label,
+            warn,
         } = self;
+        let with_label = label.map(|label| quote!(.with_label(#label)));
+        let with_level = warn.then(|| quote!(.with_level(::garde::error::Level::Warning)));
+
+        let append = |error: TokenStream2| match (message, warn) {
+            (false, _) => quote!(__garde_report.append(__garde_path(), #error);),
+            (true, false) => quote! {
+                if __garde_message_error.is_none() {
+                    __garde_message_error = Some(#error);
+                }
+            },
+            (true, true) => quote! {
+                if __garde_message_warning.is_none() {
+                    __garde_message_warning = Some(#error);
+                }
+            },
+        };
 
         for custom_rule in rule_set.custom_rules.iter() {
+            let append = append(quote!(__garde_error #with_label #with_level));
             quote! {
                 if let Err(__garde_error) = (#custom_rule)(&*__garde_binding, &__garde_user_ctx) {
-                    __garde_report.append(__garde_path(), __garde_error);
+                    #append
                 }
             }
             .to_tokens(tokens);
         }
 
-        for rule in rule_set.rules.iter() {
+        for rule_value in rule_set.rule_values.iter() {
+            let append = append(quote!(__garde_error #with_label #with_level));
+            quote! {
+                if let Err(__garde_error) = ::garde::rules::Rule::validate(&#rule_value, &*__garde_binding, *__garde_user_ctx) {
+                    #append
+                }
+            }
+            .to_tokens(tokens);
+        }
+
+        let rules = rule_set.rules.iter().map(|rule| (rule, false));
+        let negated = rule_set.negated.iter().map(|rule| (rule, true));
+        for (rule, is_negated) in rules.chain(negated) {
             let name = TokenStream2::from_str(rule.name()).unwrap();
             use model::ValidateRule::*;
+            // `exact` has its own entrypoint, so that it can report the expected length
+            let apply = match rule {
+                LengthSimple(model::ValidateRange::Exact(_))
+                | LengthBytes(model::ValidateRange::Exact(_))
+                | LengthChars(model::ValidateRange::Exact(_))
+                | LengthGraphemes(model::ValidateRange::Exact(_))
+                | LengthUtf16(model::ValidateRange::Exact(_)) => quote!(apply_exact),
+                _ => quote!(apply),
+            };
             let args = match rule {
-                Ascii | Alphanumeric | Email | Url | CreditCard | PhoneNumber | Required => {
+                Ascii | Lowercase | Uppercase | NoWhitespace | NoLeadingWhitespace
+                | NoTrailingWhitespace | Hex | Email | Luhn | Uuid | Unique | Finite | Latitude
+                | Longitude | CoordinatePair | Required | NonDefault => {
                     quote!(())
                 }
+                #[cfg(feature = "ipnet")]
+                IpInSubnet(subnet) => {
+                    let addr = match subnet {
+                        model::Subnet::V4(octets, _) => quote!(::std::net::IpAddr::V4(
+                            ::std::net::Ipv4Addr::new(#(#octets),*)
+                        )),
+                        model::Subnet::V6(segments, _) => quote!(::std::net::IpAddr::V6(
+                            ::std::net::Ipv6Addr::new(#(#segments),*)
+                        )),
+                    };
+                    let (model::Subnet::V4(_, prefix_len) | model::Subnet::V6(_, prefix_len)) =
+                        subnet;
+                    quote!({
+                        static SUBNET: #rules_mod::ip::subnet::IpNet =
+                            #rules_mod::ip::subnet::IpNet::new_assert(#addr, #prefix_len);
+
+                        (&SUBNET,)
+                    })
+                }
+                Url(v) => {
+                    let schemes = &v.schemes;
+                    let require_https = v.require_https;
+                    let require_tld = v.require_tld;
+                    quote!((&#rules_mod::url::UrlRequirements {
+                        schemes: &[#(#schemes),*],
+                        require_https: #require_https,
+                        require_tld: #require_tld,
+                    },))
+                }
+                Domain(require_tld) => quote!((#require_tld,)),
+                MimeType(allowed) => quote!((&[#(#allowed),*],)),
+                Sorted(v) => {
+                    let order = match v.descending {
+                        true => quote!(#rules_mod::sorted::Order::Descending),
+                        false => quote!(#rules_mod::sorted::Order::Ascending),
+                    };
+                    let strict = v.strict;
+                    quote!((#order, #strict))
+                }
+                Numeric(v) => {
+                    let ty = &v.ty;
+                    let finite = v.finite;
+                    let sign = match v.sign {
+                        Some(model::Sign::Positive) => {
+                            quote!(Some(#rules_mod::range::sign::Sign::Positive))
+                        }
+                        Some(model::Sign::NonNegative) => {
+                            quote!(Some(#rules_mod::range::sign::Sign::NonNegative))
+                        }
+                        Some(model::Sign::Negative) => {
+                            quote!(Some(#rules_mod::range::sign::Sign::Negative))
+                        }
+                        Some(model::Sign::NonPositive) => {
+                            quote!(Some(#rules_mod::range::sign::Sign::NonPositive))
+                        }
+                        None => quote!(None),
+                    };
+                    let marker = quote_spanned!(ty.span() => ::core::marker::PhantomData::<#ty>);
+                    quote!((#marker, #finite, #sign))
+                }
+                Slug(v) => {
+                    let allow_uppercase = v.allow_uppercase;
+                    let allow_dots = v.allow_dots;
+                    quote!((#allow_uppercase, #allow_dots))
+                }
                 Ip => {
                     quote!((#rules_mod::ip::IpKind::Any,))
                 }
@@ -272,6 +921,148 @@ impl<'a> ToTokens for Rules<'a> {
                 IpV6 => {
                     quote!((#rules_mod::ip::IpKind::V6,))
                 }
+                Cidr(version) => match version {
+                    model::IpVersion::Any => quote!((#rules_mod::ip::IpKind::Any,)),
+                    model::IpVersion::V4 => quote!((#rules_mod::ip::IpKind::V4,)),
+                    model::IpVersion::V6 => quote!((#rules_mod::ip::IpKind::V6,)),
+                },
+                MacAddress(format) => match format {
+                    model::MacAddressFormat::Any => {
+                        quote!((#rules_mod::mac_address::MacFormat::Any,))
+                    }
+                    model::MacAddressFormat::Colon => {
+                        quote!((#rules_mod::mac_address::MacFormat::Colon,))
+                    }
+                    model::MacAddressFormat::Hyphen => {
+                        quote!((#rules_mod::mac_address::MacFormat::Hyphen,))
+                    }
+                },
+                CreditCard(networks) => {
+                    let networks = networks.iter().map(|network| {
+                        let variant = match network {
+                            model::CardNetwork::VisaElectron => quote!(VisaElectron),
+                            model::CardNetwork::Maestro => quote!(Maestro),
+                            model::CardNetwork::Forbrugsforeningen => quote!(Forbrugsforeningen),
+                            model::CardNetwork::Dankort => quote!(Dankort),
+                            model::CardNetwork::Visa => quote!(Visa),
+                            model::CardNetwork::Mir => quote!(MIR),
+                            model::CardNetwork::MasterCard => quote!(MasterCard),
+                            model::CardNetwork::Amex => quote!(Amex),
+                            model::CardNetwork::DinersClub => quote!(DinersClub),
+                            model::CardNetwork::Discover => quote!(Discover),
+                            model::CardNetwork::UnionPay => quote!(UnionPay),
+                            model::CardNetwork::Jcb => quote!(JCB),
+                        };
+                        quote!(#rules_mod::credit_card::Network::#variant)
+                    });
+                    quote!((&[#(#networks),*],))
+                }
+                JsonString(schema_type) => {
+                    let schema_type = schema_type.map(|schema_type| {
+                        let variant = match schema_type {
+                            model::JsonType::Object => quote!(Object),
+                            model::JsonType::Array => quote!(Array),
+                            model::JsonType::String => quote!(String),
+                            model::JsonType::Number => quote!(Number),
+                            model::JsonType::Boolean => quote!(Boolean),
+                            model::JsonType::Null => quote!(Null),
+                        };
+                        quote!(#rules_mod::json_string::JsonType::#variant)
+                    });
+                    match schema_type {
+                        Some(schema_type) => quote!((Some(#schema_type),)),
+                        None => quote!((None,)),
+                    }
+                }
+                Base64(v) => {
+                    let alphabet = match v.url_safe {
+                        true => quote!(#rules_mod::base64::Alphabet::UrlSafe),
+                        false => quote!(#rules_mod::base64::Alphabet::Standard),
+                    };
+                    let padding = match v.padding {
+                        model::Base64Padding::Required => {
+                            quote!(#rules_mod::base64::Padding::Required)
+                        }
+                        model::Base64Padding::Optional => {
+                            quote!(#rules_mod::base64::Padding::Optional)
+                        }
+                        model::Base64Padding::None => quote!(#rules_mod::base64::Padding::None),
+                    };
+                    quote!((#alphabet, #padding))
+                }
+                CountryCode(v) => {
+                    let alpha = match v.alpha3 {
+                        true => quote!(#rules_mod::country_code::Alpha::Alpha3),
+                        false => quote!(#rules_mod::country_code::Alpha::Alpha2),
+                    };
+                    let case_insensitive = v.case_insensitive;
+                    quote!((#alpha, #case_insensitive))
+                }
+                Isbn(format) => match format {
+                    model::IsbnFormat::Any => quote!((#rules_mod::isbn::IsbnFormat::Any,)),
+                    model::IsbnFormat::Isbn10 => quote!((#rules_mod::isbn::IsbnFormat::Isbn10,)),
+                    model::IsbnFormat::Isbn13 => quote!((#rules_mod::isbn::IsbnFormat::Isbn13,)),
+                },
+                PhoneNumber(region) => match region {
+                    Some(region) => {
+                        let region = &region.value;
+                        quote!((Some(#region),))
+                    }
+                    None => quote!((None,)),
+                },
+                Iban(country) => match country {
+                    Some(country) => {
+                        let country = &country.value;
+                        quote!((Some(#country),))
+                    }
+                    None => quote!((None,)),
+                },
+                Password(v) => {
+                    let option = |v: Option<TokenStream2>| match v {
+                        Some(v) => quote!(::std::option::Option::Some(#v)),
+                        None => quote!(::std::option::Option::None),
+                    };
+                    let min_entropy_bits = option(v.min_entropy_bits.map(|v| quote!(#v)));
+                    let min_complexity_score = option(v.min_complexity_score.map(|v| quote!(#v)));
+                    let require_uppercase = v.require_uppercase;
+                    let require_lowercase = v.require_lowercase;
+                    let require_digit = v.require_digit;
+                    let require_special = v.require_special;
+                    quote!((&#rules_mod::password::Requirements {
+                        min_entropy_bits: #min_entropy_bits,
+                        require_uppercase: #require_uppercase,
+                        require_lowercase: #require_lowercase,
+                        require_digit: #require_digit,
+                        require_special: #require_special,
+                        min_complexity_score: #min_complexity_score,
+                    },))
+                }
+                Date(v) | Time(v) | DateTime(v) => {
+                    let option = |v: &Option<String>| match v {
+                        Some(v) => quote!(::std::option::Option::Some(#v)),
+                        None => quote!(::std::option::Option::None),
+                    };
+                    let format = option(&v.format);
+                    let after = option(&v.after);
+                    let before = option(&v.before);
+                    quote!((#format, #after, #before))
+                }
+                Semver(req) => match req {
+                    // checked in `check`, so it is only parsed once
+                    Some(
+                        req @ syn::Expr::Lit(syn::ExprLit {
+                            lit: syn::Lit::Str(_),
+                            ..
+                        }),
+                    ) => quote!({
+                        static REQ: #rules_mod::semver::StaticVersionReq =
+                            #rules_mod::semver::StaticVersionReq::new();
+
+                        (Some(REQ.get(#req)),)
+                    }),
+                    Some(req) => quote_spanned!(req.span() => (Some(&*#req),)),
+                    None => quote!((::std::option::Option::<&str>::None,)),
+                },
                 LengthSimple(range)
                 | LengthBytes(range)
                 | LengthChars(range)
@@ -289,20 +1080,59 @@ impl<'a> ToTokens for Rules<'a> {
                     model::ValidateRange::Equal(equal) => {
                         quote!((#equal, #equal))
                     }
+                    model::ValidateRange::Exact(len) => {
+                        quote!((#len,))
+                    }
                 },
                 Matches(path) => {
                     quote!((stringify!(#path), &self.#path))
                 }
-                Range(range) => match range {
-                    model::ValidateRange::GreaterThan(min) => quote!((Some(#min), None)),
-                    model::ValidateRange::LowerThan(max) => quote!((None, Some(#max))),
-                    model::ValidateRange::Between(min, max) => quote!((Some(#min), Some(#max))),
-                    model::ValidateRange::Equal(equal) => quote!((Some(#equal), Some(#equal))),
-                },
-                Contains(expr) | Prefix(expr) | Suffix(expr) => {
+                Range(range) => {
+                    let bound = |bound: &model::RangeBound| match bound.exclusive {
+                        true => quote!(::std::ops::Bound::Excluded(#bound)),
+                        false => quote!(::std::ops::Bound::Included(#bound)),
+                    };
+                    let unbounded = quote!(::std::ops::Bound::Unbounded);
+                    match range {
+                        model::ValidateRange::GreaterThan(min) => {
+                            let min = bound(min);
+                            quote!((#min, #unbounded))
+                        }
+                        model::ValidateRange::LowerThan(max) => {
+                            let max = bound(max);
+                            quote!((#unbounded, #max))
+                        }
+                        model::ValidateRange::Between(min, max) => {
+                            let (min, max) = (bound(min), bound(max));
+                            quote!((#min, #max))
+                        }
+                        model::ValidateRange::Equal(equal) | model::ValidateRange::Exact(equal) => {
+                            let equal = bound(equal);
+                            quote!((#equal, #equal))
+                        }
+                    }
+                }
+                Alphanumeric(ascii) | Alphabetic(ascii) => quote!((#ascii,)),
+                Port(unprivileged) => quote!((#unprivileged,)),
+                Timezone(utc_offset_only) => quote!((#utc_offset_only,)),
+                NotCommonPassword(case_insensitive) => quote!((#case_insensitive,)),
+                Positive => quote!((#rules_mod::range::sign::Sign::Positive,)),
+                NonNegative => quote!((#rules_mod::range::sign::Sign::NonNegative,)),
+                Negative => quote!((#rules_mod::range::sign::Sign::Negative,)),
+                NonPositive => quote!((#rules_mod::range::sign::Sign::NonPositive,)),
+                MultipleOf(expr) | UniqueBy(expr) => quote_spanned!(expr.span() => (#expr,)),
+                Precision(max_decimal_places) => quote!((#max_decimal_places,)),
+                OneOf(expr) | NotOneOf(expr) | ContainsAny(expr) | ContainsAll(expr) => {
+                    quote_spanned!(expr.span() => (&#expr,))
+                }
+                Contains(expr) | NotContains(expr) | Prefix(expr) | NotPrefix(expr)
+                | Suffix(expr) | NotSuffix(expr) => {
                     quote_spanned!(expr.span() => (&#expr,))
                 }
                 Pattern(pat) => match pat {
+                    model::ValidatePattern::Expr(expr @ syn::Expr::Closure(_)) => {
+                        quote_spanned!(expr.span() => (&#rules_mod::pattern::FnMatcher::new(#expr),))
+                    }
                     model::ValidatePattern::Expr(expr) => quote_spanned!(expr.span() => (&#expr,)),
                     #[cfg(feature = "regex")]
                     model::ValidatePattern::Lit(s) => quote!({
@@ -327,13 +1157,68 @@ impl<'a> ToTokens for Rules<'a> {
                 },
             };
 
+            let rule_code = match is_negated {
+                true => rule_set.negated_codes.get(rule.name()),
+                false => rule_set.codes.get(rule.name()),
+            };
+            let code = rule_code.map(String::as_str).or(*code);
+
+            if is_negated {
+                let code = code.map_or_else(|| rule.negated_code(), str::to_string);
+                // checked to be negatable in `check`
+                let message = rule.negated_message().unwrap();
+                let append = append(quote!(__garde_error.with_code(#code) #with_label #with_level));
+
+                quote! {
+                    if (#rules_mod::#name::#apply)(&*__garde_binding, #args).is_ok() {
+                        let __garde_error = ::garde::error::Error::new(#message);
+                        #append
+                    }
+                }
+                .to_tokens(tokens);
+                continue;
+            }
+
+            let code = code.unwrap_or(rule.code());
+            let append = append(quote!(__garde_error.with_code(#code) #with_label #with_level));
+
             quote! {
-                if let Err(__garde_error) = (#rules_mod::#name::apply)(&*__garde_binding, #args) {
-                    __garde_report.append(__garde_path(), __garde_error);
+                if let Err(__garde_error) = (#rules_mod::#name::#apply)(&*__garde_binding, #args) {
+                    #append
                 }
             }
             .to_tokens(tokens)
         }
+
+        for (condition, rule_set) in rule_set.conditional.iter() {
+            let rules = Rules {
+                rules_mod,
+                rule_set,
+                message: *message,
+                code: *code,
+                label: *label,
+                warn: *warn,
+            };
+
+            quote! {
+                if ::garde::util::__when(self, *__garde_user_ctx, #condition) {
+                    #rules
+                }
+            }
+            .to_tokens(tokens)
+        }
+
+        if let Some(rule_set) = rule_set.warnings.as_deref() {
+            Rules {
+                rules_mod,
+                rule_set,
+                message: *message,
+                code: *code,
+                label: *label,
+                warn: true,
+            }
+            .to_tokens(tokens)
+        }
     }
 }
 
@@ -370,24 +1255,47 @@ where
             let rules = Rules {
                 rules_mod,
                 rule_set: &field.rule_set,
+                message: field.message.is_some(),
+                code: field.code.as_deref(),
+                label: field.label.as_deref(),
+                warn: false,
             };
-            let outer = match field.has_top_level_rules() {
-                true => Some(quote! {{#rules}}),
-                false => None,
+            let outer = match (field.has_top_level_rules(), &field.normalize) {
+                (true, Some(normalize)) => Some(quote! {{
+                    let __garde_normalized = #normalize;
+                    let __garde_binding = &__garde_normalized;
+                    #rules
+                }}),
+                (true, None) => Some(quote! {{#rules}}),
+                (false, _) => None,
             };
             let inner = match (&field.dive, &field.rule_set.inner) {
-                (Some(..), None) => Some(quote! {
-                    ::garde::validate::Validate::validate_into(
-                        &*__garde_binding,
-                        __garde_user_ctx,
-                        &mut __garde_path,
-                        __garde_report,
-                    );
-                }),
+                (Some(..), None) => match &field.dive_context {
+                    Some(context) => Some(quote! {
+                        let __garde_dive_ctx = ::garde::util::__dive_context(*__garde_user_ctx, #context);
+                        ::garde::validate::Validate::validate_into(
+                            &*__garde_binding,
+                            ::std::borrow::Borrow::borrow(&__garde_dive_ctx),
+                            &mut __garde_path,
+                            __garde_report,
+                        );
+                    }),
+                    None => Some(quote! {
+                        ::garde::validate::Validate::validate_into(
+                            &*__garde_binding,
+                            __garde_user_ctx,
+                            &mut __garde_path,
+                            __garde_report,
+                        );
+                    }),
+                },
                 (None, Some(inner)) => Some(
                     Inner {
                         rules_mod,
                         rule_set: inner,
+                        message: field.message.is_some(),
+                        code: field.code.as_deref(),
+                        label: field.label.as_deref(),
                     }
                     .to_token_stream(),
                 ),
@@ -412,6 +1320,41 @@ where
                 },
                 (None, None) => unreachable!("field should already be skipped"),
             };
+            let value = match &field.message {
+                Some(message) => {
+                    let error = with_message(message, &field.rule_set);
+                    quote! {
+                        #[allow(unused_mut)]
+                        let mut __garde_message_error = None::<::garde::error::Error>;
+                        #[allow(unused_mut)]
+                        let mut __garde_message_warning = None::<::garde::error::Error>;
+                        #value
+                        if let Some(__garde_error) = __garde_message_error {
+                            __garde_report.append(__garde_path(), #error);
+                        }
+                        if let Some(__garde_error) = __garde_message_warning {
+                            __garde_report.append(__garde_path(), #error);
+                        }
+                    }
+                }
+                None => value,
+            };
+            let value = match &field.skip_if {
+                Some(condition) => quote! {
+                    if !::garde::util::__when(self, *__garde_user_ctx, #condition) {
+                        #value
+                    }
+                },
+                None => value,
+            };
+            let value = match &field.groups {
+                Some(groups) => quote! {
+                    if ::garde::util::__in_group(__garde_group, &[#(#groups),*]) {
+                        #value
+                    }
+                },
+                None => value,
+            };
 
             let add = &self.1;
 
@@ -1,6 +1,8 @@
 mod check;
 mod emit;
 mod model;
+#[cfg(feature = "json-schema")]
+mod schema;
 mod syntax;
 mod util;
 
@@ -22,6 +24,21 @@ pub fn derive_validate(input: TokenStream) -> TokenStream {
     emit::emit(input).into()
 }
 
+#[cfg(feature = "json-schema")]
+#[proc_macro_derive(JsonSchema, attributes(garde))]
+pub fn derive_json_schema(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as DeriveInput);
+    let input = match syntax::parse(input) {
+        Ok(v) => v,
+        Err(e) => return e.into_compile_error().into(),
+    };
+    let input = match check::check(input) {
+        Ok(v) => v,
+        Err(e) => return e.into_compile_error().into(),
+    };
+    schema::emit(input).into()
+}
+
 #[proc_macro]
 pub fn select(input: TokenStream) -> TokenStream {
     fn parse_literal_digits_only(lit: Literal) -> syn::Result<String> {
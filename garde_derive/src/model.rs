@@ -1,13 +1,15 @@
 use std::collections::{BTreeMap, BTreeSet};
 
 use proc_macro2::{Ident, Span};
-use syn::{Expr, Generics, Path, Type};
+use syn::{Expr, Generics, Path, Type, WherePredicate};
 
 pub struct Input {
     pub ident: Ident,
     pub generics: Generics,
     pub attrs: Vec<(Span, Attr)>,
     pub kind: InputKind,
+    /// `#[garde(...)]` attributes on enum variants other than `context_fn`, which are ignored.
+    pub ignored_variant_attrs: Vec<Span>,
 }
 
 #[repr(u8)]
@@ -15,6 +17,21 @@ pub enum Attr {
     Context(Box<Type>, Ident),
     AllowUnvalidated,
     Transparent,
+    RenameAll(RenameRule),
+    Async,
+    Bound(Vec<WherePredicate>),
+}
+
+#[derive(Clone, Copy)]
+pub enum RenameRule {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    ScreamingKebab,
 }
 
 impl Attr {
@@ -31,13 +48,17 @@ impl Attr {
             Attr::Context(..) => "context",
             Attr::AllowUnvalidated => "allow_unvalidated",
             Attr::Transparent => "transparent",
+            Attr::RenameAll(..) => "rename_all",
+            Attr::Async => "async",
+            Attr::Bound(..) => "bound",
         }
     }
 }
 
 pub enum InputKind {
     Struct(Variant),
-    Enum(Vec<(Ident, Option<Variant>)>),
+    /// Each variant may have a `context_fn`, which maps the context of the enum to the context of the variant.
+    Enum(Vec<(Ident, Option<Expr>, Option<Variant>)>),
 }
 
 impl InputKind {
@@ -62,42 +83,332 @@ pub struct Field {
     pub rules: Vec<RawRule>,
 }
 
-// pub enum Message {
-//     Fmt(Str),
-//     Func(Expr),
-// }
+pub enum Message {
+    Fmt(Str),
+    Func(Expr),
+}
+
+pub enum Normalize {
+    Trim,
+    Lowercase,
+    Uppercase,
+    Func(Expr),
+}
+
+pub struct RawWhen {
+    pub condition: Expr,
+    pub rules: Vec<RawRule>,
+}
 
 pub struct RawRule {
     pub span: Span,
     pub kind: RawRuleKind,
+    pub code: Option<Str>,
 }
 
 pub enum RawRuleKind {
     Skip,
+    SkipIf(Expr),
+    Group(List<Str>),
     Adapt(Path),
     Rename(Str),
-    // Message(Message),
+    Message(Message),
     Code(Str),
+    Label(Str),
+    Normalize(Normalize),
     Dive,
+    DiveWith(Expr),
+    Flatten,
     Required,
+    NonDefault,
     Ascii,
-    Alphanumeric,
-    Email,
-    Url,
+    Alphanumeric(Option<RawAlpha>),
+    Alphabetic(Option<RawAlpha>),
+    Lowercase,
+    Uppercase,
+    NoWhitespace,
+    NoLeadingWhitespace,
+    NoTrailingWhitespace,
+    Hex,
+    Email(Option<RawEmail>),
+    Url(Option<RawUrl>),
+    Domain(Option<RawDomain>),
+    Slug(Option<Slug>),
+    MimeType(Option<RawMimeType>),
     Ip,
     IpV4,
     IpV6,
-    CreditCard,
-    PhoneNumber,
+    IpInSubnet(Str),
+    Cidr(Option<RawCidr>),
+    MacAddress(Option<RawMacAddress>),
+    JsonString(Option<RawJsonString>),
+    CreditCard(Option<RawCreditCard>),
+    Luhn,
+    PhoneNumber(Option<RawPhoneNumber>),
+    Iban(Option<RawIban>),
+    Isbn(Option<RawIsbn>),
+    CountryCode(Option<CountryCode>),
+    Uuid,
+    Semver(Option<RawSemver>),
+    Date(Option<RawDateTime>),
+    Time(Option<RawDateTime>),
+    DateTime(Option<RawDateTime>),
+    Password(Password),
+    NotCommonPassword(Option<RawCommonPassword>),
+    Unique,
+    UniqueBy(Expr),
+    Sorted(Option<Sorted>),
+    Numeric(Numeric),
+    Base64(Option<Base64>),
     Length(RawLength),
+    ByteLength(Range<Either<usize, Expr>>),
     Matches(Path),
-    Range(Range<Expr>),
+    Range(Box<RawRange>),
+    Port(Option<RawPort>),
+    Timezone(Option<RawTimezone>),
+    Positive,
+    NonNegative,
+    Negative,
+    NonPositive,
+    MultipleOf(Expr),
+    Precision(RawPrecision),
+    Finite,
+    Latitude,
+    Longitude,
+    CoordinatePair,
+    OneOf(Expr),
+    NotOneOf(Expr),
     Contains(Expr),
+    NotContains(Expr),
+    ContainsAny(Expr),
+    ContainsAll(Expr),
     Prefix(Expr),
+    NotPrefix(Expr),
     Suffix(Expr),
+    NotSuffix(Expr),
     Pattern(Pattern),
     Custom(Expr),
+    Rule(Expr),
+    AsyncCustom(Expr),
     Inner(List<RawRule>),
+    Key(List<RawRule>),
+    Not(Box<RawRule>),
+    Value(List<RawRule>),
+    When(RawWhen),
+    Warn(List<RawRule>),
+}
+
+pub struct RawUrl {
+    pub schemes: Vec<Str>,
+    pub require_https: bool,
+    pub require_tld: bool,
+}
+
+#[derive(Default)]
+pub struct Url {
+    pub schemes: Vec<String>,
+    pub require_https: bool,
+    pub require_tld: bool,
+}
+
+pub struct RawDomain {
+    pub require_tld: bool,
+}
+
+pub struct RawEmail {
+    pub check_mx: Option<Span>,
+}
+
+pub struct RawMimeType {
+    pub allowed: Vec<Str>,
+}
+
+#[derive(Default)]
+pub struct Slug {
+    pub allow_uppercase: bool,
+    pub allow_dots: bool,
+}
+
+#[derive(Default)]
+pub struct Sorted {
+    pub descending: bool,
+    pub strict: bool,
+}
+
+pub struct Numeric {
+    pub ty: syn::Type,
+    pub finite: bool,
+    pub sign: Option<Sign>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Sign {
+    Positive,
+    NonNegative,
+    Negative,
+    NonPositive,
+}
+
+pub struct RawAlpha {
+    pub ascii: bool,
+}
+
+pub struct RawPort {
+    pub unprivileged: bool,
+}
+
+pub struct RawCommonPassword {
+    pub case_insensitive: bool,
+}
+
+pub struct RawTimezone {
+    pub utc_offset_only: bool,
+}
+
+pub struct RawCidr {
+    pub version: IpVersion,
+}
+
+#[derive(Clone, Copy, Default)]
+pub enum IpVersion {
+    #[default]
+    Any,
+    V4,
+    V6,
+}
+
+pub struct RawMacAddress {
+    pub format: MacAddressFormat,
+}
+
+#[derive(Clone, Copy, Default)]
+pub enum MacAddressFormat {
+    #[default]
+    Any,
+    Colon,
+    Hyphen,
+}
+
+pub struct RawCreditCard {
+    pub networks: Vec<CardNetwork>,
+}
+
+#[derive(Clone, Copy)]
+pub enum CardNetwork {
+    VisaElectron,
+    Maestro,
+    Forbrugsforeningen,
+    Dankort,
+    Visa,
+    Mir,
+    MasterCard,
+    Amex,
+    DinersClub,
+    Discover,
+    UnionPay,
+    Jcb,
+}
+
+pub struct RawJsonString {
+    pub schema_type: Option<JsonType>,
+}
+
+#[derive(Clone, Copy)]
+pub enum JsonType {
+    Object,
+    Array,
+    String,
+    Number,
+    Boolean,
+    Null,
+}
+
+pub struct RawPhoneNumber {
+    pub region: Option<Str>,
+}
+
+pub struct RawIban {
+    pub country: Option<Str>,
+}
+
+pub struct RawIsbn {
+    pub format: IsbnFormat,
+}
+
+#[derive(Clone, Copy, Default)]
+pub enum IsbnFormat {
+    #[default]
+    Any,
+    Isbn10,
+    Isbn13,
+}
+
+pub struct RawSemver {
+    pub req: Option<Expr>,
+}
+
+#[cfg_attr(not(feature = "date"), allow(dead_code))]
+pub struct RawDateTime {
+    pub format: Option<Str>,
+    pub after: Option<Str>,
+    pub before: Option<Str>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum DateTimeKind {
+    Date,
+    Time,
+    DateTime,
+}
+
+impl DateTimeKind {
+    pub fn name(self) -> &'static str {
+        match self {
+            DateTimeKind::Date => "date",
+            DateTimeKind::Time => "time",
+            DateTimeKind::DateTime => "datetime",
+        }
+    }
+}
+
+pub struct DateTime {
+    pub format: Option<String>,
+    pub after: Option<String>,
+    pub before: Option<String>,
+}
+
+pub struct RawPrecision {
+    pub max_decimal_places: usize,
+}
+
+#[derive(Default)]
+pub struct Base64 {
+    pub url_safe: bool,
+    pub padding: Base64Padding,
+}
+
+#[derive(Clone, Copy, Default)]
+pub enum Base64Padding {
+    #[default]
+    Required,
+    Optional,
+    None,
+}
+
+#[derive(Default)]
+pub struct Password {
+    pub min_entropy_bits: Option<f64>,
+    pub require_uppercase: bool,
+    pub require_lowercase: bool,
+    pub require_digit: bool,
+    pub require_special: bool,
+    pub min_complexity_score: Option<u8>,
+}
+
+#[derive(Default)]
+pub struct CountryCode {
+    pub alpha3: bool,
+    pub case_insensitive: bool,
 }
 
 pub struct RawLength {
@@ -148,6 +459,14 @@ pub struct Range<T> {
     pub min: Option<T>,
     pub max: Option<T>,
     pub equal: Option<T>,
+    /// Whether `equal` was set using `exact`, which has its own error message.
+    pub exact: bool,
+}
+
+pub struct RawRange {
+    pub range: Range<Expr>,
+    pub exclusive_min: Option<Expr>,
+    pub exclusive_max: Option<Expr>,
 }
 
 pub struct List<T> {
@@ -160,6 +479,7 @@ pub struct Validate {
     pub context: (Type, Ident),
     pub is_transparent: bool,
     pub kind: ValidateKind,
+    pub ignored_variant_attrs: Vec<Span>,
     // I don't know why Rust thinks this is unused.
     // It's both read and written, grep for `.allow_unvalidated`.
     #[allow(dead_code)]
@@ -168,11 +488,14 @@ pub struct Validate {
 
 pub struct Options {
     pub allow_unvalidated: bool,
+    pub rename_all: Option<RenameRule>,
+    pub is_async: bool,
+    pub bound: Option<Vec<WherePredicate>>,
 }
 
 pub enum ValidateKind {
     Struct(ValidateVariant),
-    Enum(Vec<(Ident, Option<ValidateVariant>)>),
+    Enum(Vec<(Ident, Option<Expr>, Option<ValidateVariant>)>),
 }
 
 pub struct ValidateField {
@@ -180,11 +503,17 @@ pub struct ValidateField {
 
     pub adapter: Option<Path>,
     pub skip: Option<Span>,
+    pub skip_if: Option<Expr>,
+    pub groups: Option<Vec<String>>,
     pub alias: Option<String>,
-    // pub message: Option<Message>,
+    pub message: Option<ValidateMessage>,
     pub code: Option<String>,
+    pub label: Option<String>,
+    pub normalize: Option<Normalize>,
 
     pub dive: Option<Span>,
+    pub dive_context: Option<Expr>,
+    pub flatten: Option<Span>,
     pub rule_set: RuleSet,
 }
 
@@ -200,72 +529,371 @@ impl ValidateField {
 
 pub struct RuleSet {
     pub rules: BTreeSet<ValidateRule>,
+    pub negated: BTreeSet<ValidateRule>,
+    /// Error codes set on individual rules, by rule name.
+    pub codes: BTreeMap<&'static str, String>,
+    pub negated_codes: BTreeMap<&'static str, String>,
     pub custom_rules: Vec<Expr>,
+    pub rule_values: Vec<Expr>,
+    pub async_custom_rules: Vec<Expr>,
+    pub conditional: Vec<(Expr, RuleSet)>,
+    pub warnings: Option<Box<RuleSet>>,
     pub inner: Option<Box<RuleSet>>,
+    pub key: Option<Box<RuleSet>>,
+    pub value: Option<Box<RuleSet>>,
 }
 
 impl RuleSet {
     pub fn empty() -> Self {
         Self {
             rules: BTreeSet::new(),
+            negated: BTreeSet::new(),
+            codes: BTreeMap::new(),
+            negated_codes: BTreeMap::new(),
             custom_rules: Vec::new(),
+            rule_values: Vec::new(),
+            async_custom_rules: Vec::new(),
+            conditional: Vec::new(),
+            warnings: None,
             inner: None,
+            key: None,
+            value: None,
         }
     }
 
     pub fn is_empty(&self) -> bool {
-        let inner_empty = match &self.inner {
-            Some(inner) => inner.is_empty(),
+        let is_empty = |set: &Option<Box<RuleSet>>| match set {
+            Some(set) => set.is_empty(),
             None => true,
         };
-        inner_empty && self.rules.is_empty() && self.custom_rules.is_empty()
+        is_empty(&self.inner)
+            && is_empty(&self.warnings)
+            && is_empty(&self.key)
+            && is_empty(&self.value)
+            && self.rules.is_empty()
+            && self.negated.is_empty()
+            && self.custom_rules.is_empty()
+            && self.rule_values.is_empty()
+            && self.async_custom_rules.is_empty()
+            && self.conditional.is_empty()
     }
 
     pub fn has_top_level_rules(&self) -> bool {
-        !self.rules.is_empty() || !self.custom_rules.is_empty()
+        !self.rules.is_empty()
+            || !self.negated.is_empty()
+            || !self.custom_rules.is_empty()
+            || !self.rule_values.is_empty()
+            || !self.async_custom_rules.is_empty()
+            || !self.conditional.is_empty()
+            || self.warnings.is_some()
+    }
+
+    pub fn has_map_rules(&self) -> bool {
+        self.key.is_some() || self.value.is_some()
+    }
+
+    pub fn bounds(&self) -> Vec<Bounds<'_>> {
+        self.rules
+            .iter()
+            .filter_map(|rule| match rule {
+                ValidateRule::LengthSimple(range)
+                | ValidateRule::LengthBytes(range)
+                | ValidateRule::LengthChars(range)
+                | ValidateRule::LengthGraphemes(range)
+                | ValidateRule::LengthUtf16(range) => Some(Bounds::Length(range)),
+                ValidateRule::Range(range) => Some(Bounds::Range(range)),
+                _ => None,
+            })
+            .collect()
     }
 }
 
 #[repr(u8)]
 pub enum ValidateRule {
     Required,
+    NonDefault,
     Ascii,
-    Alphanumeric,
+    Alphanumeric(bool),
+    Alphabetic(bool),
+    Lowercase,
+    Uppercase,
+    NoWhitespace,
+    NoLeadingWhitespace,
+    NoTrailingWhitespace,
+    Hex,
     Email,
-    Url,
+    Url(Url),
+    Domain(bool),
+    Slug(Slug),
+    MimeType(Vec<String>),
     Ip,
     IpV4,
     IpV6,
-    CreditCard,
-    PhoneNumber,
+    #[cfg(feature = "ipnet")]
+    IpInSubnet(Subnet),
+    Cidr(IpVersion),
+    MacAddress(MacAddressFormat),
+    JsonString(Option<JsonType>),
+    CreditCard(Vec<CardNetwork>),
+    Luhn,
+    PhoneNumber(Option<Str>),
+    Iban(Option<Str>),
+    Isbn(IsbnFormat),
+    CountryCode(CountryCode),
+    Uuid,
+    Semver(Option<Expr>),
+    Date(DateTime),
+    Time(DateTime),
+    DateTime(DateTime),
+    Password(Password),
+    NotCommonPassword(bool),
+    Unique,
+    UniqueBy(Expr),
+    Sorted(Sorted),
+    Numeric(Numeric),
+    Base64(Base64),
     LengthSimple(LengthRange),
     LengthBytes(LengthRange),
     LengthChars(LengthRange),
     LengthGraphemes(LengthRange),
     LengthUtf16(LengthRange),
     Matches(Path),
-    Range(ValidateRange<Expr>),
+    Range(ValidateRange<RangeBound>),
+    Port(bool),
+    Timezone(bool),
+    Positive,
+    NonNegative,
+    Negative,
+    NonPositive,
+    MultipleOf(Expr),
+    Precision(usize),
+    Finite,
+    Latitude,
+    Longitude,
+    CoordinatePair,
+    OneOf(Expr),
+    NotOneOf(Expr),
     Contains(Expr),
+    NotContains(Expr),
+    ContainsAny(Expr),
+    ContainsAll(Expr),
     Prefix(Expr),
+    NotPrefix(Expr),
     Suffix(Expr),
+    NotSuffix(Expr),
     Pattern(ValidatePattern),
 }
 
 type LengthRange = ValidateRange<Either<usize, Expr>>;
 
 impl ValidateRule {
+    /// The default error code of this rule.
+    pub fn code(&self) -> &'static str {
+        match self {
+            ValidateRule::Required => "GARDE_REQUIRED",
+            ValidateRule::NonDefault => "GARDE_NON_DEFAULT",
+            ValidateRule::Ascii => "GARDE_ASCII",
+            ValidateRule::Alphanumeric(_) => "GARDE_ALPHANUMERIC",
+            ValidateRule::Alphabetic(_) => "GARDE_ALPHABETIC",
+            ValidateRule::Lowercase => "GARDE_LOWERCASE",
+            ValidateRule::Uppercase => "GARDE_UPPERCASE",
+            ValidateRule::NoWhitespace => "GARDE_NO_WHITESPACE",
+            ValidateRule::NoLeadingWhitespace => "GARDE_NO_LEADING_WHITESPACE",
+            ValidateRule::NoTrailingWhitespace => "GARDE_NO_TRAILING_WHITESPACE",
+            ValidateRule::Hex => "GARDE_HEX",
+            ValidateRule::Email => "GARDE_EMAIL",
+            ValidateRule::Url(_) => "GARDE_URL",
+            ValidateRule::Domain(_) => "GARDE_DOMAIN",
+            ValidateRule::Slug(_) => "GARDE_SLUG",
+            ValidateRule::MimeType(_) => "GARDE_MIME_TYPE",
+            ValidateRule::Ip => "GARDE_IP",
+            ValidateRule::IpV4 => "GARDE_IPV4",
+            ValidateRule::IpV6 => "GARDE_IPV6",
+            #[cfg(feature = "ipnet")]
+            ValidateRule::IpInSubnet(_) => "GARDE_IP_IN_SUBNET",
+            ValidateRule::Cidr(_) => "GARDE_CIDR",
+            ValidateRule::MacAddress(_) => "GARDE_MAC_ADDRESS",
+            ValidateRule::JsonString(_) => "GARDE_JSON_STRING",
+            ValidateRule::CreditCard(_) => "GARDE_CREDIT_CARD",
+            ValidateRule::Luhn => "GARDE_LUHN",
+            ValidateRule::PhoneNumber(_) => "GARDE_PHONE_NUMBER",
+            ValidateRule::Iban(_) => "GARDE_IBAN",
+            ValidateRule::Isbn(_) => "GARDE_ISBN",
+            ValidateRule::CountryCode(_) => "GARDE_COUNTRY_CODE",
+            ValidateRule::Uuid => "GARDE_UUID",
+            ValidateRule::Semver(_) => "GARDE_SEMVER",
+            ValidateRule::Date(_) => "GARDE_DATE",
+            ValidateRule::Time(_) => "GARDE_TIME",
+            ValidateRule::DateTime(_) => "GARDE_DATETIME",
+            ValidateRule::Password(_) => "GARDE_PASSWORD",
+            ValidateRule::NotCommonPassword(_) => "GARDE_NOT_COMMON_PASSWORD",
+            ValidateRule::Unique => "GARDE_UNIQUE",
+            ValidateRule::UniqueBy(_) => "GARDE_UNIQUE_BY",
+            ValidateRule::Sorted(_) => "GARDE_SORTED",
+            ValidateRule::Numeric(_) => "GARDE_NUMERIC",
+            ValidateRule::Base64(_) => "GARDE_BASE64",
+            ValidateRule::LengthSimple(_) => "GARDE_LENGTH",
+            ValidateRule::LengthBytes(_) => "GARDE_LENGTH",
+            ValidateRule::LengthChars(_) => "GARDE_LENGTH",
+            ValidateRule::LengthGraphemes(_) => "GARDE_LENGTH",
+            ValidateRule::LengthUtf16(_) => "GARDE_LENGTH",
+            ValidateRule::Matches(_) => "GARDE_MATCHES",
+            ValidateRule::Range(_) => "GARDE_RANGE",
+            ValidateRule::Port(_) => "GARDE_PORT",
+            ValidateRule::Timezone(_) => "GARDE_TIMEZONE",
+            ValidateRule::Positive => "GARDE_POSITIVE",
+            ValidateRule::NonNegative => "GARDE_NON_NEGATIVE",
+            ValidateRule::Negative => "GARDE_NEGATIVE",
+            ValidateRule::NonPositive => "GARDE_NON_POSITIVE",
+            ValidateRule::MultipleOf(_) => "GARDE_MULTIPLE_OF",
+            ValidateRule::Precision(_) => "GARDE_PRECISION",
+            ValidateRule::Finite => "GARDE_FINITE",
+            ValidateRule::Latitude => "GARDE_LATITUDE",
+            ValidateRule::Longitude => "GARDE_LONGITUDE",
+            ValidateRule::CoordinatePair => "GARDE_COORDINATE_PAIR",
+            ValidateRule::OneOf(_) => "GARDE_ONE_OF",
+            ValidateRule::NotOneOf(_) => "GARDE_NOT_ONE_OF",
+            ValidateRule::Contains(_) => "GARDE_CONTAINS",
+            ValidateRule::NotContains(_) => "GARDE_NOT_CONTAINS",
+            ValidateRule::ContainsAny(_) => "GARDE_CONTAINS_ANY",
+            ValidateRule::ContainsAll(_) => "GARDE_CONTAINS_ALL",
+            ValidateRule::Prefix(_) => "GARDE_PREFIX",
+            ValidateRule::NotPrefix(_) => "GARDE_NOT_PREFIX",
+            ValidateRule::Suffix(_) => "GARDE_SUFFIX",
+            ValidateRule::NotSuffix(_) => "GARDE_NOT_SUFFIX",
+            ValidateRule::Pattern(_) => "GARDE_PATTERN",
+        }
+    }
+
+    /// The default error code of this rule when wrapped in `not`.
+    pub fn negated_code(&self) -> String {
+        format!("GARDE_NOT_{}", self.code().trim_start_matches("GARDE_"))
+    }
+
+    /// The error message of this rule when wrapped in `not`, or `None` if
+    /// the rule may not be negated.
+    pub fn negated_message(&self) -> Option<&'static str> {
+        let message = match self {
+            ValidateRule::Required => "must not be set",
+            ValidateRule::NonDefault => "must be the default value",
+            ValidateRule::Ascii => "must not be ascii",
+            ValidateRule::Alphanumeric(_) => "must not be alphanumeric",
+            ValidateRule::Alphabetic(_) => "must not be alphabetic",
+            ValidateRule::Lowercase => "must not be lowercase",
+            ValidateRule::Uppercase => "must not be uppercase",
+            ValidateRule::NoWhitespace => "must not be free of whitespace",
+            ValidateRule::NoLeadingWhitespace => "must not be free of leading whitespace",
+            ValidateRule::NoTrailingWhitespace => "must not be free of trailing whitespace",
+            ValidateRule::Hex => "must not be hexadecimal",
+            ValidateRule::Email => "must not be a valid email",
+            ValidateRule::Url(_) => "must not be a valid url",
+            ValidateRule::Domain(_) => "must not be a valid domain",
+            ValidateRule::Slug(_) => "must not be a valid slug",
+            ValidateRule::MimeType(_) => "must not be a valid MIME type",
+            ValidateRule::Ip => "must not be a valid IP address",
+            ValidateRule::IpV4 => "must not be a valid IPv4 address",
+            ValidateRule::IpV6 => "must not be a valid IPv6 address",
+            #[cfg(feature = "ipnet")]
+            ValidateRule::IpInSubnet(_) => "must not be in the subnet",
+            ValidateRule::Cidr(_) => "must not be a valid CIDR notation",
+            ValidateRule::MacAddress(_) => "must not be a valid MAC address",
+            ValidateRule::JsonString(_) => "must not be valid JSON",
+            ValidateRule::CreditCard(_) => "must not be a valid credit card number",
+            ValidateRule::Luhn => "must not be a valid Luhn number",
+            ValidateRule::PhoneNumber(_) => "must not be a valid phone number",
+            ValidateRule::Iban(_) => "must not be a valid IBAN",
+            ValidateRule::Isbn(_) => "must not be a valid ISBN",
+            ValidateRule::CountryCode(_) => "must not be a valid country code",
+            ValidateRule::Uuid => "must not be a valid UUID",
+            ValidateRule::Semver(_) => "must not be a valid semantic version",
+            ValidateRule::Date(_) => "must not be a valid date",
+            ValidateRule::Time(_) => "must not be a valid time",
+            ValidateRule::DateTime(_) => "must not be a valid date and time",
+            ValidateRule::Password(_) => "must not meet the password requirements",
+            ValidateRule::Unique => "must not have unique elements",
+            ValidateRule::UniqueBy(_) => "must not have unique elements by the given key",
+            ValidateRule::Sorted(_) => "must not be sorted",
+            ValidateRule::Numeric(_) => "must not be a valid number",
+            ValidateRule::Base64(_) => "must not be valid base64",
+            ValidateRule::LengthSimple(_)
+            | ValidateRule::LengthBytes(_)
+            | ValidateRule::LengthChars(_)
+            | ValidateRule::LengthGraphemes(_)
+            | ValidateRule::LengthUtf16(_) => "must not have a length within the range",
+            ValidateRule::Matches(_) => "must not match the other field",
+            ValidateRule::Range(_) => "must not be within the range",
+            ValidateRule::Port(_) => "must not be a valid port",
+            ValidateRule::Timezone(_) => "must not be a valid timezone",
+            ValidateRule::Positive => "must not be positive",
+            ValidateRule::NonNegative => "must not be non-negative",
+            ValidateRule::Negative => "must not be negative",
+            ValidateRule::NonPositive => "must not be non-positive",
+            ValidateRule::MultipleOf(_) => "must not be a multiple of the given value",
+            ValidateRule::Precision(_) => "must not be within the allowed precision",
+            ValidateRule::Finite => "must not be finite",
+            ValidateRule::Latitude => "must not be a valid latitude",
+            ValidateRule::Longitude => "must not be a valid longitude",
+            ValidateRule::CoordinatePair => "must not be a valid coordinate pair",
+            ValidateRule::OneOf(_) => "must not be one of the given values",
+            ValidateRule::Contains(_) => "must not contain the pattern",
+            ValidateRule::ContainsAny(_) => "must not contain any of the patterns",
+            ValidateRule::ContainsAll(_) => "must not contain all of the patterns",
+            ValidateRule::Prefix(_) => "must not start with the prefix",
+            ValidateRule::Suffix(_) => "must not end with the suffix",
+            ValidateRule::Pattern(_) => "must not match the pattern",
+            ValidateRule::NotOneOf(_)
+            | ValidateRule::NotContains(_)
+            | ValidateRule::NotPrefix(_)
+            | ValidateRule::NotSuffix(_)
+            | ValidateRule::NotCommonPassword(_) => return None,
+        };
+        Some(message)
+    }
+
     pub fn name(&self) -> &'static str {
         match self {
             ValidateRule::Required => "required",
+            ValidateRule::NonDefault => "non_default",
             ValidateRule::Ascii => "ascii",
-            ValidateRule::Alphanumeric => "alphanumeric",
+            ValidateRule::Alphanumeric(_) => "alphanumeric",
+            ValidateRule::Alphabetic(_) => "alphabetic",
+            ValidateRule::Lowercase => "case::lowercase",
+            ValidateRule::Uppercase => "case::uppercase",
+            ValidateRule::NoWhitespace => "whitespace::none",
+            ValidateRule::NoLeadingWhitespace => "whitespace::leading",
+            ValidateRule::NoTrailingWhitespace => "whitespace::trailing",
+            ValidateRule::Hex => "hex",
             ValidateRule::Email => "email",
-            ValidateRule::Url => "url",
+            ValidateRule::Url(_) => "url",
+            ValidateRule::Domain(_) => "domain",
+            ValidateRule::Slug(_) => "slug",
+            ValidateRule::MimeType(_) => "mime_type",
             ValidateRule::Ip => "ip",
             ValidateRule::IpV4 => "ip",
             ValidateRule::IpV6 => "ip",
-            ValidateRule::CreditCard => "credit_card",
-            ValidateRule::PhoneNumber => "phone_number",
+            #[cfg(feature = "ipnet")]
+            ValidateRule::IpInSubnet(_) => "ip::subnet",
+            ValidateRule::Cidr(_) => "cidr",
+            ValidateRule::MacAddress(_) => "mac_address",
+            ValidateRule::JsonString(_) => "json_string",
+            ValidateRule::CreditCard(_) => "credit_card",
+            ValidateRule::Luhn => "luhn",
+            ValidateRule::PhoneNumber(_) => "phone_number",
+            ValidateRule::Uuid => "uuid",
+            ValidateRule::Iban(_) => "iban",
+            ValidateRule::Isbn(_) => "isbn",
+            ValidateRule::CountryCode(_) => "country_code",
+            ValidateRule::Semver(_) => "semver",
+            ValidateRule::Date(_) => "date",
+            ValidateRule::Time(_) => "time",
+            ValidateRule::DateTime(_) => "datetime",
+            ValidateRule::Password(_) => "password",
+            ValidateRule::NotCommonPassword(_) => "password::common",
+            ValidateRule::Unique => "unique",
+            ValidateRule::UniqueBy(_) => "unique::by",
+            ValidateRule::Sorted(_) => "sorted",
+            ValidateRule::Numeric(_) => "numeric_string",
+            ValidateRule::Base64(_) => "base64",
             ValidateRule::LengthSimple(_) => "length::simple",
             ValidateRule::LengthBytes(_) => "length::bytes",
             ValidateRule::LengthChars(_) => "length::chars",
@@ -273,14 +901,39 @@ impl ValidateRule {
             ValidateRule::LengthUtf16(_) => "length::utf16",
             ValidateRule::Matches(_) => "matches",
             ValidateRule::Range(_) => "range",
+            ValidateRule::Port(_) => "port",
+            ValidateRule::Timezone(_) => "timezone",
+            ValidateRule::Positive
+            | ValidateRule::NonNegative
+            | ValidateRule::Negative
+            | ValidateRule::NonPositive => "range::sign",
+            ValidateRule::MultipleOf(_) => "multiple_of",
+            ValidateRule::Precision(_) => "precision",
+            ValidateRule::Finite => "finite",
+            ValidateRule::Latitude => "geolocation::latitude",
+            ValidateRule::Longitude => "geolocation::longitude",
+            ValidateRule::CoordinatePair => "geolocation::coordinate_pair",
+            ValidateRule::OneOf(_) => "one_of",
+            ValidateRule::NotOneOf(_) => "one_of::not",
             ValidateRule::Contains(_) => "contains",
+            ValidateRule::NotContains(_) => "contains::not",
+            ValidateRule::ContainsAny(_) => "contains::any",
+            ValidateRule::ContainsAll(_) => "contains::all",
             ValidateRule::Prefix(_) => "prefix",
+            ValidateRule::NotPrefix(_) => "prefix::not",
             ValidateRule::Suffix(_) => "suffix",
+            ValidateRule::NotSuffix(_) => "suffix::not",
             ValidateRule::Pattern(_) => "pattern",
         }
     }
 }
 
+#[cfg(feature = "ipnet")]
+pub enum Subnet {
+    V4([u8; 4], u8),
+    V6([u16; 8], u8),
+}
+
 pub enum ValidatePattern {
     #[cfg(feature = "regex")]
     Lit(String),
@@ -292,6 +945,57 @@ pub enum ValidateRange<T> {
     LowerThan(T),
     Between(T, T),
     Equal(T),
+    Exact(T),
+}
+
+impl<T> ValidateRange<T> {
+    pub fn get(&self, name: &str) -> Option<&T> {
+        match (self, name) {
+            (ValidateRange::GreaterThan(v), "min")
+            | (ValidateRange::LowerThan(v), "max")
+            | (ValidateRange::Between(v, _), "min")
+            | (ValidateRange::Between(_, v), "max")
+            | (ValidateRange::Equal(v) | ValidateRange::Exact(v), "min" | "max" | "equal") => {
+                Some(v)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// A bound of the `range` rule, which is exclusive if it was set using `exclusive_min` or `exclusive_max`
+pub struct RangeBound {
+    pub value: Expr,
+    pub exclusive: bool,
+}
+
+impl quote::ToTokens for RangeBound {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        self.value.to_tokens(tokens)
+    }
+}
+
+pub enum ValidateMessage {
+    /// Format string and the names of the placeholders used in it
+    Fmt(Str, Vec<String>),
+    Func(Expr),
+}
+
+/// A `length` or `range` rule, whose bounds may be referenced in a `message`
+pub enum Bounds<'a> {
+    Length(&'a LengthRange),
+    Range(&'a ValidateRange<RangeBound>),
+}
+
+impl Bounds<'_> {
+    pub fn get(&self, name: &str) -> Option<proc_macro2::TokenStream> {
+        use quote::ToTokens as _;
+
+        match self {
+            Bounds::Length(range) => range.get(name).map(|v| v.to_token_stream()),
+            Bounds::Range(range) => range.get(name).map(|v| v.to_token_stream()),
+        }
+    }
 }
 
 pub enum ValidateVariant {
@@ -0,0 +1,262 @@
+use proc_macro2::{Ident, TokenStream as TokenStream2, TokenTree};
+use quote::{quote, ToTokens};
+use syn::ext::IdentExt as _;
+use syn::parse_quote;
+
+use crate::model;
+
+pub fn emit(input: model::Validate) -> TokenStream2 {
+    let ident = &input.ident;
+    let (_, context_ident) = &input.context;
+    let mut generics = input.generics.clone();
+    for param in generics.type_params_mut() {
+        param.bounds.push(parse_quote!(::garde::schema::JsonSchema));
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let schema = Schema { context_ident };
+    let body = match &input.kind {
+        model::ValidateKind::Struct(variant) => schema.variant(variant, input.is_transparent),
+        model::ValidateKind::Enum(variants) => schema.enum_(variants),
+    };
+
+    quote! {
+        impl #impl_generics ::garde::schema::JsonSchema for #ident #ty_generics #where_clause {
+            fn json_schema() -> ::garde::external::serde_json::Value {
+                #body
+            }
+        }
+    }
+}
+
+struct Schema<'a> {
+    context_ident: &'a Ident,
+}
+
+impl<'a> Schema<'a> {
+    /// Enums use the externally tagged representation.
+    fn enum_(
+        &self,
+        variants: &[(Ident, Option<syn::Expr>, Option<model::ValidateVariant>)],
+    ) -> TokenStream2 {
+        let variants = variants.iter().map(|(ident, _, variant)| {
+            let name = ident.unraw().to_string();
+            match variant {
+                None => quote! {
+                    ::garde::external::serde_json::json!({ "const": #name })
+                },
+                Some(variant) => {
+                    let value = match variant {
+                        model::ValidateVariant::Tuple(fields) if fields.len() == 1 => {
+                            self.field(&fields[0])
+                        }
+                        variant => self.variant(variant, false),
+                    };
+                    quote! {{
+                        let mut __garde_schema = ::garde::external::serde_json::json!({
+                            "type": "object",
+                            "additionalProperties": false,
+                        });
+                        ::garde::schema::__property(&mut __garde_schema, #name, #value, true);
+                        __garde_schema
+                    }}
+                }
+            }
+        });
+
+        quote! {
+            ::garde::external::serde_json::json!({ "oneOf": [#((#variants)),*] })
+        }
+    }
+
+    fn variant(&self, variant: &model::ValidateVariant, is_transparent: bool) -> TokenStream2 {
+        match variant {
+            model::ValidateVariant::Struct(fields) if is_transparent => self.field(&fields[0].1),
+            model::ValidateVariant::Tuple(fields) if is_transparent => self.field(&fields[0]),
+            model::ValidateVariant::Struct(fields) => {
+                let properties = fields.iter().map(|(ident, field)| {
+                    let value = self.field(field);
+                    if field.flatten.is_some() {
+                        return quote! {
+                            ::garde::schema::__flatten(&mut __garde_schema, #value);
+                        };
+                    }
+                    let name = field.alias.clone().unwrap_or_else(|| ident.to_string());
+                    let required = !is_option(&field.ty)
+                        || field
+                            .rule_set
+                            .rules
+                            .contains(&model::ValidateRule::Required);
+                    quote! {
+                        ::garde::schema::__property(&mut __garde_schema, #name, #value, #required);
+                    }
+                });
+
+                quote! {{
+                    let mut __garde_schema = ::garde::external::serde_json::json!({
+                        "type": "object",
+                    });
+                    #(#properties)*
+                    __garde_schema
+                }}
+            }
+            model::ValidateVariant::Tuple(fields) => {
+                let len = fields.len();
+                let items = fields.iter().map(|field| self.field(field));
+
+                quote! {
+                    ::garde::external::serde_json::json!({
+                        "type": "array",
+                        "items": [#((#items)),*],
+                        "minItems": #len,
+                        "maxItems": #len,
+                    })
+                }
+            }
+        }
+    }
+
+    fn field(&self, field: &model::ValidateField) -> TokenStream2 {
+        // Skipped fields are still serialized, but we know nothing about them.
+        if field.skip.is_some() {
+            return quote!(::garde::external::serde_json::json!({}));
+        }
+
+        let ty = &field.ty;
+        let rules = self.rule_set(&field.rule_set);
+
+        quote! {{
+            let mut __garde_value = <#ty as ::garde::schema::JsonSchema>::json_schema();
+            let __garde_schema = &mut __garde_value;
+            #rules
+            __garde_value
+        }}
+    }
+
+    fn rule_set(&self, rule_set: &model::RuleSet) -> TokenStream2 {
+        let rules = rule_set.rules.iter().filter_map(|rule| self.rule(rule));
+        let subschemas = [
+            ("items", &rule_set.inner),
+            ("propertyNames", &rule_set.key),
+            ("additionalProperties", &rule_set.value),
+        ]
+        .into_iter()
+        .filter_map(|(key, rule_set)| {
+            let rules = self.rule_set(rule_set.as_ref()?);
+            Some(quote! {
+                ::garde::schema::__subschema(__garde_schema, #key, |__garde_schema| {
+                    #rules
+                });
+            })
+        });
+
+        quote! {
+            #(#rules)*
+            #(#subschemas)*
+        }
+    }
+
+    fn rule(&self, rule: &model::ValidateRule) -> Option<TokenStream2> {
+        use model::ValidateRule::*;
+
+        let set = |key: &str, value: TokenStream2| {
+            quote! {
+                ::garde::schema::__set(__garde_schema, #key, #value);
+            }
+        };
+        let format = |format: &str| set("format", quote!(#format.into()));
+
+        let rule = match rule {
+            Email => format("email"),
+            Url(_) => format("uri"),
+            Domain(_) => format("hostname"),
+            IpV4 => format("ipv4"),
+            IpV6 => format("ipv6"),
+            Uuid => format("uuid"),
+            Date(v) if v.format.is_none() => format("date"),
+            Time(v) if v.format.is_none() => format("time"),
+            DateTime(v) if v.format.is_none() => format("date-time"),
+            #[cfg(feature = "regex")]
+            Pattern(model::ValidatePattern::Lit(pattern)) => {
+                set("pattern", quote!(#pattern.into()))
+            }
+            Unique => set("uniqueItems", quote!(true.into())),
+            // `simple` is not exactly the same as `minLength` for strings, as it counts bytes instead of characters
+            LengthSimple(range) | LengthChars(range) => {
+                let bound = |bound: Option<&model::Either<usize, syn::Expr>>| match bound {
+                    Some(bound) if !self.uses_context(bound) => {
+                        quote!(::std::option::Option::Some(#bound))
+                    }
+                    _ => quote!(::std::option::Option::None),
+                };
+                let min = bound(range.get("min"));
+                let max = bound(range.get("max"));
+                quote! {
+                    ::garde::schema::__length(__garde_schema, #min, #max);
+                }
+            }
+            Range(range) => {
+                let bound =
+                    |bound: Option<&model::RangeBound>, inclusive: &str, exclusive: &str| {
+                        let bound = bound.filter(|bound| !self.uses_context(&bound.value))?;
+                        let key = match bound.exclusive {
+                            true => exclusive,
+                            false => inclusive,
+                        };
+                        let value = &bound.value;
+                        Some(set(key, quote!(::garde::schema::__to_value(&(#value)))))
+                    };
+                let min = bound(range.get("min"), "minimum", "exclusiveMinimum");
+                let max = bound(range.get("max"), "maximum", "exclusiveMaximum");
+                quote!(#min #max)
+            }
+            Positive => set("exclusiveMinimum", quote!(0.into())),
+            NonNegative => set("minimum", quote!(0.into())),
+            Negative => set("exclusiveMaximum", quote!(0.into())),
+            NonPositive => set("maximum", quote!(0.into())),
+            Latitude => {
+                let min = set("minimum", quote!((-90).into()));
+                let max = set("maximum", quote!(90.into()));
+                quote!(#min #max)
+            }
+            Longitude => {
+                let min = set("minimum", quote!((-180).into()));
+                let max = set("maximum", quote!(180.into()));
+                quote!(#min #max)
+            }
+            MultipleOf(expr) if !self.uses_context(expr) => {
+                set("multipleOf", quote!(::garde::schema::__to_value(&(#expr))))
+            }
+            OneOf(expr) if !self.uses_context(expr) => {
+                set("enum", quote!(::garde::schema::__to_value(&(#expr))))
+            }
+            _ => return None,
+        };
+
+        Some(rule)
+    }
+
+    /// Whether the expression refers to the context, which isn't available when building the schema.
+    fn uses_context(&self, expr: &impl ToTokens) -> bool {
+        fn visit(tokens: TokenStream2, ident: &Ident) -> bool {
+            tokens.into_iter().any(|tt| match tt {
+                TokenTree::Ident(v) => &v == ident,
+                TokenTree::Group(group) => visit(group.stream(), ident),
+                _ => false,
+            })
+        }
+
+        visit(expr.to_token_stream(), self.context_ident)
+    }
+}
+
+/// Optional fields may be omitted, unless they are `required`.
+fn is_option(ty: &syn::Type) -> bool {
+    let syn::Type::Path(path) = ty else {
+        return false;
+    };
+    path.path
+        .segments
+        .last()
+        .is_some_and(|segment| segment.ident == "Option")
+}
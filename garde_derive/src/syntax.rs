@@ -1,6 +1,6 @@
 use std::collections::BTreeMap;
 
-use proc_macro2::{Ident, Span};
+use proc_macro2::{Delimiter, Group, Ident, Punct, Spacing, Span, TokenStream, TokenTree};
 use syn::ext::IdentExt;
 use syn::parse::Parse;
 use syn::punctuated::Punctuated;
@@ -24,9 +24,10 @@ pub fn parse(input: DeriveInput) -> syn::Result<model::Input> {
             Vec::new()
         }
     };
+    let mut ignored_variant_attrs = Vec::new();
     let kind = match &input.data {
         syn::Data::Struct(v) => parse_struct(v),
-        syn::Data::Enum(v) => parse_enum(v),
+        syn::Data::Enum(v) => parse_enum(v, &mut ignored_variant_attrs),
         syn::Data::Union(v) => parse_union(v),
     };
     let kind = match kind {
@@ -46,6 +47,7 @@ pub fn parse(input: DeriveInput) -> syn::Result<model::Input> {
         generics,
         attrs,
         kind,
+        ignored_variant_attrs,
     })
 }
 
@@ -101,6 +103,19 @@ impl Parse for model::Attr {
             }
             "allow_unvalidated" => Ok(model::Attr::AllowUnvalidated),
             "transparent" => Ok(model::Attr::Transparent),
+            "async" => Ok(model::Attr::Async),
+            "bound" => {
+                input.parse::<Token![=]>()?;
+                let lit = input.parse::<syn::LitStr>()?;
+                let predicates =
+                    lit.parse_with(Punctuated::<syn::WherePredicate, Token![,]>::parse_terminated)?;
+                Ok(model::Attr::Bound(predicates.into_iter().collect()))
+            }
+            "rename_all" => {
+                let content;
+                syn::parenthesized!(content in input);
+                Ok(model::Attr::RenameAll(content.parse()?))
+            }
             _ => Err(syn::Error::new(ident.span(), "unrecognized attribute")),
         }
     }
@@ -131,13 +146,23 @@ fn parse_struct(node: &syn::DataStruct) -> syn::Result<model::InputKind> {
     Ok(model::InputKind::Struct(fields))
 }
 
-fn parse_enum(node: &syn::DataEnum) -> syn::Result<model::InputKind> {
+fn parse_enum(
+    node: &syn::DataEnum,
+    ignored_attrs: &mut Vec<Span>,
+) -> syn::Result<model::InputKind> {
     let mut error = None;
     let mut variants = Vec::new();
 
     for variant in node.variants.iter() {
+        let context_fn = match parse_variant_attr_list(&variant.attrs, ignored_attrs) {
+            Ok(v) => v,
+            Err(e) => {
+                error.maybe_fold(e);
+                None
+            }
+        };
         match parse_variant(&variant.fields) {
-            Ok(v) => variants.push((variant.ident.clone(), v)),
+            Ok(v) => variants.push((variant.ident.clone(), context_fn, v)),
             Err(e) => error.maybe_fold(e),
         }
     }
@@ -149,6 +174,63 @@ fn parse_enum(node: &syn::DataEnum) -> syn::Result<model::InputKind> {
     Ok(model::InputKind::Enum(variants))
 }
 
+/// Variants only accept `context_fn(<function or closure>)`.
+///
+/// Any other `#[garde(...)]` attribute on a variant used to be silently ignored,
+/// so it is still ignored, but its span is collected to emit a deprecation warning.
+fn parse_variant_attr_list(
+    attrs: &[syn::Attribute],
+    ignored_attrs: &mut Vec<Span>,
+) -> syn::Result<Option<syn::Expr>> {
+    let mut error = None;
+    let mut context_fn = None;
+
+    for attr in attrs.iter() {
+        if attr.path().is_ident("garde") {
+            if !is_context_fn_attr(attr) {
+                ignored_attrs.push(attr.span());
+                continue;
+            }
+            let parsed = attr.parse_args_with(|input: syn::parse::ParseStream| {
+                let ident = Ident::parse_any(input)?;
+                let content;
+                syn::parenthesized!(content in input);
+                Ok((ident, content.parse::<syn::Expr>()?))
+            });
+            match parsed {
+                Ok((ident, expr)) => {
+                    if context_fn.is_some() {
+                        error.maybe_fold(syn::Error::new(
+                            ident.span(),
+                            "duplicate attribute `context_fn`",
+                        ));
+                    }
+                    context_fn = Some(expr);
+                }
+                Err(e) => error.maybe_fold(e),
+            }
+        }
+    }
+
+    if let Some(error) = error {
+        return Err(error);
+    }
+
+    Ok(context_fn)
+}
+
+fn is_context_fn_attr(attr: &syn::Attribute) -> bool {
+    attr.parse_args_with(|input: syn::parse::ParseStream| {
+        let is_context_fn = input
+            .fork()
+            .call(Ident::parse_any)
+            .is_ok_and(|ident| ident == "context_fn");
+        input.parse::<TokenStream>()?;
+        Ok(is_context_fn)
+    })
+    .unwrap_or(false)
+}
+
 fn parse_union(node: &syn::DataUnion) -> syn::Result<model::InputKind> {
     Err(syn::Error::new(
         node.union_token.span(),
@@ -249,21 +331,99 @@ impl Parse for model::RawRule {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         let ident = Ident::parse_any(input)?;
 
-        macro_rules! rules {
+        // Any rule accepts a `code = "..."` argument, which is removed
+        // before the rest of the arguments are parsed by the rule.
+        let split = match input.peek(syn::token::Paren) {
+            true => split_code_arg(input.fork().parse()?),
+            false => None,
+        };
+        let (kind, code) = match split {
+            Some((args, code)) => {
+                input.parse::<Group>()?;
+                let parser = |input: syn::parse::ParseStream| parse_rule_kind(&ident, input);
+                (syn::parse::Parser::parse2(parser, args)?, Some(code))
+            }
+            None => (parse_rule_kind(&ident, input)?, None),
+        };
+
+        Ok(model::RawRule {
+            span: ident.span(),
+            kind,
+            code,
+        })
+    }
+}
+
+/// Removes a top-level `code = "..."` argument from the arguments of a rule.
+///
+/// Returns `None` if there is no such argument, in which case the arguments
+/// are parsed as they are.
+fn split_code_arg(group: Group) -> Option<(TokenStream, model::Str)> {
+    let mut args = vec![Vec::new()];
+    for token in group.stream() {
+        match token {
+            TokenTree::Punct(punct) if punct.as_char() == ',' => args.push(Vec::new()),
+            token => args.last_mut().unwrap().push(token),
+        }
+    }
+
+    let index = args.iter().position(|arg| {
+        matches!(
+            arg.as_slice(),
+            [TokenTree::Ident(name), TokenTree::Punct(eq), TokenTree::Literal(_)]
+                if name == "code" && eq.as_char() == '='
+        )
+    })?;
+    let code = syn::parse2::<model::Str>(args.remove(index)[2].clone().into()).ok()?;
+
+    let mut rest = TokenStream::new();
+    for (i, arg) in args.into_iter().filter(|arg| !arg.is_empty()).enumerate() {
+        if i > 0 {
+            rest.extend([TokenTree::Punct(Punct::new(',', Spacing::Alone))]);
+        }
+        rest.extend(arg);
+    }
+    if rest.is_empty() {
+        return Some((rest, code));
+    }
+    let mut rest = Group::new(Delimiter::Parenthesis, rest);
+    rest.set_span(group.span());
+    Some((TokenTree::Group(rest).into(), code))
+}
+
+fn parse_rule_kind(
+    ident: &Ident,
+    input: syn::parse::ParseStream,
+) -> syn::Result<model::RawRuleKind> {
+    // `Rule(content)` requires parenthesized arguments,
+    // `Rule[content]` accepts them optionally.
+    macro_rules! rules {
             (($input:ident, $ident:ident) {
-                $($name:literal => $rule:ident $(($content:ident))?,)*
+                $($name:literal => $rule:ident $(($content:ident))? $([$optional:ident])?,)*
             }) => {
                 match $ident.to_string().as_str() {
                     $(
                         $name => {
                             $(
+                                if !$input.peek(syn::token::Paren) {
+                                    return Err(syn::Error::new(
+                                        $ident.span(),
+                                        concat!("rule `", $name, "` requires arguments"),
+                                    ));
+                                }
                                 let $content;
                                 syn::parenthesized!($content in $input);
                             )?
-                            Ok(model::RawRule {
-                                span: $ident.span(),
-                                kind: model::RawRuleKind::$rule $(($content.parse()?))?
-                            })
+                            $(
+                                let $optional = if $input.peek(syn::token::Paren) {
+                                    let $optional;
+                                    syn::parenthesized!($optional in $input);
+                                    Some($optional.parse()?)
+                                } else {
+                                    None
+                                };
+                            )?
+                            Ok(model::RawRuleKind::$rule $(($content.parse()?))? $(($optional))?)
                         }
                     )*
                     _ => Err(syn::Error::new($ident.span(), "unrecognized validation rule")),
@@ -271,34 +431,98 @@ impl Parse for model::RawRule {
             };
         }
 
-        rules! {
-            (input, ident) {
-                "skip" => Skip,
-                "adapt" => Adapt(content),
-                "rename" => Rename(content),
-                // "message" => Message(content),
-                "code" => Code(content),
-                "dive" => Dive,
-                "required" => Required,
-                "ascii" => Ascii,
-                "alphanumeric" => Alphanumeric,
-                "email" => Email,
-                "url" => Url,
-                "ip" => Ip,
-                "ipv4" => IpV4,
-                "ipv6" => IpV6,
-                "credit_card" => CreditCard,
-                "phone_number" => PhoneNumber,
-                "length" => Length(content),
-                "matches" => Matches(content),
-                "range" => Range(content),
-                "contains" => Contains(content),
-                "prefix" => Prefix(content),
-                "suffix" => Suffix(content),
-                "pattern" => Pattern(content),
-                "custom" => Custom(content),
-                "inner" => Inner(content),
-            }
+    rules! {
+        (input, ident) {
+            "skip" => Skip,
+            "skip_if" => SkipIf(content),
+            "group" => Group(content),
+            "adapt" => Adapt(content),
+            "rename" => Rename(content),
+            "alias" => Rename(content),
+            "message" => Message(content),
+            "code" => Code(content),
+            "label" => Label(content),
+            "normalize" => Normalize(content),
+            "dive" => Dive,
+            "dive_with" => DiveWith(content),
+            "flatten" => Flatten,
+            "required" => Required,
+            "non_default" => NonDefault,
+            "ascii" => Ascii,
+            "alphanumeric" => Alphanumeric[content],
+            "alphabetic" => Alphabetic[content],
+            "lowercase" => Lowercase,
+            "uppercase" => Uppercase,
+            "no_whitespace" => NoWhitespace,
+            "no_leading_whitespace" => NoLeadingWhitespace,
+            "no_trailing_whitespace" => NoTrailingWhitespace,
+            "hex" => Hex,
+            "email" => Email[content],
+            "url" => Url[content],
+            "domain" => Domain[content],
+            "slug" => Slug[content],
+            "mime_type" => MimeType[content],
+            "ip" => Ip,
+            "ipv4" => IpV4,
+            "ipv6" => IpV6,
+            "ip_in_subnet" => IpInSubnet(content),
+            "cidr" => Cidr[content],
+            "mac_address" => MacAddress[content],
+            "json_string" => JsonString[content],
+            "credit_card" => CreditCard[content],
+            "luhn" => Luhn,
+            "phone_number" => PhoneNumber[content],
+            "uuid" => Uuid,
+            "iban" => Iban[content],
+            "isbn" => Isbn[content],
+            "country_code" => CountryCode[content],
+            "semver" => Semver[content],
+            "date" => Date[content],
+            "time" => Time[content],
+            "datetime" => DateTime[content],
+            "password" => Password(content),
+            "not_common_password" => NotCommonPassword[content],
+            "unique" => Unique,
+            "unique_by" => UniqueBy(content),
+            "sorted" => Sorted[content],
+            "numeric" => Numeric(content),
+            "base64" => Base64[content],
+            "length" => Length(content),
+            "byte_length" => ByteLength(content),
+            "matches" => Matches(content),
+            "range" => Range(content),
+            "port" => Port[content],
+            "timezone" => Timezone[content],
+            "positive" => Positive,
+            "non_negative" => NonNegative,
+            "negative" => Negative,
+            "non_positive" => NonPositive,
+            "multiple_of" => MultipleOf(content),
+            "precision" => Precision(content),
+            "finite" => Finite,
+            "latitude" => Latitude,
+            "longitude" => Longitude,
+            "coordinate_pair" => CoordinatePair,
+            "one_of" => OneOf(content),
+            "not_one_of" => NotOneOf(content),
+            "contains" => Contains(content),
+            "not_contains" => NotContains(content),
+            "contains_any" => ContainsAny(content),
+            "contains_all" => ContainsAll(content),
+            "prefix" => Prefix(content),
+            "not_prefix" => NotPrefix(content),
+            "suffix" => Suffix(content),
+            "not_suffix" => NotSuffix(content),
+            "pattern" => Pattern(content),
+            "custom" => Custom(content),
+            "rule" => Rule(content),
+            "async_custom" => AsyncCustom(content),
+            "inner" => Inner(content),
+            "key" => Key(content),
+            "not" => Not(content),
+            "value" => Value(content),
+            "when" => When(content),
+            "warn" => Warn(content),
         }
     }
 }
@@ -322,194 +546,167 @@ impl Parse for model::Str {
     }
 }
 
-// impl Parse for model::Message {
-//     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-//         if input.peek(syn::LitStr) {
-//             Ok(Self::Fmt(model::Str::parse(input)?))
-//         } else {
-//             Ok(Self::Func(syn::Expr::parse(input)?))
-//         }
-//     }
-// }
-
-impl Parse for model::RawLength {
+impl Parse for model::RawUrl {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        let span = input.span();
-
-        let args =
-            Punctuated::<ContinueOnFail<RawLengthArgument>, Token![,]>::parse_terminated(input)?;
+        let args = Punctuated::<syn::Meta, Token![,]>::parse_terminated(input)?;
 
         let mut error = None;
 
-        let mut mode = None;
-        let mut min = None;
-        let mut max = None;
-        let mut equal = None;
+        let mut schemes = None;
+        let mut require_https = None;
+        let mut require_tld = None;
 
         for arg in args {
-            let arg = match arg {
-                ContinueOnFail::Ok(arg) => arg,
-                ContinueOnFail::Err(e) => {
-                    error.maybe_fold(e);
-                    continue;
-                }
-            };
-            match arg {
-                RawLengthArgument::Min(span, v) => {
-                    if min.is_some() {
-                        error.maybe_fold(syn::Error::new(span, "duplicate argument"))
+            let pair = match arg {
+                syn::Meta::Path(path) => {
+                    let flag = if path.is_ident("require_https") {
+                        &mut require_https
+                    } else if path.is_ident("require_tld") {
+                        &mut require_tld
                     } else {
-                        min = Some(v)
+                        error.maybe_fold(syn::Error::new(path.span(), "unexpected argument"));
+                        continue;
+                    };
+                    if flag.is_some() {
+                        error.maybe_fold(syn::Error::new(path.span(), "duplicate argument"));
+                        continue;
                     }
+                    *flag = Some(true);
+                    continue;
                 }
-                RawLengthArgument::Max(span, v) => {
-                    if max.is_some() {
-                        error.maybe_fold(syn::Error::new(span, "duplicate argument"))
-                    } else {
-                        max = Some(v)
-                    }
+                syn::Meta::NameValue(pair) => pair,
+                syn::Meta::List(list) => {
+                    error.maybe_fold(syn::Error::new(list.span(), "unexpected argument"));
+                    continue;
                 }
-                RawLengthArgument::Equal(span, v) => {
-                    if equal.is_some() {
-                        error.maybe_fold(syn::Error::new(span, "duplicate argument"))
-                    } else {
-                        equal = Some(v)
-                    }
+            };
+            if pair.path.is_ident("schemes") {
+                if schemes.is_some() {
+                    error.maybe_fold(syn::Error::new(pair.path.span(), "duplicate argument"));
+                    continue;
                 }
-                RawLengthArgument::Mode(span, v) => {
-                    if mode.is_some() {
-                        error.maybe_fold(syn::Error::new(span, "duplicate argument"))
-                    } else {
-                        mode = Some(v)
+                let syn::Expr::Array(array) = pair.value else {
+                    error.maybe_fold(syn::Error::new(
+                        pair.value.span(),
+                        "expected array of string literals",
+                    ));
+                    continue;
+                };
+                if array.elems.is_empty() {
+                    error.maybe_fold(syn::Error::new(
+                        array.span(),
+                        "expected at least one scheme",
+                    ));
+                    continue;
+                }
+                let mut values = Vec::with_capacity(array.elems.len());
+                for elem in array.elems {
+                    match <model::Str as FromExpr>::from_expr(elem) {
+                        Ok(v) if v.value.is_empty() => {
+                            error.maybe_fold(syn::Error::new(v.span, "scheme may not be empty"))
+                        }
+                        // the `url` crate always normalizes schemes to lowercase
+                        Ok(v) if v.value.bytes().any(|b| b.is_ascii_uppercase()) => {
+                            error.maybe_fold(syn::Error::new(v.span, "scheme must be lowercase"))
+                        }
+                        Ok(v) => values.push(v),
+                        Err(e) => error.maybe_fold(e),
                     }
                 }
+                schemes = Some(values);
+            } else {
+                error.maybe_fold(syn::Error::new(pair.path.span(), "unexpected argument"));
+                continue;
             }
         }
 
         if let Some(error) = error {
-            return Err(error);
+            Err(error)
+        } else {
+            Ok(model::RawUrl {
+                schemes: schemes.unwrap_or_default(),
+                require_https: require_https.unwrap_or_default(),
+                require_tld: require_tld.unwrap_or_default(),
+            })
         }
-
-        Ok(model::RawLength {
-            mode: mode.unwrap_or_default(),
-            range: model::Range {
-                span,
-                min,
-                max,
-                equal,
-            },
-        })
     }
 }
 
-enum RawLengthArgument {
-    Min(Span, model::Either<usize, syn::Expr>),
-    Max(Span, model::Either<usize, syn::Expr>),
-    Equal(Span, model::Either<usize, syn::Expr>),
-    Mode(Span, model::LengthMode),
-}
-
-impl Parse for RawLengthArgument {
+impl Parse for model::RawDomain {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        let ident = Ident::parse_any(input)?;
-        let span = ident.span();
-        let v = match ident.to_string().as_str() {
-            "simple" => RawLengthArgument::Mode(span, model::LengthMode::Simple),
-            "bytes" => RawLengthArgument::Mode(span, model::LengthMode::Bytes),
-            "chars" => RawLengthArgument::Mode(span, model::LengthMode::Chars),
-            "graphemes" => RawLengthArgument::Mode(span, model::LengthMode::Graphemes),
-            "utf16" => RawLengthArgument::Mode(span, model::LengthMode::Utf16),
-            "min" => {
-                let _ = input.parse::<Token![=]>()?;
-                let v = input.parse::<syn::Expr>()?;
-                RawLengthArgument::Min(span, FromExpr::from_expr(v)?)
-            }
-            "max" => {
-                let _ = input.parse::<Token![=]>()?;
-                let v = input.parse::<syn::Expr>()?;
-                RawLengthArgument::Max(span, FromExpr::from_expr(v)?)
-            }
-            "equal" => {
-                let _ = input.parse::<Token![=]>()?;
-                let v = input.parse::<syn::Expr>()?;
-                RawLengthArgument::Equal(span, FromExpr::from_expr(v)?)
-            }
-            _ => {
-                if input.peek(Token![=]) {
-                    let _ = input.parse::<Token![=]>()?;
-                }
-                if !input.peek(Token![,]) {
-                    let _ = input.parse::<syn::Expr>()?;
+        let idents =
+            Punctuated::<Ident, Token![,]>::parse_terminated_with(input, Ident::parse_any)?;
+
+        let mut error = None;
+
+        let mut require_tld = None;
+
+        for ident in idents {
+            if ident == "require_tld" {
+                if require_tld.is_some() {
+                    error.maybe_fold(syn::Error::new(ident.span(), "duplicate argument"));
+                    continue;
                 }
-                return Err(syn::Error::new(span, "invalid argument"));
+                require_tld = Some(true);
+            } else {
+                error.maybe_fold(syn::Error::new(ident.span(), "unexpected argument"));
+                continue;
             }
-        };
-        Ok(v)
+        }
+
+        if let Some(error) = error {
+            Err(error)
+        } else {
+            Ok(model::RawDomain {
+                require_tld: require_tld.unwrap_or_default(),
+            })
+        }
     }
 }
 
-impl<T> Parse for model::Range<T>
-where
-    T: FromExpr,
-{
+impl Parse for model::RawMimeType {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        let span = input.span();
-
         let pairs = Punctuated::<syn::MetaNameValue, Token![,]>::parse_terminated(input)?;
 
         let mut error = None;
 
-        let mut min = None::<T>;
-        let mut max = None::<T>;
-        let mut equal = None::<T>;
+        let mut allowed = None;
 
         for pair in pairs {
-            if pair.path.is_ident("min") {
-                if min.is_some() {
+            if pair.path.is_ident("allowed") {
+                if allowed.is_some() {
                     error.maybe_fold(syn::Error::new(pair.path.span(), "duplicate argument"));
                     continue;
                 }
-                let value = match <T as FromExpr>::from_expr(pair.value) {
-                    Ok(v) => v,
-                    Err(e) => {
-                        error.maybe_fold(e);
-                        continue;
-                    }
-                };
-                min = Some(value);
-            } else if pair.path.is_ident("max") {
-                if max.is_some() {
-                    error.maybe_fold(syn::Error::new(pair.path.span(), "duplicate argument"));
+                let syn::Expr::Array(array) = pair.value else {
+                    error.maybe_fold(syn::Error::new(
+                        pair.value.span(),
+                        "expected array of string literals",
+                    ));
                     continue;
-                }
-                let value = match <T as FromExpr>::from_expr(pair.value) {
-                    Ok(v) => v,
-                    Err(e) => {
-                        error.maybe_fold(e);
-                        continue;
-                    }
                 };
-                max = Some(value);
-            } else if pair.path.is_ident("equal") {
-                if equal.is_some() {
-                    error.maybe_fold(syn::Error::new(pair.path.span(), "duplicate argument"));
+                if array.elems.is_empty() {
+                    error.maybe_fold(syn::Error::new(
+                        array.span(),
+                        "expected at least one MIME type",
+                    ));
                     continue;
                 }
-                let value = match <T as FromExpr>::from_expr(pair.value) {
-                    Ok(v) => v,
-                    Err(e) => {
-                        error.maybe_fold(e);
-                        continue;
+                let mut values = Vec::with_capacity(array.elems.len());
+                for elem in array.elems {
+                    match <model::Str as FromExpr>::from_expr(elem) {
+                        // parameters are ignored when checking membership
+                        Ok(v) if !is_mime_type_essence(&v.value) => {
+                            error.maybe_fold(syn::Error::new(
+                                v.span,
+                                "expected a MIME type of the form `type/subtype`",
+                            ))
+                        }
+                        Ok(v) => values.push(v),
+                        Err(e) => error.maybe_fold(e),
                     }
-                };
-
-                if min.is_some() || max.is_some() {
-                    error.maybe_fold(syn::Error::new(
-                        pair.path.span(),
-                        "min or max conflict with equal",
-                    ));
                 }
-                equal = Some(value);
+                allowed = Some(values);
             } else {
                 error.maybe_fold(syn::Error::new(pair.path.span(), "unexpected argument"));
                 continue;
@@ -519,46 +716,1425 @@ where
         if let Some(error) = error {
             Err(error)
         } else {
-            Ok(model::Range {
-                span,
-                min,
-                max,
-                equal,
+            Ok(model::RawMimeType {
+                allowed: allowed.unwrap_or_default(),
             })
         }
     }
 }
 
-impl<T: Parse> Parse for List<T> {
+fn is_mime_type_essence(value: &str) -> bool {
+    let Some((type_, subtype)) = value.split_once('/') else {
+        return false;
+    };
+    let is_name = |v: &str| {
+        !v.is_empty()
+            && v.bytes().all(|b| {
+                b.is_ascii_alphanumeric()
+                    || matches!(
+                        b,
+                        b'!' | b'#' | b'$' | b'&' | b'-' | b'^' | b'_' | b'.' | b'+'
+                    )
+            })
+    };
+    is_name(type_) && is_name(subtype)
+}
+
+impl Parse for model::RawEmail {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        type CommaSeparated<T> = Punctuated<T, Token![,]>;
-        let contents: Vec<_> = CommaSeparated::parse_terminated(input)?
-            .into_iter()
-            .collect();
+        let idents =
+            Punctuated::<Ident, Token![,]>::parse_terminated_with(input, Ident::parse_any)?;
 
-        Ok(Self { contents })
-    }
-}
+        let mut error = None;
 
-trait FromExpr: Sized {
-    fn from_expr(v: syn::Expr) -> syn::Result<Self>;
-}
+        let mut check_mx = None;
 
-impl<L, R> FromExpr for model::Either<L, R>
-where
-    L: FromExpr,
-    R: FromExpr,
-{
-    fn from_expr(v: syn::Expr) -> syn::Result<Self> {
-        L::from_expr(v.clone())
-            .map(model::Either::Left)
-            .or_else(|_| R::from_expr(v).map(model::Either::Right))
+        for ident in idents {
+            if ident != "check_mx" {
+                error.maybe_fold(syn::Error::new(ident.span(), "unexpected argument"));
+                continue;
+            }
+            if check_mx.is_some() {
+                error.maybe_fold(syn::Error::new(ident.span(), "duplicate argument"));
+                continue;
+            }
+            check_mx = Some(ident.span());
+        }
+
+        if let Some(error) = error {
+            Err(error)
+        } else {
+            Ok(model::RawEmail { check_mx })
+        }
     }
 }
 
-impl FromExpr for syn::Expr {
-    fn from_expr(v: syn::Expr) -> syn::Result<Self> {
-        Ok(v)
+impl Parse for model::Slug {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let idents =
+            Punctuated::<Ident, Token![,]>::parse_terminated_with(input, Ident::parse_any)?;
+
+        let mut error = None;
+
+        let mut allow_uppercase = None;
+        let mut allow_dots = None;
+
+        for ident in idents {
+            let arg = if ident == "allow_uppercase" {
+                &mut allow_uppercase
+            } else if ident == "allow_dots" {
+                &mut allow_dots
+            } else {
+                error.maybe_fold(syn::Error::new(ident.span(), "unexpected argument"));
+                continue;
+            };
+            if arg.is_some() {
+                error.maybe_fold(syn::Error::new(ident.span(), "duplicate argument"));
+                continue;
+            }
+            *arg = Some(true);
+        }
+
+        if let Some(error) = error {
+            Err(error)
+        } else {
+            Ok(model::Slug {
+                allow_uppercase: allow_uppercase.unwrap_or_default(),
+                allow_dots: allow_dots.unwrap_or_default(),
+            })
+        }
+    }
+}
+
+impl Parse for model::Sorted {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let idents =
+            Punctuated::<Ident, Token![,]>::parse_terminated_with(input, Ident::parse_any)?;
+
+        let mut error = None;
+
+        let mut descending = None;
+        let mut strict = None;
+
+        for ident in idents {
+            let arg = if ident == "descending" {
+                &mut descending
+            } else if ident == "strict" {
+                &mut strict
+            } else {
+                error.maybe_fold(syn::Error::new(ident.span(), "unexpected argument"));
+                continue;
+            };
+            if arg.is_some() {
+                error.maybe_fold(syn::Error::new(ident.span(), "duplicate argument"));
+                continue;
+            }
+            *arg = Some(true);
+        }
+
+        if let Some(error) = error {
+            Err(error)
+        } else {
+            Ok(model::Sorted {
+                descending: descending.unwrap_or_default(),
+                strict: strict.unwrap_or_default(),
+            })
+        }
+    }
+}
+
+impl Parse for model::Numeric {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let ty = input.parse::<syn::Type>()?;
+        let idents = match input.is_empty() {
+            true => Punctuated::new(),
+            false => {
+                <Token![,]>::parse(input)?;
+                Punctuated::<Ident, Token![,]>::parse_terminated_with(input, Ident::parse_any)?
+            }
+        };
+
+        let mut error = None;
+
+        let mut finite = None;
+        let mut sign = None;
+
+        for ident in idents {
+            let value = if ident == "finite" {
+                if finite.is_some() {
+                    error.maybe_fold(syn::Error::new(ident.span(), "duplicate argument"));
+                    continue;
+                }
+                finite = Some(true);
+                continue;
+            } else if ident == "positive" {
+                model::Sign::Positive
+            } else if ident == "non_negative" {
+                model::Sign::NonNegative
+            } else if ident == "negative" {
+                model::Sign::Negative
+            } else if ident == "non_positive" {
+                model::Sign::NonPositive
+            } else {
+                error.maybe_fold(syn::Error::new(ident.span(), "unexpected argument"));
+                continue;
+            };
+            match sign {
+                Some(sign) if sign == value => {
+                    error.maybe_fold(syn::Error::new(ident.span(), "duplicate argument"));
+                }
+                Some(_) => {
+                    error.maybe_fold(syn::Error::new(
+                        ident.span(),
+                        "only one of `positive`, `non_negative`, `negative` or `non_positive` may be used",
+                    ));
+                }
+                None => sign = Some(value),
+            }
+        }
+
+        if let Some(error) = error {
+            Err(error)
+        } else {
+            Ok(model::Numeric {
+                ty,
+                finite: finite.unwrap_or_default(),
+                sign,
+            })
+        }
+    }
+}
+
+impl Parse for model::RawAlpha {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let idents =
+            Punctuated::<Ident, Token![,]>::parse_terminated_with(input, Ident::parse_any)?;
+
+        let mut error = None;
+
+        let mut ascii = None;
+
+        for ident in idents {
+            if ident == "ascii" {
+                if ascii.is_some() {
+                    error.maybe_fold(syn::Error::new(ident.span(), "duplicate argument"));
+                    continue;
+                }
+                ascii = Some(true);
+            } else {
+                error.maybe_fold(syn::Error::new(ident.span(), "unexpected argument"));
+                continue;
+            }
+        }
+
+        if let Some(error) = error {
+            Err(error)
+        } else {
+            Ok(model::RawAlpha {
+                ascii: ascii.unwrap_or_default(),
+            })
+        }
+    }
+}
+
+impl Parse for model::RawPort {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let idents =
+            Punctuated::<Ident, Token![,]>::parse_terminated_with(input, Ident::parse_any)?;
+
+        let mut error = None;
+
+        let mut unprivileged = None;
+
+        for ident in idents {
+            if ident == "unprivileged" {
+                if unprivileged.is_some() {
+                    error.maybe_fold(syn::Error::new(ident.span(), "duplicate argument"));
+                    continue;
+                }
+                unprivileged = Some(true);
+            } else {
+                error.maybe_fold(syn::Error::new(ident.span(), "unexpected argument"));
+                continue;
+            }
+        }
+
+        if let Some(error) = error {
+            Err(error)
+        } else {
+            Ok(model::RawPort {
+                unprivileged: unprivileged.unwrap_or_default(),
+            })
+        }
+    }
+}
+
+impl Parse for model::RawCommonPassword {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let idents =
+            Punctuated::<Ident, Token![,]>::parse_terminated_with(input, Ident::parse_any)?;
+
+        let mut error = None;
+
+        let mut case_insensitive = None;
+
+        for ident in idents {
+            if ident == "case_insensitive" {
+                if case_insensitive.is_some() {
+                    error.maybe_fold(syn::Error::new(ident.span(), "duplicate argument"));
+                    continue;
+                }
+                case_insensitive = Some(true);
+            } else {
+                error.maybe_fold(syn::Error::new(ident.span(), "unexpected argument"));
+                continue;
+            }
+        }
+
+        if let Some(error) = error {
+            Err(error)
+        } else {
+            Ok(model::RawCommonPassword {
+                case_insensitive: case_insensitive.unwrap_or_default(),
+            })
+        }
+    }
+}
+
+impl Parse for model::RawTimezone {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let idents =
+            Punctuated::<Ident, Token![,]>::parse_terminated_with(input, Ident::parse_any)?;
+
+        let mut error = None;
+
+        let mut utc_offset_only = None;
+
+        for ident in idents {
+            if ident == "utc_offset_only" {
+                if utc_offset_only.is_some() {
+                    error.maybe_fold(syn::Error::new(ident.span(), "duplicate argument"));
+                    continue;
+                }
+                utc_offset_only = Some(true);
+            } else {
+                error.maybe_fold(syn::Error::new(ident.span(), "unexpected argument"));
+                continue;
+            }
+        }
+
+        if let Some(error) = error {
+            Err(error)
+        } else {
+            Ok(model::RawTimezone {
+                utc_offset_only: utc_offset_only.unwrap_or_default(),
+            })
+        }
+    }
+}
+
+impl Parse for model::RawCidr {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let pairs = Punctuated::<syn::MetaNameValue, Token![,]>::parse_terminated(input)?;
+
+        let mut error = None;
+
+        let mut version = None;
+
+        for pair in pairs {
+            if pair.path.is_ident("version") {
+                if version.is_some() {
+                    error.maybe_fold(syn::Error::new(pair.path.span(), "duplicate argument"));
+                    continue;
+                }
+                let value = match <model::Str as FromExpr>::from_expr(pair.value) {
+                    Ok(v) => v,
+                    Err(e) => {
+                        error.maybe_fold(e);
+                        continue;
+                    }
+                };
+                match value.value.as_str() {
+                    "ipv4" => version = Some(model::IpVersion::V4),
+                    "ipv6" => version = Some(model::IpVersion::V6),
+                    _ => error.maybe_fold(syn::Error::new(
+                        value.span,
+                        "expected one of \"ipv4\", \"ipv6\"",
+                    )),
+                }
+            } else {
+                error.maybe_fold(syn::Error::new(pair.path.span(), "unexpected argument"));
+                continue;
+            }
+        }
+
+        if let Some(error) = error {
+            Err(error)
+        } else {
+            Ok(model::RawCidr {
+                version: version.unwrap_or_default(),
+            })
+        }
+    }
+}
+
+impl Parse for model::RawMacAddress {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let pairs = Punctuated::<syn::MetaNameValue, Token![,]>::parse_terminated(input)?;
+
+        let mut error = None;
+
+        let mut format = None;
+
+        for pair in pairs {
+            if pair.path.is_ident("format") {
+                if format.is_some() {
+                    error.maybe_fold(syn::Error::new(pair.path.span(), "duplicate argument"));
+                    continue;
+                }
+                let value = match <model::Str as FromExpr>::from_expr(pair.value) {
+                    Ok(v) => v,
+                    Err(e) => {
+                        error.maybe_fold(e);
+                        continue;
+                    }
+                };
+                match value.value.as_str() {
+                    "colon" => format = Some(model::MacAddressFormat::Colon),
+                    "hyphen" => format = Some(model::MacAddressFormat::Hyphen),
+                    _ => error.maybe_fold(syn::Error::new(
+                        value.span,
+                        "expected one of \"colon\", \"hyphen\"",
+                    )),
+                }
+            } else {
+                error.maybe_fold(syn::Error::new(pair.path.span(), "unexpected argument"));
+                continue;
+            }
+        }
+
+        if let Some(error) = error {
+            Err(error)
+        } else {
+            Ok(model::RawMacAddress {
+                format: format.unwrap_or_default(),
+            })
+        }
+    }
+}
+
+impl Parse for model::RawCreditCard {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let pairs = Punctuated::<syn::MetaNameValue, Token![,]>::parse_terminated(input)?;
+
+        let mut error = None;
+
+        let mut networks = None;
+
+        for pair in pairs {
+            if pair.path.is_ident("networks") {
+                if networks.is_some() {
+                    error.maybe_fold(syn::Error::new(pair.path.span(), "duplicate argument"));
+                    continue;
+                }
+                let syn::Expr::Array(array) = pair.value else {
+                    error.maybe_fold(syn::Error::new(
+                        pair.value.span(),
+                        "expected array of string literals",
+                    ));
+                    continue;
+                };
+                if array.elems.is_empty() {
+                    error.maybe_fold(syn::Error::new(
+                        array.span(),
+                        "expected at least one card network",
+                    ));
+                    continue;
+                }
+                let mut values = Vec::with_capacity(array.elems.len());
+                for elem in array.elems {
+                    let value = match <model::Str as FromExpr>::from_expr(elem) {
+                        Ok(v) => v,
+                        Err(e) => {
+                            error.maybe_fold(e);
+                            continue;
+                        }
+                    };
+                    let network = match value.value.as_str() {
+                        "visaelectron" => model::CardNetwork::VisaElectron,
+                        "maestro" => model::CardNetwork::Maestro,
+                        "forbrugsforeningen" => model::CardNetwork::Forbrugsforeningen,
+                        "dankort" => model::CardNetwork::Dankort,
+                        "visa" => model::CardNetwork::Visa,
+                        "mir" => model::CardNetwork::Mir,
+                        "mastercard" => model::CardNetwork::MasterCard,
+                        "amex" => model::CardNetwork::Amex,
+                        "dinersclub" => model::CardNetwork::DinersClub,
+                        "discover" => model::CardNetwork::Discover,
+                        "unionpay" => model::CardNetwork::UnionPay,
+                        "jcb" => model::CardNetwork::Jcb,
+                        _ => {
+                            error.maybe_fold(syn::Error::new(
+                                value.span,
+                                "expected one of \"visa\", \"visaelectron\", \"mastercard\", \"maestro\", \"amex\", \"discover\", \"dinersclub\", \"jcb\", \"unionpay\", \"mir\", \"dankort\", \"forbrugsforeningen\"",
+                            ));
+                            continue;
+                        }
+                    };
+                    values.push(network);
+                }
+                networks = Some(values);
+            } else {
+                error.maybe_fold(syn::Error::new(pair.path.span(), "unexpected argument"));
+                continue;
+            }
+        }
+
+        if let Some(error) = error {
+            Err(error)
+        } else {
+            Ok(model::RawCreditCard {
+                networks: networks.unwrap_or_default(),
+            })
+        }
+    }
+}
+
+impl Parse for model::RawJsonString {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let pairs = Punctuated::<syn::MetaNameValue, Token![,]>::parse_terminated(input)?;
+
+        let mut error = None;
+
+        let mut schema_type = None;
+
+        for pair in pairs {
+            if pair.path.is_ident("schema_type") {
+                if schema_type.is_some() {
+                    error.maybe_fold(syn::Error::new(pair.path.span(), "duplicate argument"));
+                    continue;
+                }
+                let value = match <model::Str as FromExpr>::from_expr(pair.value) {
+                    Ok(v) => v,
+                    Err(e) => {
+                        error.maybe_fold(e);
+                        continue;
+                    }
+                };
+                match value.value.as_str() {
+                    "object" => schema_type = Some(model::JsonType::Object),
+                    "array" => schema_type = Some(model::JsonType::Array),
+                    "string" => schema_type = Some(model::JsonType::String),
+                    "number" => schema_type = Some(model::JsonType::Number),
+                    "boolean" => schema_type = Some(model::JsonType::Boolean),
+                    "null" => schema_type = Some(model::JsonType::Null),
+                    _ => error.maybe_fold(syn::Error::new(
+                        value.span,
+                        "expected one of \"object\", \"array\", \"string\", \"number\", \"boolean\", \"null\"",
+                    )),
+                }
+            } else {
+                error.maybe_fold(syn::Error::new(pair.path.span(), "unexpected argument"));
+                continue;
+            }
+        }
+
+        if let Some(error) = error {
+            Err(error)
+        } else {
+            Ok(model::RawJsonString { schema_type })
+        }
+    }
+}
+
+impl Parse for model::RawPhoneNumber {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let pairs = Punctuated::<syn::MetaNameValue, Token![,]>::parse_terminated(input)?;
+
+        let mut error = None;
+
+        let mut region = None;
+
+        for pair in pairs {
+            if pair.path.is_ident("region") {
+                if region.is_some() {
+                    error.maybe_fold(syn::Error::new(pair.path.span(), "duplicate argument"));
+                    continue;
+                }
+                let value = match <model::Str as FromExpr>::from_expr(pair.value) {
+                    Ok(v) => v,
+                    Err(e) => {
+                        error.maybe_fold(e);
+                        continue;
+                    }
+                };
+                if value.value.len() != 2 || !value.value.bytes().all(|b| b.is_ascii_uppercase()) {
+                    error.maybe_fold(syn::Error::new(
+                        value.span,
+                        "expected a two-letter uppercase region code",
+                    ));
+                    continue;
+                }
+                region = Some(value);
+            } else {
+                error.maybe_fold(syn::Error::new(pair.path.span(), "unexpected argument"));
+                continue;
+            }
+        }
+
+        if let Some(error) = error {
+            Err(error)
+        } else {
+            Ok(model::RawPhoneNumber { region })
+        }
+    }
+}
+
+impl Parse for model::RawIban {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let pairs = Punctuated::<syn::MetaNameValue, Token![,]>::parse_terminated(input)?;
+
+        let mut error = None;
+
+        let mut country = None;
+
+        for pair in pairs {
+            if pair.path.is_ident("country") {
+                if country.is_some() {
+                    error.maybe_fold(syn::Error::new(pair.path.span(), "duplicate argument"));
+                    continue;
+                }
+                let value = match <model::Str as FromExpr>::from_expr(pair.value) {
+                    Ok(v) => v,
+                    Err(e) => {
+                        error.maybe_fold(e);
+                        continue;
+                    }
+                };
+                if value.value.len() != 2 || !value.value.bytes().all(|b| b.is_ascii_uppercase()) {
+                    error.maybe_fold(syn::Error::new(
+                        value.span,
+                        "expected a two-letter uppercase country code",
+                    ));
+                    continue;
+                }
+                country = Some(value);
+            } else {
+                error.maybe_fold(syn::Error::new(pair.path.span(), "unexpected argument"));
+                continue;
+            }
+        }
+
+        if let Some(error) = error {
+            Err(error)
+        } else {
+            Ok(model::RawIban { country })
+        }
+    }
+}
+
+impl Parse for model::RawIsbn {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let pairs = Punctuated::<syn::MetaNameValue, Token![,]>::parse_terminated(input)?;
+
+        let mut error = None;
+
+        let mut format = None;
+
+        for pair in pairs {
+            if pair.path.is_ident("format") {
+                if format.is_some() {
+                    error.maybe_fold(syn::Error::new(pair.path.span(), "duplicate argument"));
+                    continue;
+                }
+                let value = match <model::Str as FromExpr>::from_expr(pair.value) {
+                    Ok(v) => v,
+                    Err(e) => {
+                        error.maybe_fold(e);
+                        continue;
+                    }
+                };
+                match value.value.as_str() {
+                    "isbn10" => format = Some(model::IsbnFormat::Isbn10),
+                    "isbn13" => format = Some(model::IsbnFormat::Isbn13),
+                    _ => error.maybe_fold(syn::Error::new(
+                        value.span,
+                        "expected one of \"isbn10\", \"isbn13\"",
+                    )),
+                }
+            } else if pair.path.is_ident("version") {
+                // `version = 13` is equivalent to `format = "isbn13"`
+                if format.is_some() {
+                    error.maybe_fold(syn::Error::new(pair.path.span(), "duplicate argument"));
+                    continue;
+                }
+                let span = pair.value.span();
+                match <usize as FromExpr>::from_expr(pair.value) {
+                    Ok(10) => format = Some(model::IsbnFormat::Isbn10),
+                    Ok(13) => format = Some(model::IsbnFormat::Isbn13),
+                    Ok(_) => error.maybe_fold(syn::Error::new(span, "expected one of 10, 13")),
+                    Err(e) => error.maybe_fold(e),
+                }
+            } else {
+                error.maybe_fold(syn::Error::new(pair.path.span(), "unexpected argument"));
+                continue;
+            }
+        }
+
+        if let Some(error) = error {
+            Err(error)
+        } else {
+            Ok(model::RawIsbn {
+                format: format.unwrap_or_default(),
+            })
+        }
+    }
+}
+
+impl Parse for model::RawSemver {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let pairs = Punctuated::<syn::MetaNameValue, Token![,]>::parse_terminated(input)?;
+
+        let mut error = None;
+
+        let mut req = None;
+
+        for pair in pairs {
+            if pair.path.is_ident("req") {
+                if req.is_some() {
+                    error.maybe_fold(syn::Error::new(pair.path.span(), "duplicate argument"));
+                    continue;
+                }
+                req = Some(pair.value);
+            } else {
+                error.maybe_fold(syn::Error::new(pair.path.span(), "unexpected argument"));
+                continue;
+            }
+        }
+
+        if let Some(error) = error {
+            Err(error)
+        } else {
+            Ok(model::RawSemver { req })
+        }
+    }
+}
+
+impl Parse for model::RawDateTime {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let pairs = Punctuated::<syn::MetaNameValue, Token![,]>::parse_terminated(input)?;
+
+        let mut error = None;
+
+        let mut format = None;
+        let mut after = None;
+        let mut before = None;
+
+        for pair in pairs {
+            let arg = if pair.path.is_ident("format") {
+                &mut format
+            } else if pair.path.is_ident("after") {
+                &mut after
+            } else if pair.path.is_ident("before") {
+                &mut before
+            } else {
+                error.maybe_fold(syn::Error::new(pair.path.span(), "unexpected argument"));
+                continue;
+            };
+            if arg.is_some() {
+                error.maybe_fold(syn::Error::new(pair.path.span(), "duplicate argument"));
+                continue;
+            }
+            match <model::Str as FromExpr>::from_expr(pair.value) {
+                Ok(v) => *arg = Some(v),
+                Err(e) => error.maybe_fold(e),
+            }
+        }
+
+        if let Some(error) = error {
+            Err(error)
+        } else {
+            Ok(model::RawDateTime {
+                format,
+                after,
+                before,
+            })
+        }
+    }
+}
+
+impl Parse for model::RawPrecision {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let span = input.span();
+        let pairs = Punctuated::<syn::MetaNameValue, Token![,]>::parse_terminated(input)?;
+
+        let mut error = None;
+
+        let mut max_decimal_places = None;
+
+        for pair in pairs {
+            if pair.path.is_ident("max_decimal_places") {
+                if max_decimal_places.is_some() {
+                    error.maybe_fold(syn::Error::new(pair.path.span(), "duplicate argument"));
+                    continue;
+                }
+                match <usize as FromExpr>::from_expr(pair.value) {
+                    Ok(v) => max_decimal_places = Some(v),
+                    Err(e) => error.maybe_fold(e),
+                }
+            } else {
+                error.maybe_fold(syn::Error::new(pair.path.span(), "unexpected argument"));
+                continue;
+            }
+        }
+
+        if let Some(error) = error {
+            return Err(error);
+        }
+
+        match max_decimal_places {
+            Some(max_decimal_places) => Ok(model::RawPrecision { max_decimal_places }),
+            None => Err(syn::Error::new(
+                span,
+                "missing required argument `max_decimal_places`",
+            )),
+        }
+    }
+}
+
+impl Parse for model::Base64 {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let args =
+            Punctuated::<ContinueOnFail<Base64Argument>, Token![,]>::parse_terminated(input)?;
+
+        let mut error = None;
+
+        let mut alphabet = None;
+        let mut padding = None;
+
+        for arg in args {
+            let arg = match arg {
+                ContinueOnFail::Ok(arg) => arg,
+                ContinueOnFail::Err(e) => {
+                    error.maybe_fold(e);
+                    continue;
+                }
+            };
+            match arg {
+                Base64Argument::Alphabet(span, v) => {
+                    if alphabet.is_some() {
+                        error.maybe_fold(syn::Error::new(span, "duplicate argument"))
+                    } else {
+                        alphabet = Some(v)
+                    }
+                }
+                Base64Argument::Padding(span, v) => {
+                    if padding.is_some() {
+                        error.maybe_fold(syn::Error::new(span, "duplicate argument"))
+                    } else {
+                        padding = Some(v)
+                    }
+                }
+            }
+        }
+
+        if let Some(error) = error {
+            return Err(error);
+        }
+
+        Ok(model::Base64 {
+            url_safe: alphabet.unwrap_or_default(),
+            padding: padding.unwrap_or_default(),
+        })
+    }
+}
+
+impl Parse for model::CountryCode {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let args =
+            Punctuated::<ContinueOnFail<CountryCodeArgument>, Token![,]>::parse_terminated(input)?;
+
+        let mut error = None;
+
+        let mut alpha3 = None;
+        let mut case_insensitive = None;
+
+        for arg in args {
+            let arg = match arg {
+                ContinueOnFail::Ok(arg) => arg,
+                ContinueOnFail::Err(e) => {
+                    error.maybe_fold(e);
+                    continue;
+                }
+            };
+            match arg {
+                CountryCodeArgument::Alpha(span, v) => {
+                    if alpha3.is_some() {
+                        error.maybe_fold(syn::Error::new(span, "duplicate argument"))
+                    } else {
+                        alpha3 = Some(v)
+                    }
+                }
+                CountryCodeArgument::CaseInsensitive(span) => {
+                    if case_insensitive.is_some() {
+                        error.maybe_fold(syn::Error::new(span, "duplicate argument"))
+                    } else {
+                        case_insensitive = Some(true)
+                    }
+                }
+            }
+        }
+
+        if let Some(error) = error {
+            return Err(error);
+        }
+
+        Ok(model::CountryCode {
+            alpha3: alpha3.unwrap_or_default(),
+            case_insensitive: case_insensitive.unwrap_or_default(),
+        })
+    }
+}
+
+enum CountryCodeArgument {
+    /// `true` if the code is alpha-3
+    Alpha(Span, bool),
+    CaseInsensitive(Span),
+}
+
+impl Parse for CountryCodeArgument {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let ident = Ident::parse_any(input)?;
+        let span = ident.span();
+        let v = match ident.to_string().as_str() {
+            "alpha" => {
+                let _ = input.parse::<Token![=]>()?;
+                let value = input.parse::<syn::LitInt>()?;
+                let alpha3 = match value.base10_parse::<u8>() {
+                    Ok(2) => false,
+                    Ok(3) => true,
+                    _ => return Err(syn::Error::new(value.span(), "expected one of 2, 3")),
+                };
+                CountryCodeArgument::Alpha(span, alpha3)
+            }
+            "case_insensitive" => CountryCodeArgument::CaseInsensitive(span),
+            _ => {
+                if input.peek(Token![=]) {
+                    let _ = input.parse::<Token![=]>()?;
+                }
+                if !input.peek(Token![,]) {
+                    let _ = input.parse::<syn::Expr>()?;
+                }
+                return Err(syn::Error::new(span, "invalid argument"));
+            }
+        };
+        Ok(v)
+    }
+}
+
+enum Base64Argument {
+    /// `true` if the alphabet is URL-safe
+    Alphabet(Span, bool),
+    Padding(Span, model::Base64Padding),
+}
+
+impl Parse for Base64Argument {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let ident = Ident::parse_any(input)?;
+        let span = ident.span();
+        let v = match ident.to_string().as_str() {
+            "standard" => Base64Argument::Alphabet(span, false),
+            "url_safe" => Base64Argument::Alphabet(span, true),
+            "padding" => {
+                let _ = input.parse::<Token![=]>()?;
+                let value = input.parse::<syn::LitStr>()?;
+                let padding = match value.value().as_str() {
+                    "required" => model::Base64Padding::Required,
+                    "optional" => model::Base64Padding::Optional,
+                    "none" => model::Base64Padding::None,
+                    _ => {
+                        return Err(syn::Error::new(
+                            value.span(),
+                            "expected one of \"required\", \"optional\", \"none\"",
+                        ))
+                    }
+                };
+                Base64Argument::Padding(span, padding)
+            }
+            _ => {
+                if input.peek(Token![=]) {
+                    let _ = input.parse::<Token![=]>()?;
+                }
+                if !input.peek(Token![,]) {
+                    let _ = input.parse::<syn::Expr>()?;
+                }
+                return Err(syn::Error::new(span, "invalid argument"));
+            }
+        };
+        Ok(v)
+    }
+}
+
+impl Parse for model::RenameRule {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let value = model::Str::parse(input)?;
+        match value.value.as_str() {
+            "lowercase" => Ok(Self::Lower),
+            "UPPERCASE" => Ok(Self::Upper),
+            "PascalCase" => Ok(Self::Pascal),
+            "camelCase" => Ok(Self::Camel),
+            "snake_case" => Ok(Self::Snake),
+            "SCREAMING_SNAKE_CASE" => Ok(Self::ScreamingSnake),
+            "kebab-case" => Ok(Self::Kebab),
+            "SCREAMING-KEBAB-CASE" => Ok(Self::ScreamingKebab),
+            _ => Err(syn::Error::new(
+                value.span,
+                "unrecognized rename rule, expected one of \"lowercase\", \"UPPERCASE\", \"PascalCase\", \"camelCase\", \"snake_case\", \"SCREAMING_SNAKE_CASE\", \"kebab-case\", \"SCREAMING-KEBAB-CASE\"",
+            )),
+        }
+    }
+}
+
+impl Parse for model::RawWhen {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let condition = syn::Expr::parse(input)?;
+        if input.is_empty() {
+            return Err(syn::Error::new(
+                condition.span(),
+                "expected at least one rule after the condition",
+            ));
+        }
+        <Token![,]>::parse(input)?;
+        let rules = Punctuated::<model::RawRule, Token![,]>::parse_terminated(input)?;
+        if rules.is_empty() {
+            return Err(syn::Error::new(
+                condition.span(),
+                "expected at least one rule after the condition",
+            ));
+        }
+        Ok(Self {
+            condition,
+            rules: rules.into_iter().collect(),
+        })
+    }
+}
+
+impl Parse for model::Message {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        if input.peek(syn::LitStr) {
+            Ok(Self::Fmt(model::Str::parse(input)?))
+        } else {
+            Ok(Self::Func(syn::Expr::parse(input)?))
+        }
+    }
+}
+
+impl Parse for model::Normalize {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        if input.peek(syn::Ident) && input.peek2(syn::parse::End) {
+            let ident = input.fork().parse::<Ident>()?;
+            let normalize = match ident.to_string().as_str() {
+                "trim" => Some(Self::Trim),
+                "lowercase" => Some(Self::Lowercase),
+                "uppercase" => Some(Self::Uppercase),
+                _ => None,
+            };
+            if let Some(normalize) = normalize {
+                input.parse::<Ident>()?;
+                return Ok(normalize);
+            }
+        }
+
+        Ok(Self::Func(syn::Expr::parse(input)?))
+    }
+}
+
+impl Parse for model::Password {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let span = input.span();
+        let args = Punctuated::<syn::Meta, Token![,]>::parse_terminated(input)?;
+
+        let mut error = None;
+
+        let mut password = model::Password::default();
+        let mut seen = Vec::new();
+
+        for arg in args {
+            let name = match arg.path().get_ident() {
+                Some(name) => name.to_string(),
+                None => {
+                    error.maybe_fold(syn::Error::new(arg.span(), "unexpected argument"));
+                    continue;
+                }
+            };
+            if seen.contains(&name) {
+                error.maybe_fold(syn::Error::new(arg.span(), "duplicate argument"));
+                continue;
+            }
+            seen.push(name.clone());
+
+            match (name.as_str(), arg) {
+                ("require_uppercase", syn::Meta::Path(_)) => password.require_uppercase = true,
+                ("require_lowercase", syn::Meta::Path(_)) => password.require_lowercase = true,
+                ("require_digit", syn::Meta::Path(_)) => password.require_digit = true,
+                ("require_special", syn::Meta::Path(_)) => password.require_special = true,
+                ("min_entropy_bits", syn::Meta::NameValue(pair)) => {
+                    let value = match &pair.value {
+                        syn::Expr::Lit(syn::ExprLit {
+                            lit: syn::Lit::Float(v),
+                            ..
+                        }) => v.base10_parse::<f64>(),
+                        syn::Expr::Lit(syn::ExprLit {
+                            lit: syn::Lit::Int(v),
+                            ..
+                        }) => v.base10_parse::<f64>(),
+                        value => Err(syn::Error::new(value.span(), "expected number")),
+                    };
+                    match value {
+                        Ok(v) if v.is_finite() && v > 0.0 => password.min_entropy_bits = Some(v),
+                        Ok(_) => error.maybe_fold(syn::Error::new(
+                            pair.value.span(),
+                            "`min_entropy_bits` must be greater than 0",
+                        )),
+                        Err(e) => error.maybe_fold(e),
+                    }
+                }
+                ("min_complexity_score", syn::Meta::NameValue(pair)) => {
+                    let span = pair.value.span();
+                    match <usize as FromExpr>::from_expr(pair.value) {
+                        Ok(v @ 1..=4) => password.min_complexity_score = Some(v as u8),
+                        Ok(_) => error.maybe_fold(syn::Error::new(
+                            span,
+                            "`min_complexity_score` must be between 1 and 4",
+                        )),
+                        Err(e) => error.maybe_fold(e),
+                    }
+                }
+                (_, arg) => {
+                    error.maybe_fold(syn::Error::new(arg.span(), "unexpected argument"));
+                }
+            }
+        }
+
+        if seen.is_empty() && error.is_none() {
+            error.maybe_fold(syn::Error::new(span, "expected at least one argument"));
+        }
+
+        if let Some(error) = error {
+            Err(error)
+        } else {
+            Ok(password)
+        }
+    }
+}
+
+impl Parse for model::RawLength {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let span = input.span();
+
+        let args =
+            Punctuated::<ContinueOnFail<RawLengthArgument>, Token![,]>::parse_terminated(input)?;
+
+        let mut error = None;
+
+        let mut mode = None;
+        let mut min = None;
+        let mut max = None;
+        let mut equal = None;
+        let mut exact = false;
+
+        for arg in args {
+            let arg = match arg {
+                ContinueOnFail::Ok(arg) => arg,
+                ContinueOnFail::Err(e) => {
+                    error.maybe_fold(e);
+                    continue;
+                }
+            };
+            match arg {
+                RawLengthArgument::Min(span, v) => {
+                    if min.is_some() {
+                        error.maybe_fold(syn::Error::new(span, "duplicate argument"))
+                    } else {
+                        min = Some(v)
+                    }
+                }
+                RawLengthArgument::Max(span, v) => {
+                    if max.is_some() {
+                        error.maybe_fold(syn::Error::new(span, "duplicate argument"))
+                    } else {
+                        max = Some(v)
+                    }
+                }
+                RawLengthArgument::Equal(span, v, is_exact) => {
+                    if equal.is_some() {
+                        error.maybe_fold(syn::Error::new(span, "duplicate argument"))
+                    } else {
+                        equal = Some(v);
+                        exact = is_exact;
+                    }
+                }
+                RawLengthArgument::Mode(span, v) => {
+                    if mode.is_some() {
+                        error.maybe_fold(syn::Error::new(span, "duplicate argument"))
+                    } else {
+                        mode = Some(v)
+                    }
+                }
+            }
+        }
+
+        if let Some(error) = error {
+            return Err(error);
+        }
+
+        Ok(model::RawLength {
+            mode: mode.unwrap_or_default(),
+            range: model::Range {
+                span,
+                min,
+                max,
+                equal,
+                exact,
+            },
+        })
+    }
+}
+
+enum RawLengthArgument {
+    Min(Span, model::Either<usize, syn::Expr>),
+    Max(Span, model::Either<usize, syn::Expr>),
+    /// The flag is set if the argument was written as `exact`.
+    Equal(Span, model::Either<usize, syn::Expr>, bool),
+    Mode(Span, model::LengthMode),
+}
+
+impl Parse for RawLengthArgument {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let ident = Ident::parse_any(input)?;
+        let span = ident.span();
+        let v = match ident.to_string().as_str() {
+            "simple" => RawLengthArgument::Mode(span, model::LengthMode::Simple),
+            "bytes" => RawLengthArgument::Mode(span, model::LengthMode::Bytes),
+            "chars" => RawLengthArgument::Mode(span, model::LengthMode::Chars),
+            "graphemes" => RawLengthArgument::Mode(span, model::LengthMode::Graphemes),
+            "utf16" => RawLengthArgument::Mode(span, model::LengthMode::Utf16),
+            "min" => {
+                let _ = input.parse::<Token![=]>()?;
+                let v = input.parse::<syn::Expr>()?;
+                RawLengthArgument::Min(span, FromExpr::from_expr(v)?)
+            }
+            "max" => {
+                let _ = input.parse::<Token![=]>()?;
+                let v = input.parse::<syn::Expr>()?;
+                RawLengthArgument::Max(span, FromExpr::from_expr(v)?)
+            }
+            "equal" | "exact" => {
+                let _ = input.parse::<Token![=]>()?;
+                let v = input.parse::<syn::Expr>()?;
+                RawLengthArgument::Equal(span, FromExpr::from_expr(v)?, ident == "exact")
+            }
+            _ => {
+                if input.peek(Token![=]) {
+                    let _ = input.parse::<Token![=]>()?;
+                }
+                if !input.peek(Token![,]) {
+                    let _ = input.parse::<syn::Expr>()?;
+                }
+                return Err(syn::Error::new(span, "invalid argument"));
+            }
+        };
+        Ok(v)
+    }
+}
+
+impl<T> Parse for model::Range<T>
+where
+    T: FromExpr,
+{
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let span = input.span();
+
+        let pairs = Punctuated::<syn::MetaNameValue, Token![,]>::parse_terminated(input)?;
+
+        // this is only used by `byte_length`, which accepts `exact` like `length` does
+        parse_range_pairs(span, pairs, &["equal", "exact"])
+    }
+}
+
+/// `equal_names` lists the names accepted for the `equal` argument.
+fn parse_range_pairs<T>(
+    span: Span,
+    pairs: impl IntoIterator<Item = syn::MetaNameValue>,
+    equal_names: &[&str],
+) -> syn::Result<model::Range<T>>
+where
+    T: FromExpr,
+{
+    let mut error = None;
+
+    let mut min = None::<T>;
+    let mut max = None::<T>;
+    let mut equal = None::<T>;
+    let mut exact = false;
+
+    for pair in pairs {
+        if pair.path.is_ident("min") {
+            if min.is_some() {
+                error.maybe_fold(syn::Error::new(pair.path.span(), "duplicate argument"));
+                continue;
+            }
+            let value = match <T as FromExpr>::from_expr(pair.value) {
+                Ok(v) => v,
+                Err(e) => {
+                    error.maybe_fold(e);
+                    continue;
+                }
+            };
+            min = Some(value);
+        } else if pair.path.is_ident("max") {
+            if max.is_some() {
+                error.maybe_fold(syn::Error::new(pair.path.span(), "duplicate argument"));
+                continue;
+            }
+            let value = match <T as FromExpr>::from_expr(pair.value) {
+                Ok(v) => v,
+                Err(e) => {
+                    error.maybe_fold(e);
+                    continue;
+                }
+            };
+            max = Some(value);
+        } else if equal_names.iter().any(|name| pair.path.is_ident(name)) {
+            if equal.is_some() {
+                error.maybe_fold(syn::Error::new(pair.path.span(), "duplicate argument"));
+                continue;
+            }
+            let value = match <T as FromExpr>::from_expr(pair.value) {
+                Ok(v) => v,
+                Err(e) => {
+                    error.maybe_fold(e);
+                    continue;
+                }
+            };
+
+            if min.is_some() || max.is_some() {
+                error.maybe_fold(syn::Error::new(
+                    pair.path.span(),
+                    "min or max conflict with equal",
+                ));
+            }
+            equal = Some(value);
+            exact = pair.path.is_ident("exact");
+        } else {
+            error.maybe_fold(syn::Error::new(pair.path.span(), "unexpected argument"));
+            continue;
+        }
+    }
+
+    if let Some(error) = error {
+        Err(error)
+    } else {
+        Ok(model::Range {
+            span,
+            min,
+            max,
+            equal,
+            exact,
+        })
+    }
+}
+
+impl Parse for model::RawRange {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let span = input.span();
+
+        let pairs = Punctuated::<syn::MetaNameValue, Token![,]>::parse_terminated(input)?;
+
+        let mut error = None;
+
+        let mut exclusive_min = None::<syn::Expr>;
+        let mut exclusive_max = None::<syn::Expr>;
+        let mut rest = Vec::new();
+
+        for pair in pairs {
+            let bound = if pair.path.is_ident("exclusive_min") {
+                &mut exclusive_min
+            } else if pair.path.is_ident("exclusive_max") {
+                &mut exclusive_max
+            } else {
+                rest.push(pair);
+                continue;
+            };
+            if bound.is_some() {
+                error.maybe_fold(syn::Error::new(pair.path.span(), "duplicate argument"));
+                continue;
+            }
+            *bound = Some(pair.value);
+        }
+
+        let range = parse_range_pairs(span, rest, &["equal"]);
+
+        match (range, error) {
+            (Ok(range), None) => Ok(model::RawRange {
+                range,
+                exclusive_min,
+                exclusive_max,
+            }),
+            (Ok(_), Some(error)) | (Err(error), None) => Err(error),
+            (Err(e), Some(mut error)) => {
+                error.combine(e);
+                Err(error)
+            }
+        }
+    }
+}
+
+impl<T: Parse> Parse for List<T> {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        type CommaSeparated<T> = Punctuated<T, Token![,]>;
+        let contents: Vec<_> = CommaSeparated::parse_terminated(input)?
+            .into_iter()
+            .collect();
+
+        Ok(Self { contents })
+    }
+}
+
+trait FromExpr: Sized {
+    fn from_expr(v: syn::Expr) -> syn::Result<Self>;
+}
+
+impl<L, R> FromExpr for model::Either<L, R>
+where
+    L: FromExpr,
+    R: FromExpr,
+{
+    fn from_expr(v: syn::Expr) -> syn::Result<Self> {
+        L::from_expr(v.clone())
+            .map(model::Either::Left)
+            .or_else(|_| R::from_expr(v).map(model::Either::Right))
+    }
+}
+
+impl FromExpr for syn::Expr {
+    fn from_expr(v: syn::Expr) -> syn::Result<Self> {
+        Ok(v)
+    }
+}
+
+impl FromExpr for model::Str {
+    fn from_expr(v: syn::Expr) -> syn::Result<Self> {
+        match v {
+            syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(v),
+                ..
+            }) => Ok(model::Str {
+                span: v.span(),
+                value: v.value(),
+            }),
+            _ => Err(syn::Error::new(v.span(), "expected string literal")),
+        }
     }
 }
 
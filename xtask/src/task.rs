@@ -1,4 +1,5 @@
 mod check;
+mod passwords;
 mod publish;
 mod setup;
 mod test;
@@ -16,6 +17,7 @@ pub enum Task {
     Setup(setup::Setup),
     Version(version::Version),
     Publish(publish::Publish),
+    Passwords(passwords::Passwords),
 }
 
 impl Task {
@@ -26,6 +28,7 @@ impl Task {
             Task::Setup(cmd) => cmd.run(),
             Task::Version(cmd) => cmd.run(),
             Task::Publish(cmd) => cmd.run(),
+            Task::Passwords(cmd) => cmd.run(),
         }
     }
 }
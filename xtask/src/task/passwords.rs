@@ -0,0 +1,38 @@
+use std::collections::BTreeSet;
+use std::process::Command;
+
+use argp::FromArgs;
+
+use crate::util::{project_root, CommandExt as _};
+use crate::Result;
+
+/// The 10,000 most common passwords from SecLists, which is available under the MIT license:
+/// https://github.com/danielmiessler/SecLists/blob/master/LICENSE
+const SOURCE: &str = "https://raw.githubusercontent.com/danielmiessler/SecLists/master/Passwords/Common-Credentials/10k-most-common.txt";
+const OUTPUT: &str = "../garde/src/rules/password/common-passwords.txt";
+
+#[derive(FromArgs)]
+#[argp(subcommand, name = "passwords")]
+/// Regenerate the list of common passwords used by `not_common_password`
+pub struct Passwords {}
+
+impl Passwords {
+    pub fn run(self) -> Result {
+        let list = Command::new("curl")
+            .with_args(["--silent", "--show-error", "--fail", "--location", SOURCE])
+            .run_with_output()?;
+
+        // `not_common_password` expects the entries to be lowercase, and the unit test checks that they're sorted.
+        let entries = list
+            .lines()
+            .map(|line| line.trim().to_lowercase())
+            .filter(|line| !line.is_empty())
+            .collect::<BTreeSet<_>>();
+
+        let mut output = entries.into_iter().collect::<Vec<_>>().join("\n");
+        output.push('\n');
+        std::fs::write(project_root().join(OUTPUT), output)?;
+
+        Ok(())
+    }
+}